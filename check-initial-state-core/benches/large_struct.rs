@@ -0,0 +1,49 @@
+//! Measures expansion of a struct with as many fields as generated protocol
+//! builders have. Run with `cargo bench -p check-initial-state-core`.
+use check_initial_state_core::expand;
+use quote::{format_ident, quote};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use syn::DeriveInput;
+
+/// Number of fields of the measured struct.
+const FIELD_COUNT: usize = 1200;
+
+/// Number of measured expansions, following a warm-up one.
+const ITERATIONS: u32 = 20;
+
+fn main() {
+	let fields = (0..FIELD_COUNT).map(|index| {
+		let name = format_ident!("field_{}", index);
+		match index % 4 {
+			0 => quote! { #name: Option<u32> },
+			1 => quote! { #[expect_false] #name: bool },
+			2 => quote! { #[expect_zero] #name: u64 },
+			_ => quote! { #[report_name = "Renamed field"] #name: Option<Vec<String>> },
+		}
+	});
+	let plain: DeriveInput = syn::parse_quote! {
+		struct Builder {
+			#(#fields,)*
+		}
+	};
+	let mut with_constructor = plain.clone();
+	with_constructor.attrs.push(syn::parse_quote! {
+		#[check_initial_state(constructor = "Self::new", mutation_test, violations_iter)]
+	});
+	measure("default options", &plain);
+	measure("constructor, mutation test, violations()", &with_constructor);
+}
+
+/// Expands `input` repeatedly and prints the mean duration of an expansion.
+fn measure(name: &str, input: &DeriveInput) {
+	let expansion = expand(input);
+	assert!(!expansion.to_string().contains("compile_error"), "{}", expansion);
+	let mut total = Duration::default();
+	for _ in 0..ITERATIONS {
+		let start = Instant::now();
+		black_box(expand(black_box(input)));
+		total += start.elapsed();
+	}
+	println!("{} fields, {}: {:?} per expansion", FIELD_COUNT, name, total / ITERATIONS);
+}
@@ -0,0 +1,172 @@
+use crate::option_errors::skip_option;
+use crate::{
+	generate_coverage_off, generate_violation, tag_message, to_compile_error, ContainerOptions,
+	ParsedField,
+};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::{Attribute, Data, DeriveInput, Field, GenericParam, Index};
+
+/// Attribute of `bon` crate, which configures the builder and its members.
+const BUILDER_ATTRIBUTE: &str = "builder";
+
+/// Name of the function creating the builder, unless `start_fn` option of
+/// `builder` attribute renames it.
+const DEFAULT_START_FN: &str = "builder";
+
+/// How `bon` treats a field of the struct.
+enum Member {
+	/// The field is stored as `Option` in the builder's tuple of named members
+	/// and is set by the generated setter.
+	Named,
+	/// The field is annotated with `#[builder(field)]`, so it is stored in the
+	/// builder as is, initialized with the default or the specified value.
+	Custom,
+	/// The field is an argument of the start function, i.e. it is annotated
+	/// with `#[builder(start_fn)]`.
+	StartFn,
+	/// The field is not stored in the builder, e.g. it is annotated with
+	/// `#[builder(skip)]` or `#[builder(finish_fn)]`.
+	Absent,
+}
+
+/// Generates a test, which creates the builder of `bon::Builder` derive for
+/// `input` and verifies its state. Named members are expected to be unset,
+/// while custom fields, i.e. members annotated with `#[builder(field)]`, are
+/// checked like the fields of the struct itself. Fields annotated with
+/// `ignore_field` are skipped.
+/// # Parameters
+/// * `fields` &ndash; Parsed fields of `input` in the order of declaration.
+pub fn generate_bon_test(input: &DeriveInput, fields: &[ParsedField], options: &ContainerOptions)
+	-> Result<TokenStream, TokenStream> {
+	let struct_name = &input.ident;
+	if options.compare_with.is_some() {
+		let message = "`bon` option cannot be combined with `compare_with` option";
+		return Err(to_compile_error(struct_name, message));
+	}
+	let generic = input.generics.params.iter()
+		.any(|param| !matches!(param, GenericParam::Lifetime(_)));
+	if generic {
+		let message = "`bon` option is not applicable to a struct with type or const parameters, \
+			since its builder type cannot be inferred";
+		return Err(to_compile_error(&input.generics, message));
+	}
+	let data_fields = match &input.data {
+		Data::Struct(data_struct) => &data_struct.fields,
+		_ => unreachable!("Fields of other items than structs are never parsed"),
+	};
+	let member_message = tag_message(options, "Member `{}` of `{}` builder is set initially");
+	let field_message = tag_message(options, "Field `{}` of `{}` builder {}");
+	let struct_name_string = struct_name.to_string();
+	let mut named_index = 0;
+	let mut checks = Vec::new();
+	for (field, parsed_field) in data_fields.iter().zip(fields) {
+		let member = member_kind(field);
+		let index = Index::from(named_index);
+		if let Member::Named = member {
+			named_index += 1;
+		}
+		if parsed_field.options.ignored {
+			continue;
+		}
+		let field_name = parsed_field.report_name();
+		match member {
+			Member::Named => {
+				checks.push(quote! {
+					if ::std::option::Option::is_some(&builder.__unsafe_private_named.#index) {
+						::std::panic!(#member_message, #field_name, #struct_name_string);
+					}
+				});
+			},
+			Member::Custom => {
+				let violation = generate_violation(parsed_field, options, &quote!(builder));
+				checks.push(quote! {
+					if let ::std::option::Option::Some(reason) = #violation {
+						::std::panic!(#field_message, #field_name, #struct_name_string, reason);
+					}
+				});
+			},
+			Member::StartFn => {
+				let message = "`bon` option is not applicable to a struct with start function \
+					arguments, since its builder cannot be created without them";
+				return Err(to_compile_error(field, message));
+			},
+			Member::Absent => (),
+		}
+	}
+	let start_fn = find_start_fn(&input.attrs);
+	let module_name = Ident::new(
+		&format!("__check_initial_state_bon_{}", struct_name),
+		Span::call_site(),
+	);
+	let coverage_off = generate_coverage_off();
+	return Ok(quote! {
+		/// Test generated by `CheckInitialState` proc macro for the builder
+		/// of `bon::Builder` derive.
+		#[cfg(test)]
+		#coverage_off
+		#[allow(non_snake_case, clippy::panic)]
+		mod #module_name {
+			use super::*;
+
+			#[test]
+			fn bon_builder() {
+				let builder = #struct_name::#start_fn();
+				#(#checks)*
+			}
+		}
+	});
+}
+
+/// Tells how `bon` treats `field` according to its `builder` attributes.
+/// Malformed attributes are left for `bon` itself to report.
+fn member_kind(field: &Field) -> Member {
+	let mut member = Member::Named;
+	let attributes =
+		field.attrs.iter().filter(|attribute| attribute.path().is_ident(BUILDER_ATTRIBUTE));
+	for attribute in attributes {
+		let _ = attribute.parse_nested_meta(|option| {
+			if option.path.is_ident("field") {
+				member = Member::Custom;
+			} else if option.path.is_ident("start_fn") {
+				member = Member::StartFn;
+			} else if option.path.is_ident("skip") || option.path.is_ident("finish_fn") {
+				member = Member::Absent;
+			}
+			skip_option(option.input);
+			return Ok(());
+		});
+	}
+	return member;
+}
+
+/// Finds the name of the function creating the builder, which is specified by
+/// `start_fn` option of `builder` attribute of the struct, e.g.
+/// `#[builder(start_fn = new_builder)]` or
+/// `#[builder(start_fn(name = new_builder))]`. The default is `builder`.
+fn find_start_fn(attributes: &[Attribute]) -> Ident {
+	let mut start_fn = Ident::new(DEFAULT_START_FN, Span::call_site());
+	let attributes =
+		attributes.iter().filter(|attribute| attribute.path().is_ident(BUILDER_ATTRIBUTE));
+	for attribute in attributes {
+		let _ = attribute.parse_nested_meta(|option| {
+			if !option.path.is_ident("start_fn") {
+				skip_option(option.input);
+				return Ok(());
+			}
+			if option.input.peek(syn::Token![=]) {
+				start_fn = option.value()?.parse()?;
+				return Ok(());
+			}
+			return option.parse_nested_meta(|start_fn_option| {
+				if start_fn_option.path.is_ident("name") {
+					start_fn = start_fn_option.value()?.parse()?;
+				} else {
+					skip_option(start_fn_option.input);
+				}
+				return Ok(());
+			});
+		});
+	}
+	return start_fn;
+}
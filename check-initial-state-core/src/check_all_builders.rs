@@ -0,0 +1,136 @@
+use crate::option_errors::{parse_option, unknown_option, OptionErrors};
+use crate::to_compile_error;
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::parse::Parser;
+use syn::{meta, Attribute, Item, ItemMod, ItemStruct, LitStr};
+
+/// Attribute macro which applies `CheckInitialState` derive to matching
+/// structs of a module.
+const CHECK_ALL_BUILDERS_ATTRIBUTE: &str = "check_all_builders";
+
+/// Option of `check_all_builders` attribute which specifies the pattern of
+/// struct names, e.g. `pattern = "*Config"`.
+const PATTERN_OPTION: &str = "pattern";
+
+/// All options of `check_all_builders` attribute.
+const KNOWN_OPTIONS: [&str; 1] = [PATTERN_OPTION];
+
+/// Error of `check_all_builders` attribute placed on other item than an inline
+/// module.
+const MISPLACED_MESSAGE: &str = "`check_all_builders` attribute is applicable to inline modules only";
+
+/// Pattern of struct names which is used if none is specified.
+const DEFAULT_PATTERN: &str = "*Builder";
+
+/// Wildcard of a pattern, which matches any sequence of characters.
+const WILDCARD: char = '*';
+
+/// Attribute which lists derives of a struct.
+const DERIVE_ATTRIBUTE: &str = "derive";
+
+/// Name of the derive, which is recognized among derives a struct already has.
+const DERIVE_NAME: &str = "CheckInitialState";
+
+/// Generates the implementation of `check_all_builders` attribute macro. Each
+/// struct of `item` module, which name matches the pattern specified in `args`,
+/// gets `CheckInitialState` derive, unless it derives it already. Nested
+/// modules are not scanned. Problems of `args` or `item` are reported as compile
+/// errors in the returned tokens.
+pub fn expand_check_all_builders(args: TokenStream, item: TokenStream) -> TokenStream {
+	let pattern = match parse_pattern(args) {
+		Ok(pattern) => pattern,
+		Err(err) => return err,
+	};
+	let mut module = match syn::parse2::<ItemMod>(item) {
+		Ok(module) => module,
+		Err(err) => return syn::Error::new(err.span(), MISPLACED_MESSAGE).to_compile_error(),
+	};
+	let items = match &mut module.content {
+		Some((_, items)) => items,
+		None => {
+			return to_compile_error(&module, MISPLACED_MESSAGE);
+		},
+	};
+	for item in items.iter_mut() {
+		if let Item::Struct(item_struct) = item {
+			if matches_pattern(&item_struct.ident.to_string(), &pattern) && !has_derive(item_struct) {
+				// Derive must precede its helper attributes, e.g. `check_initial_state`
+				let derive: Attribute = syn::parse_quote! {
+					#[derive(::check_initial_state::CheckInitialState)]
+				};
+				item_struct.attrs.insert(0, derive);
+			}
+		}
+	}
+	return module.into_token_stream();
+}
+
+/// Parses `pattern` option out of `args` of `check_all_builders` attribute.
+/// [`DEFAULT_PATTERN`] is returned if `args` are empty.
+fn parse_pattern(args: TokenStream) -> Result<String, TokenStream> {
+	let mut pattern = DEFAULT_PATTERN.to_owned();
+	let mut errors = OptionErrors::default();
+	let parser = meta::parser(|option| {
+		parse_option(&option, &mut errors, |option| {
+			if option.path.is_ident(PATTERN_OPTION) {
+				let value = option.value()?.parse::<LitStr>().map_err(|err| {
+					return syn::Error::new(
+						err.span(),
+						"`pattern` option expects a string literal, e.g. `pattern = \"*Config\"`",
+					);
+				})?;
+				pattern = value.value();
+			} else {
+				return Err(unknown_option(option, CHECK_ALL_BUILDERS_ATTRIBUTE, &KNOWN_OPTIONS));
+			}
+			return Ok(());
+		});
+		return Ok(());
+	});
+	let result = parser.parse2(args);
+	errors.check(result);
+	errors.finish()?;
+	return Ok(pattern);
+}
+
+/// Tells whether `item_struct` already derives `CheckInitialState`, so the
+/// derive must not be applied twice.
+fn has_derive(item_struct: &ItemStruct) -> bool {
+	let mut found = false;
+	let derives =
+		item_struct.attrs.iter().filter(|attribute| attribute.path().is_ident(DERIVE_ATTRIBUTE));
+	for attribute in derives {
+		// Malformed derives are reported by the compiler itself
+		let _ = attribute.parse_nested_meta(|derive| {
+			found |= derive.path.segments.last().is_some_and(|segment| segment.ident == DERIVE_NAME);
+			return Ok(());
+		});
+	}
+	return found;
+}
+
+/// Tells whether `name` matches `pattern`, where each `*` stands for any,
+/// possibly empty, sequence of characters, e.g. `*Builder` matches
+/// `ServerBuilder`.
+fn matches_pattern(name: &str, pattern: &str) -> bool {
+	let mut parts = pattern.split(WILDCARD);
+	// Text before the first wildcard, or the whole pattern if it has none
+	let prefix = parts.next().unwrap_or_default();
+	let mut rest = match name.strip_prefix(prefix) {
+		Some(rest) => rest,
+		None => return false,
+	};
+	let parts = parts.collect::<Vec<_>>();
+	let (suffix, middle) = match parts.split_last() {
+		Some((suffix, middle)) => (*suffix, middle),
+		None => return rest.is_empty(),
+	};
+	for part in middle {
+		match rest.find(part) {
+			Some(index) => rest = &rest[index + part.len()..],
+			None => return false,
+		}
+	}
+	return rest.ends_with(suffix);
+}
@@ -0,0 +1,123 @@
+use crate::{
+	generate_check_prelude, generate_coverage_off, generate_dependency_tracking,
+	generate_violation, generics_with_bound, parse_sibling_derive, tag_message, to_compile_error,
+	RustVersion,
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::DeriveInput;
+
+/// Generates the implementation of `CheckAnySet` derive for `input`, i.e.
+/// `check_any_set()` method, which is the complement of
+/// `check_initial_state()`, and `check_exactly_n_set()` method. Fields are
+/// selected and checked by the same attributes as `CheckInitialState` derive
+/// uses. Problems of `input` are reported as compile errors in the returned
+/// tokens.
+pub fn expand_check_any_set(input: &DeriveInput) -> TokenStream {
+	let (options, fields) = match parse_sibling_derive(input, "CheckAnySet") {
+		Ok(parsed) => parsed,
+		Err(err) => return err,
+	};
+	let struct_name = &input.ident;
+	let checked_fields = fields.iter().filter(|field| !field.options.ignored).collect::<Vec<_>>();
+	if checked_fields.is_empty() {
+		let message = format!(
+			"All fields of `{}` are ignored, so `check_any_set()` always panics",
+			struct_name,
+		);
+		return to_compile_error(struct_name, &message);
+	}
+	let generics = generics_with_bound(input, &options);
+	let (leading_generics, trailing_generics, where_clause) = &generics.split_for_impl();
+	let track_caller = if RustVersion::supports(options.msrv, RustVersion::TRACK_CALLER) {
+		quote! { #[track_caller] }
+	} else {
+		TokenStream::new()
+	};
+	let (check, count_check) = if options.disabled {
+		(TokenStream::new(), quote! { let _ = n; })
+	} else {
+		let check_prelude = generate_check_prelude(&options);
+		let violations = checked_fields.iter()
+			.map(|field| (field.report_name(), generate_violation(field, &options, &quote!(self))))
+			.collect::<Vec<_>>();
+		let unset_checks = violations.iter().map(|(_, violation)| {
+			return quote! { ::std::option::Option::is_none(&#violation) };
+		});
+		let field_names = violations.iter()
+			.map(|(field_name, _)| format!("`{}`", field_name))
+			.collect::<Vec<_>>()
+			.join(", ");
+		let message = tag_message(
+			&options,
+			&format!("No checked field of `{}` is set. Checked fields: {}", struct_name, field_names),
+		);
+		let set_field_pushes = violations.iter().map(|(field_name, violation)| {
+			let field_name = format!("`{}`", field_name);
+			return quote! {
+				if ::std::option::Option::is_some(&#violation) {
+					set_fields.push(#field_name);
+				}
+			};
+		});
+		let count_message = tag_message(
+			&options,
+			&format!("Expected {{}} checked fields of `{}` to be set, found {{}}: {{}}", struct_name),
+		);
+		let check = quote! {
+			#check_prelude
+			if #(#unset_checks)&&* {
+				::std::panic!(#message);
+			}
+		};
+		let count_check = quote! {
+			#check_prelude
+			let mut set_fields: ::std::vec::Vec<&'static str> = ::std::vec::Vec::new();
+			#(#set_field_pushes)*
+			let count = set_fields.len();
+			if count != n {
+				let set_fields = if count == 0 {
+					::std::string::String::from("none")
+				} else {
+					set_fields.join(", ")
+				};
+				::std::panic!(#count_message, n, count, set_fields);
+			}
+		};
+		(check, count_check)
+	};
+	let coverage_off = generate_coverage_off();
+	let dependency_tracking = generate_dependency_tracking(&options);
+	return quote! {
+		#[automatically_derived]
+		#[allow(dead_code, clippy::panic)]
+		impl #leading_generics #struct_name #trailing_generics #where_clause {
+			/// Checks at least one field, which is not annotated with
+			/// `ignore_field`, to be out of initial state, e.g. an `Option`
+			/// field to be `Some`. Is expected to be used for testing that a
+			/// setter actually recorded something.
+			/// # Panics
+			/// All checked fields are in initial state.
+			#track_caller
+			#coverage_off
+			fn check_any_set(&self) {
+				#check
+			}
+
+			/// Checks exactly `n` fields, which are not annotated with
+			/// `ignore_field`, to be out of initial state. Is expected to be
+			/// used for step&#x2011;by&#x2011;step tests of a builder, which
+			/// verify its incremental population.
+			/// # Panics
+			/// Other number of checked fields is out of initial state. Panic
+			/// message lists such fields.
+			#track_caller
+			#coverage_off
+			fn check_exactly_n_set(&self, n: usize) {
+				#count_check
+			}
+		}
+
+		#dependency_tracking
+	};
+}
@@ -0,0 +1,118 @@
+use crate::container_attribute::ContainerOptions;
+use crate::disabled_switch;
+use crate::field_attribute::parse_field_options;
+use crate::{
+	field_ident, generate_dependency_tracking, generate_violation, to_compile_error, ParsedField,
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::token::Comma;
+use syn::{braced, Attribute, Expr, Field, FieldMutability, Path, Type, TypeInfer, Visibility};
+
+/// Input of `check_initial_state_for!` macro, e.g.
+/// `builder, Builder { option, #[expect_false] flag }`.
+struct CheckForInput {
+	/// Expression evaluating to the checked instance or to a reference to it.
+	instance: Expr,
+	/// Type of the checked instance.
+	ty: Path,
+	/// Checked fields along with their check attributes.
+	fields: Punctuated<Field, Comma>,
+}
+
+impl Parse for CheckForInput {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let instance = input.parse()?;
+		input.parse::<Comma>()?;
+		let ty = input.parse()?;
+		let content;
+		braced!(content in input);
+		let fields = content.parse_terminated(parse_field, Comma)?;
+		if !input.is_empty() {
+			input.parse::<Comma>()?;
+		}
+		return Ok(Self { instance, ty, fields });
+	}
+}
+
+/// Parses a field name preceded by its attributes, e.g. `#[expect_false] flag`.
+/// Types of foreign fields are not listed, so the type is left inferred.
+fn parse_field(input: ParseStream) -> syn::Result<Field> {
+	return Ok(Field {
+		attrs: input.call(Attribute::parse_outer)?,
+		vis: Visibility::Inherited,
+		mutability: FieldMutability::None,
+		ident: Some(input.parse()?),
+		colon_token: None,
+		ty: Type::Infer(TypeInfer { underscore_token: Default::default() }),
+	});
+}
+
+/// Generates the implementation of `check_initial_state_for!` macro for
+/// `input`. The generated block checks the listed fields of the given instance
+/// the same way `check_initial_state()` method does, and panics on the first
+/// field which is not in initial state. Nothing is checked if checks are
+/// disabled by `CHECK_INITIAL_STATE_DISABLED` environment variable. Problems
+/// of `input` are reported as compile errors in the returned tokens.
+pub fn expand_check_for(input: TokenStream) -> TokenStream {
+	let input = match syn::parse2::<CheckForInput>(input) {
+		Ok(input) => input,
+		Err(err) => return err.to_compile_error(),
+	};
+	let container = ContainerOptions::default();
+	let mut field_checks = TokenStream::new();
+	// Errors of all fields are reported at once
+	let mut errors = TokenStream::new();
+	for field in input.fields.iter() {
+		let options = match parse_field_options(field, &container) {
+			Ok(options) if options.ignored => continue,
+			Ok(options) => options,
+			Err(err) => {
+				errors.extend(err);
+				continue;
+			},
+		};
+		if options.delegated {
+			let ident = field_ident(field);
+			field_checks.extend(quote! {
+				::check_initial_state::CheckInitialState::check_initial_state(&instance.#ident);
+			});
+			continue;
+		}
+		let parsed_field = ParsedField { ident: field_ident(field), ty: &field.ty, options };
+		let field_name = parsed_field.report_name();
+		let violation = generate_violation(&parsed_field, &container, &quote!(instance));
+		field_checks.extend(quote! {
+			if let ::std::option::Option::Some(reason) = #violation {
+				::std::panic!("Field `{}` {}", #field_name, reason);
+			};
+		});
+	}
+	if !errors.is_empty() {
+		return errors;
+	}
+	let ty = &input.ty;
+	if field_checks.is_empty() {
+		return to_compile_error(ty, "`check_initial_state_for!` lists no checked fields");
+	}
+	let instance = &input.instance;
+	let dependency_tracking = generate_dependency_tracking(&container);
+	if disabled_switch::is_disabled() {
+		// The instance is still type checked
+		return quote! {
+			{
+				#dependency_tracking
+				let _: &#ty = &#instance;
+			}
+		};
+	}
+	return quote! {
+		{
+			#dependency_tracking
+			let instance: &#ty = &#instance;
+			#field_checks
+		}
+	};
+}
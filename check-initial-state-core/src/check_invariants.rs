@@ -0,0 +1,134 @@
+use crate::option_errors::OptionErrors;
+use crate::{
+	generate_coverage_off, generate_dependency_tracking, generics_with_bound,
+	parse_container_options, tag_message, to_compile_error, write_tokens, RustVersion,
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, DeriveInput, Error, MetaList};
+
+/// The struct annotated with this attribute declares an invariant, which holds
+/// between its fields, e.g. `#[invariant(self.start <= self.end)]`.
+const INVARIANT_ATTRIBUTE: &str = "invariant";
+
+/// Invariant declared with `invariant` attribute.
+struct Invariant {
+	/// Boolean expression, which refers to the instance as `self`.
+	condition: TokenStream,
+	/// The expression as it is written in the attribute, which panic messages
+	/// contain.
+	text: String,
+}
+
+/// Generates the implementation of `CheckInvariants` derive for `input`, i.e.
+/// `check_invariants()` method, which verifies the relationships between
+/// fields declared with `invariant` attributes. `check_initial_state`
+/// options, which tag messages or disable checks, are applied the same way as
+/// `CheckInitialState` derive applies them. Problems of `input` are reported
+/// as compile errors in the returned tokens.
+pub fn expand_check_invariants(input: &DeriveInput) -> TokenStream {
+	let options = match parse_container_options(input) {
+		Ok(options) => options,
+		Err(err) => return err,
+	};
+	let struct_name = &input.ident;
+	if options.remote.is_some() {
+		return to_compile_error(
+			struct_name,
+			"`CheckInvariants` derive cannot be combined with `remote` option",
+		);
+	}
+	let mut invariants = Vec::new();
+	let mut errors = OptionErrors::default();
+	let attributes =
+		input.attrs.iter().filter(|attribute| attribute.path().is_ident(INVARIANT_ATTRIBUTE));
+	for attribute in attributes {
+		match parse_invariant(attribute) {
+			Ok(invariant) => invariants.push(invariant),
+			Err(error) => errors.push(error),
+		}
+	}
+	if let Err(err) = errors.finish() {
+		return err;
+	}
+	if invariants.is_empty() {
+		let message = format!(
+			"`{}` has no `invariant` attribute, e.g. `#[invariant(self.start <= self.end)]`",
+			struct_name,
+		);
+		return to_compile_error(struct_name, &message);
+	}
+	let generics = generics_with_bound(input, &options);
+	let (leading_generics, trailing_generics, where_clause) = &generics.split_for_impl();
+	let track_caller = if RustVersion::supports(options.msrv, RustVersion::TRACK_CALLER) {
+		quote! { #[track_caller] }
+	} else {
+		TokenStream::new()
+	};
+	let checks = if options.disabled {
+		TokenStream::new()
+	} else {
+		let format = tag_message(&options, "Invariant `{}` of `{}` doesn't hold");
+		let struct_name_string = struct_name.to_string();
+		let checks = invariants.iter().map(|Invariant { condition, text }| {
+			return quote! {
+				if !(#condition) {
+					::std::panic!(#format, #text, #struct_name_string);
+				}
+			};
+		});
+		quote! { #(#checks)* }
+	};
+	let coverage_off = generate_coverage_off();
+	let dependency_tracking = generate_dependency_tracking(&options);
+	return quote! {
+		#[automatically_derived]
+		#[allow(dead_code, clippy::panic)]
+		impl #leading_generics #struct_name #trailing_generics #where_clause {
+			/// Checks all invariants declared with `invariant` attributes to
+			/// hold, in the order of declaration. Is expected to be used for
+			/// testing purposes, e.g. right after construction.
+			/// # Panics
+			/// Any of the invariants doesn't hold. Panic message will contain
+			/// the first such invariant.
+			#track_caller
+			#coverage_off
+			fn check_invariants(&self) {
+				#checks
+			}
+		}
+
+		#dependency_tracking
+	};
+}
+
+/// Parses `invariant` attribute, which expects a boolean expression in
+/// parentheses, e.g. `#[invariant(self.start <= self.end)]`. The expression
+/// is not parsed, so the compiler reports its problems in place.
+fn parse_invariant(attribute: &Attribute) -> syn::Result<Invariant> {
+	let list = match attribute.meta.require_list() {
+		Ok(list) if !list.tokens.is_empty() => list,
+		_ => {
+			let message = "`invariant` attribute expects a boolean expression, e.g. \
+				`#[invariant(self.start <= self.end)]`";
+			return Err(Error::new_spanned(attribute, message));
+		},
+	};
+	return Ok(Invariant { condition: list.tokens.clone(), text: invariant_text(list) });
+}
+
+/// Renders the expression of `invariant` attribute as it is written in the
+/// source. The tokens are rendered if the source is unavailable, e.g. outside
+/// of a procedural macro.
+fn invariant_text(list: &MetaList) -> String {
+	let source = list.delimiter.span().join().source_text();
+	let parenthesized = source.as_deref()
+		.and_then(|source| source.strip_prefix('('))
+		.and_then(|source| source.strip_suffix(')'));
+	if let Some(text) = parenthesized {
+		return text.split_whitespace().collect::<Vec<_>>().join(" ");
+	}
+	let mut text = String::new();
+	write_tokens(list.tokens.clone(), &mut text);
+	return text;
+}
@@ -0,0 +1,145 @@
+use crate::option_errors::OptionErrors;
+use crate::{
+	generate_check_prelude, generate_coverage_off, generate_dependency_tracking,
+	generate_violation, generics_with_bound, parse_sibling_derive, tag_message, to_compile_error,
+	ParsedField, RustVersion,
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, Data, DeriveInput, Error, Expr, ExprLit, Field, Lit, Meta};
+
+/// Fields which are annotated with this attribute are populated in the phase
+/// specified as its value, e.g. `#[phase = 1]`.
+const PHASE_ATTRIBUTE: &str = "phase";
+
+/// Generates the implementation of `CheckPhase` derive for `input`, i.e.
+/// `check_phase()` method, which verifies that fields annotated with `phase`
+/// are populated in the declared order. Fields are checked by the same
+/// attributes as `CheckInitialState` derive uses, while fields without `phase`
+/// attribute are not checked. Problems of `input` are reported as compile
+/// errors in the returned tokens.
+pub fn expand_check_phase(input: &DeriveInput) -> TokenStream {
+	let (options, fields) = match parse_sibling_derive(input, "CheckPhase") {
+		Ok(parsed) => parsed,
+		Err(err) => return err,
+	};
+	let struct_name = &input.ident;
+	let data_fields = match &input.data {
+		Data::Struct(data_struct) => &data_struct.fields,
+		_ => unreachable!("Fields of other items than structs are never parsed"),
+	};
+	let mut phased_fields = Vec::new();
+	let mut errors = OptionErrors::default();
+	for (field, parsed_field) in data_fields.iter().zip(&fields) {
+		match parse_phase(field, parsed_field) {
+			Ok(Some(phase)) => phased_fields.push((phase, parsed_field)),
+			Ok(None) => (),
+			Err(error) => errors.push(error),
+		}
+	}
+	if let Err(err) = errors.finish() {
+		return err;
+	}
+	if phased_fields.is_empty() {
+		let message = format!(
+			"No field of `{}` is annotated with `phase` attribute, e.g. `#[phase = 1]`",
+			struct_name,
+		);
+		return to_compile_error(struct_name, &message);
+	}
+	let generics = generics_with_bound(input, &options);
+	let (leading_generics, trailing_generics, where_clause) = &generics.split_for_impl();
+	let track_caller = if RustVersion::supports(options.msrv, RustVersion::TRACK_CALLER) {
+		quote! { #[track_caller] }
+	} else {
+		TokenStream::new()
+	};
+	let check = if options.disabled {
+		quote! { let _ = n; }
+	} else {
+		let check_prelude = generate_check_prelude(&options);
+		let unset_format = tag_message(&options, "Field `{}` of phase {} is not set in phase {}");
+		let set_format = tag_message(&options, "Field `{}` of phase {} {} in phase {}");
+		let field_checks = phased_fields.iter().map(|(phase, field)| {
+			let field_name = field.report_name();
+			let violation = generate_violation(field, &options, &quote!(self));
+			return quote! {
+				match (#violation, #phase) {
+					(::std::option::Option::None, phase) if phase < n => {
+						::std::panic!(#unset_format, #field_name, phase, n);
+					},
+					(::std::option::Option::Some(reason), phase) if phase > n => {
+						::std::panic!(#set_format, #field_name, phase, reason, n);
+					},
+					_ => (),
+				}
+			};
+		});
+		quote! {
+			#check_prelude
+			#(#field_checks)*
+		}
+	};
+	let coverage_off = generate_coverage_off();
+	let dependency_tracking = generate_dependency_tracking(&options);
+	return quote! {
+		#[automatically_derived]
+		#[allow(dead_code, clippy::panic)]
+		impl #leading_generics #struct_name #trailing_generics #where_clause {
+			/// Checks the struct to be populated up to phase `n`, i.e. all
+			/// fields of earlier phases to be out of initial state, and all
+			/// fields of later phases to be in initial state. Fields of phase
+			/// `n` itself may be in either state. Is expected to be used for
+			/// testing the construction order of a builder.
+			/// # Panics
+			/// A field of an earlier phase is in initial state, or a field of a
+			/// later phase is not. Panic message will contain the name of the
+			/// first such field.
+			#track_caller
+			#coverage_off
+			fn check_phase(&self, n: u32) {
+				#check
+			}
+		}
+
+		#dependency_tracking
+	};
+}
+
+/// Parses `phase` attribute of `field`, which expects an integer, e.g.
+/// `#[phase = 1]`. `None` is returned if the field has no such attribute.
+/// # Parameters
+/// * `parsed_field` &ndash; Options of `field`. Ignored fields cannot be
+/// phased.
+fn parse_phase(field: &Field, parsed_field: &ParsedField) -> syn::Result<Option<u32>> {
+	let mut attributes =
+		field.attrs.iter().filter(|attribute| attribute.path().is_ident(PHASE_ATTRIBUTE));
+	let attribute = match attributes.next() {
+		Some(attribute) => attribute,
+		None => return Ok(None),
+	};
+	if let Some(other) = attributes.next() {
+		return Err(Error::new_spanned(other, "Field may have a single `phase` attribute only"));
+	}
+	if parsed_field.options.ignored {
+		return Err(Error::new_spanned(
+			attribute,
+			"`phase` attribute cannot be combined with `ignore_field`",
+		));
+	}
+	return match &attribute.meta {
+		Meta::NameValue(name_value) => {
+			match &name_value.value {
+				Expr::Lit(ExprLit { lit: Lit::Int(phase), .. }) => phase.base10_parse().map(Some),
+				_ => Err(malformed_phase(attribute)),
+			}
+		},
+		_ => Err(malformed_phase(attribute)),
+	};
+}
+
+/// Creates an error of malformed `phase` attribute.
+fn malformed_phase(attribute: &Attribute) -> Error {
+	let message = "`phase` attribute expects an integer, e.g. `#[phase = 1]`";
+	return Error::new_spanned(attribute, message);
+}
@@ -0,0 +1,71 @@
+use crate::container_attribute::ContainerOptions;
+use crate::{disabled_switch, generate_dependency_tracking, to_compile_error};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{ItemFn, ReturnType};
+
+/// Error of `checked_constructor` attribute placed on other item than a
+/// function.
+const MISPLACED_MESSAGE: &str = "`checked_constructor` attribute is applicable to functions only";
+
+/// Generates the implementation of `checked_constructor` attribute macro. The
+/// body of `item` function is wrapped, so in debug builds the returned instance
+/// is checked with `check_initial_state()` before it is returned. Bodies of
+/// `async` functions are awaited first. The function is left intact if checks
+/// are disabled by `CHECK_INITIAL_STATE_DISABLED` environment variable. Problems
+/// of `args` or `item` are reported as compile errors in the returned tokens.
+pub fn expand_checked_constructor(args: TokenStream, item: TokenStream) -> TokenStream {
+	if !args.is_empty() {
+		return to_compile_error(args, "`checked_constructor` attribute takes no options");
+	}
+	let function = match syn::parse2::<ItemFn>(item) {
+		Ok(function) => function,
+		Err(err) => return syn::Error::new(err.span(), MISPLACED_MESSAGE).to_compile_error(),
+	};
+	let signature = &function.sig;
+	if let Some(constness) = &signature.constness {
+		return to_compile_error(
+			constness,
+			"`checked_constructor` attribute is not applicable to `const` functions",
+		);
+	}
+	let ty = match &signature.output {
+		ReturnType::Type(_, ty) => ty,
+		ReturnType::Default => {
+			return to_compile_error(
+				signature,
+				"`checked_constructor` attribute expects a function returning the constructed \
+					instance",
+			);
+		},
+	};
+	let dependency_tracking = generate_dependency_tracking(&ContainerOptions::default());
+	let attrs = &function.attrs;
+	let vis = &function.vis;
+	let body = &function.block;
+	if disabled_switch::is_disabled() {
+		return quote! {
+			#(#attrs)*
+			#vis #signature {
+				#dependency_tracking
+				#body
+			}
+		};
+	}
+	// `return` statements of the body must leave the wrapper, not the function
+	let constructed = if signature.asyncness.is_some() {
+		quote!(async move #body.await)
+	} else {
+		quote!((move || -> #ty #body)())
+	};
+	return quote! {
+		#(#attrs)*
+		#vis #signature {
+			#dependency_tracking
+			let instance: #ty = #constructed;
+			#[cfg(debug_assertions)]
+			::check_initial_state::CheckInitialState::check_initial_state(&instance);
+			return instance;
+		}
+	};
+}
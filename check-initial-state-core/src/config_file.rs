@@ -0,0 +1,344 @@
+use crate::container_attribute::EmptyCheckPolicy;
+use crate::rust_version::RustVersion;
+use std::collections::HashMap;
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use syn::{Ident, Visibility};
+
+/// Name of the file defining defaults of container options.
+pub const CONFIG_FILE_NAME: &str = "check_initial_state.toml";
+
+/// Environment variable which Cargo sets to the directory of the package being
+/// compiled.
+const MANIFEST_DIR_VARIABLE: &str = "CARGO_MANIFEST_DIR";
+
+/// Manifest of a package or a workspace.
+const MANIFEST_FILE_NAME: &str = "Cargo.toml";
+
+/// Header of a manifest normalized by `cargo package`, which a package
+/// published to a registry has.
+const NORMALIZED_MANIFEST_HEADER: &str = "# THIS FILE IS AUTOMATICALLY GENERATED BY CARGO";
+
+/// Key of the config file which makes `lenient` option the default.
+const LENIENT_KEY: &str = "lenient";
+
+/// Key of the config file which makes `warn_ignored_option` option the
+/// default.
+const WARN_IGNORED_OPTION_KEY: &str = "warn_ignored_option";
+
+/// Key of the config file which makes `serde` option the default.
+const SERDE_KEY: &str = "serde";
+
+/// Key of the config file which specifies how to treat structs with no
+/// checked fields, i.e. `"allow"`, `"warn"` or `"deny"`.
+const EMPTY_CHECK_KEY: &str = "empty_check";
+
+/// Key of the config file which specifies the default of `msrv` option.
+const MSRV_KEY: &str = "msrv";
+
+/// Key of the config file which enables strict mode, i.e. makes both
+/// `warn_ignored_option` and `deny_empty_check` options the default.
+const STRICT_KEY: &str = "strict";
+
+/// Key of the config file which specifies the default setters naming
+/// convention of `setter_hint` option.
+const SETTER_HINT_KEY: &str = "setter_hint";
+
+/// Key of the config file which specifies the default of `visibility` option.
+const VISIBILITY_KEY: &str = "visibility";
+
+/// All keys of the config file.
+const KNOWN_KEYS: [&str; 8] = [
+	LENIENT_KEY,
+	WARN_IGNORED_OPTION_KEY,
+	SERDE_KEY,
+	EMPTY_CHECK_KEY,
+	MSRV_KEY,
+	STRICT_KEY,
+	SETTER_HINT_KEY,
+	VISIBILITY_KEY,
+];
+
+/// Results of [`ConfigFile::from_package()`] by `CARGO_MANIFEST_DIR`, since
+/// each derive of a package looks the config file up.
+type ConfigCache = Mutex<HashMap<OsString, Result<Option<ConfigFile>, String>>>;
+
+/// Defaults of container options read from `check_initial_state.toml`. Options
+/// of `check_initial_state` container attribute are applied on top of them.
+#[derive(Clone, Default)]
+pub struct ConfigFile {
+	/// Path of the read file.
+	pub path: PathBuf,
+	/// Whether `lenient` option is enabled for all structs.
+	pub lenient: bool,
+	/// Whether `warn_ignored_option` option is enabled for all structs.
+	pub warn_ignored_option: bool,
+	/// Whether `serde` option is enabled for all structs.
+	pub serde: bool,
+	/// How to treat structs with no checked fields.
+	pub empty_check: EmptyCheckPolicy,
+	/// Minimal Rust version the generated code must compile with. Takes
+	/// precedence over `rust-version` of the package.
+	pub msrv: Option<RustVersion>,
+	/// Setters naming convention, with `{}` standing for a field name, which
+	/// panic messages suggest for all structs.
+	pub setter_hint: Option<String>,
+	/// Visibility of functions, constants and enums generated for all structs,
+	/// e.g. `pub(crate)`. Is kept as text, since parsed tokens cannot be shared
+	/// between derives.
+	pub visibility: Option<String>,
+}
+
+impl ConfigFile {
+	/// Finds the config file of the package being compiled, which is located by
+	/// `CARGO_MANIFEST_DIR` environment variable. `None` is returned if there is
+	/// no such file or the variable is not set. The file is found and read once
+	/// per package directory, while the result is reused by later derives.
+	/// # Errors
+	/// The file exists, but cannot be read or is malformed.
+	pub fn from_package() -> Result<Option<Self>, String> {
+		static CACHE: OnceLock<ConfigCache> = OnceLock::new();
+		let manifest_dir = match env::var_os(MANIFEST_DIR_VARIABLE) {
+			Some(manifest_dir) => manifest_dir,
+			None => return Ok(None),
+		};
+		let mut cache = CACHE.get_or_init(ConfigCache::default)
+			.lock()
+			// A panic while reading leaves no partial entries
+			.unwrap_or_else(|err| err.into_inner());
+		return cache.entry(manifest_dir)
+			.or_insert_with_key(|manifest_dir| Self::find(Path::new(manifest_dir)))
+			.clone();
+	}
+
+	/// Finds the config file in package `directory` or its closest ancestor, up
+	/// to the workspace root, and reads it. Only `directory` itself is searched
+	/// if it is not a member of a workspace, e.g. if it is a package published
+	/// to a registry. `None` is returned if there is no such file.
+	/// # Errors
+	/// The file exists, but cannot be read or is malformed.
+	pub fn find(directory: &Path) -> Result<Option<Self>, String> {
+		let root = directory.ancestors()
+			.find(|ancestor| is_workspace_root(ancestor))
+			.unwrap_or(directory);
+		for directory in directory.ancestors() {
+			let path = directory.join(CONFIG_FILE_NAME);
+			if path.is_file() {
+				return Self::read(path).map(Some);
+			}
+			if directory == root {
+				break;
+			}
+		}
+		return Ok(None);
+	}
+
+	/// Reads the config file at `path`.
+	/// # Errors
+	/// The file cannot be read or is malformed.
+	pub fn read(path: PathBuf) -> Result<Self, String> {
+		let malformed = |message: &dyn std::fmt::Display| {
+			return format!("Malformed `{}`: {}", path.display(), message);
+		};
+		let text = fs::read_to_string(&path)
+			.map_err(|err| format!("Failed to read `{}`: {}", path.display(), err))?;
+		let entries = parse_entries(&text).map_err(|err| malformed(&err))?;
+		let mut config = Self::default();
+		for (key, value) in entries.iter() {
+			match *key {
+				LENIENT_KEY => {
+					config.lenient = parse_bool(key, value).map_err(|err| malformed(&err))?;
+				},
+				WARN_IGNORED_OPTION_KEY => {
+					config.warn_ignored_option =
+						parse_bool(key, value).map_err(|err| malformed(&err))?;
+				},
+				SERDE_KEY => {
+					config.serde = parse_bool(key, value).map_err(|err| malformed(&err))?;
+				},
+				EMPTY_CHECK_KEY => {
+					config.empty_check = match value.as_str() {
+						Some("allow") => EmptyCheckPolicy::Allow,
+						Some("warn") => EmptyCheckPolicy::Warn,
+						Some("deny") => EmptyCheckPolicy::Deny,
+						_ => {
+							return Err(malformed(
+								&"`empty_check` expects either \"allow\", \"warn\" or \"deny\"",
+							));
+						},
+					};
+				},
+				MSRV_KEY => {
+					config.msrv = value.as_str().and_then(RustVersion::parse);
+					if config.msrv.is_none() {
+						return Err(malformed(&"`msrv` expects a Rust version, e.g. \"1.40\""));
+					}
+				},
+				STRICT_KEY => {
+					if parse_bool(key, value).map_err(|err| malformed(&err))? {
+						let overridden_keys = [WARN_IGNORED_OPTION_KEY, EMPTY_CHECK_KEY];
+						let overridden = entries.iter().find(|(key, _)| overridden_keys.contains(key));
+						if let Some((overridden, _)) = overridden {
+							let message =
+								format!("`strict` cannot be combined with `{}`", overridden);
+							return Err(malformed(&message));
+						}
+						config.warn_ignored_option = true;
+						config.empty_check = EmptyCheckPolicy::Deny;
+					}
+				},
+				SETTER_HINT_KEY => {
+					let setter_hint = value.as_str().filter(|setter_hint| {
+						let expanded = setter_hint.replace("{}", "field");
+						return setter_hint.contains("{}")
+							&& syn::parse_str::<Ident>(&expanded).is_ok();
+					});
+					config.setter_hint = match setter_hint {
+						Some(setter_hint) => Some(setter_hint.to_owned()),
+						None => {
+							return Err(malformed(
+								&"`setter_hint` expects a setters naming convention with `{}` \
+									placeholder for a field name, e.g. \"set_{}\"",
+							));
+						},
+					};
+				},
+				VISIBILITY_KEY => {
+					let visibility = value.as_str()
+						.filter(|visibility| syn::parse_str::<Visibility>(visibility).is_ok());
+					config.visibility = match visibility {
+						Some(visibility) => Some(visibility.to_owned()),
+						None => {
+							return Err(malformed(
+								&"`visibility` expects a visibility, e.g. \"pub(crate)\"",
+							));
+						},
+					};
+				},
+				_ => {
+					let message = format!("unknown key `{}`, expected one of {:?}", key, KNOWN_KEYS);
+					return Err(malformed(&message));
+				},
+			}
+		}
+		config.path = path;
+		return Ok(config);
+	}
+}
+
+/// Value of a config file key. The file is parsed as the subset of TOML, which
+/// the supported keys need, so no TOML library is required.
+#[derive(Debug, PartialEq)]
+enum Value<'a> {
+	/// `true` or `false`.
+	Bool(bool),
+	/// Basic string without escapes, e.g. `"deny"`, or literal string, e.g.
+	/// `'deny'`.
+	String(&'a str),
+}
+
+impl<'a> Value<'a> {
+	/// The value if it is a boolean.
+	fn as_bool(&self) -> Option<bool> {
+		return match self {
+			Self::Bool(value) => Some(*value),
+			Self::String(_) => None,
+		};
+	}
+
+	/// The value if it is a string.
+	fn as_str(&self) -> Option<&'a str> {
+		return match self {
+			Self::Bool(_) => None,
+			Self::String(value) => Some(value),
+		};
+	}
+}
+
+/// Parses `text` of the config file into key-value pairs in the order of
+/// appearance. Each line is either blank, a comment starting with `#`, or
+/// `key = value` optionally followed by a comment, where the key is bare and
+/// the value is a boolean or a string. Tables, arrays, numbers, escapes and
+/// multi-line strings are rejected, since no key expects them.
+/// # Errors
+/// A line doesn't match the supported syntax, or a key is duplicated.
+fn parse_entries(text: &str) -> Result<Vec<(&str, Value<'_>)>, String> {
+	let mut entries = Vec::<(&str, Value<'_>)>::new();
+	for (index, line) in text.lines().enumerate() {
+		let line = line.trim();
+		if line.is_empty() || line.starts_with('#') {
+			continue;
+		}
+		let error = |message: &str| format!("line {}: {}", index + 1, message);
+		let (key, value) = match line.split_once('=') {
+			Some((key, value)) => (key.trim(), value.trim()),
+			None => return Err(error("expected `key = value`")),
+		};
+		let is_bare =
+			|character: char| character.is_ascii_alphanumeric() || "_-".contains(character);
+		if key.is_empty() || !key.chars().all(is_bare) {
+			return Err(error("expected a bare key, e.g. `lenient`"));
+		}
+		let (value, rest) = parse_value(value).map_err(|message| error(&message))?;
+		let rest = rest.trim_start();
+		if !rest.is_empty() && !rest.starts_with('#') {
+			return Err(error("expected a comment or the end of line after the value"));
+		}
+		if entries.iter().any(|(entry_key, _)| *entry_key == key) {
+			return Err(error(&format!("duplicate key `{}`", key)));
+		}
+		entries.push((key, value));
+	}
+	return Ok(entries);
+}
+
+/// Parses the value at the beginning of `text`.
+/// # Returns
+/// The value and the rest of `text` following it.
+/// # Errors
+/// `text` doesn't start with a boolean or a string without escapes.
+fn parse_value(text: &str) -> Result<(Value<'_>, &str), String> {
+	for quote in ['"', '\''].iter() {
+		if let Some(string) = text.strip_prefix(*quote) {
+			let end = string.find(*quote)
+				.ok_or_else(|| String::from("unterminated string"))?;
+			let value = &string[..end];
+			if *quote == '"' && value.contains('\\') {
+				return Err(String::from("escapes are not supported"));
+			}
+			return Ok((Value::String(value), &string[end + 1..]));
+		}
+	}
+	for (literal, value) in [("true", true), ("false", false)].iter() {
+		if let Some(rest) = text.strip_prefix(literal) {
+			if !rest.starts_with(|character: char| character.is_ascii_alphanumeric()) {
+				return Ok((Value::Bool(*value), rest));
+			}
+		}
+	}
+	return Err(String::from("expected a boolean or a string"));
+}
+
+/// Tells whether `directory` is the root of a workspace, i.e. its manifest
+/// declares `[workspace]` table. A package published to a registry is the
+/// root of its own workspace, since Cargo never looks for an enclosing one,
+/// e.g. when the package is vendored into another workspace.
+fn is_workspace_root(directory: &Path) -> bool {
+	let manifest = match fs::read_to_string(directory.join(MANIFEST_FILE_NAME)) {
+		Ok(manifest) => manifest,
+		Err(_) => return false,
+	};
+	return manifest.starts_with(NORMALIZED_MANIFEST_HEADER)
+		|| manifest.lines().map(str::trim).any(|line| {
+			// Subtables like `[workspace.dependencies]` declare the table as well
+			return line.starts_with("[workspace]") || line.starts_with("[workspace.");
+		});
+}
+
+/// Parses `value` of a boolean `key`.
+fn parse_bool(key: &str, value: &Value<'_>) -> Result<bool, String> {
+	return value.as_bool().ok_or_else(|| format!("`{}` expects a boolean", key));
+}
@@ -0,0 +1,948 @@
+use crate::config_file::ConfigFile;
+use crate::disabled_switch;
+use crate::option_errors::{parse_option, unknown_option, OptionErrors};
+use crate::rust_version::RustVersion;
+use crate::serde_attribute::{has_serde_option, DEFAULT_OPTIONS};
+use proc_macro2::{Span, TokenStream};
+use std::path::PathBuf;
+use syn::meta::ParseNestedMeta;
+use syn::parse::ParseStream;
+use syn::punctuated::Punctuated;
+use syn::token::{self, Comma};
+use syn::{
+	parenthesized, Attribute, DeriveInput, Error, Expr, Ident, LitInt, LitStr, Path, Visibility,
+	WherePredicate,
+};
+
+/// Container attribute which configures `CheckInitialState` proc macro for the
+/// whole struct.
+pub const CONTAINER_ATTRIBUTE: &str = "check_initial_state";
+
+/// Container attribute which lists other structs deriving `CheckInitialState`,
+/// e.g. `#[inherit_checks(Base)]`, which checked fields must be checked by
+/// the struct as well.
+const INHERIT_CHECKS_ATTRIBUTE: &str = "inherit_checks";
+
+/// Option of `check_initial_state` container attribute which specifies a
+/// single constructor to be verified.
+const CONSTRUCTOR_OPTION: &str = "constructor";
+
+/// Option of `check_initial_state` container attribute which specifies a list
+/// of constructors to be verified.
+const CONSTRUCTORS_OPTION: &str = "constructors";
+
+/// Option of `check_initial_state` container attribute which requests a
+/// fallible associated function for each constructor, e.g. `try_new()` for
+/// `Self::new`, which returns the constructed instance once it is verified.
+const TRY_CONSTRUCTORS_OPTION: &str = "try_constructors";
+
+/// Option of `check_initial_state` container attribute which requests
+/// additional tests checking clones of constructed instances.
+const CHECK_CLONE_OPTION: &str = "check_clone";
+
+/// Option of `check_initial_state` container attribute which requests
+/// additional tests verifying that each checked field is detected by the
+/// check.
+const MUTATION_TEST_OPTION: &str = "mutation_test";
+
+/// Option of `check_initial_state` container attribute which registers the
+/// struct along with its constructors for `verify_all()` of the runtime crate.
+const REGISTER_OPTION: &str = "register";
+
+/// Option of `check_initial_state` container attribute which specifies setters
+/// naming convention for `mutation_test`.
+const SETTER_OPTION: &str = "setter";
+
+/// Option of `check_initial_state` container attribute which specifies setters
+/// naming convention for hints in panic messages, e.g.
+/// `setter_hint = "set_{}"`. `setter` option is used if absent.
+const SETTER_HINT_OPTION: &str = "setter_hint";
+
+/// Option of `check_initial_state` container attribute which requests
+/// `INITIAL` associated constant generation.
+const INITIAL_CONST_OPTION: &str = "initial_const";
+
+/// Option of `check_initial_state` container attribute which requests an
+/// associated function creating an instance in initial state, e.g.
+/// `builder_fn` or `builder_fn = "new_builder"`.
+const BUILDER_FN_OPTION: &str = "builder_fn";
+
+/// Name of the function requested by `builder_fn` option, unless specified
+/// explicitly.
+const DEFAULT_BUILDER_FN: &str = "builder";
+
+/// Option of `check_initial_state` container attribute which requests
+/// `Default` implementation creating an instance in initial state.
+const DERIVE_DEFAULT_OPTION: &str = "derive_default";
+
+/// Option of `check_initial_state` container attribute which specifies a
+/// constructor of the reference instance to compare with.
+const COMPARE_WITH_OPTION: &str = "compare_with";
+
+/// Option of `check_initial_state` container attribute which maps types to
+/// methods telling whether a value of the type is empty, e.g.
+/// `empty_via(Vec = "is_empty")`.
+const EMPTY_VIA_OPTION: &str = "empty_via";
+
+/// Option of `check_initial_state` container attribute which lists types, e.g.
+/// `ignore_types(PhantomData, Instant)`, which fields are skipped by the check.
+const IGNORE_TYPES_OPTION: &str = "ignore_types";
+
+/// Option of `check_initial_state` container attribute which makes fields,
+/// that turn out not to be `Option`, skipped by the check instead of failing
+/// compilation.
+const LENIENT_OPTION: &str = "lenient";
+
+/// Option of `check_initial_state` container attribute which makes fields,
+/// that are not `Option`, checked if their type implements `IsUnset` or is a
+/// common standard type, e.g. `bool` or `Vec`.
+const AUTO_UNSET_OPTION: &str = "auto_unset";
+
+/// Option of `check_initial_state` container attribute which requests a
+/// warning for each `Option` field annotated with `ignore_field` without a
+/// `reason`.
+const WARN_IGNORED_OPTION_OPTION: &str = "warn_ignored_option";
+
+/// Option of `check_initial_state` container attribute which requests a
+/// warning if the struct has no checked fields.
+const WARN_EMPTY_CHECK_OPTION: &str = "warn_empty_check";
+
+/// Option of `check_initial_state` container attribute which requests a
+/// compile error if the struct has no checked fields.
+const DENY_EMPTY_CHECK_OPTION: &str = "deny_empty_check";
+
+/// Option of `check_initial_state` container attribute which requests `rstest`
+/// fixture generation. Is available with `rstest` feature only.
+const FIXTURE_OPTION: &str = "fixture";
+
+/// Option of `check_initial_state` container attribute which specifies the
+/// minimal Rust version the generated code must compile with.
+const MSRV_OPTION: &str = "msrv";
+
+/// Option of `check_initial_state` container attribute which requests
+/// `initial_state_errors()` method reporting violations as bits of `u32`.
+const ERROR_BITS_OPTION: &str = "error_bits";
+
+/// Option of `check_initial_state` container attribute which makes `serde`
+/// attributes decide which fields are ignored.
+const SERDE_OPTION: &str = "serde";
+
+/// Option of `check_initial_state` container attribute which requests a Kani
+/// proof harness for each constructor. Is available with `kani` feature only.
+const KANI_PROOF_OPTION: &str = "kani_proof";
+
+/// Option of `check_initial_state` container attribute which makes the struct
+/// a mirror of a foreign one, e.g. `remote = "other_crate::Builder"`, so the
+/// check is generated against the foreign struct.
+const REMOTE_OPTION: &str = "remote";
+
+/// Option of `check_initial_state` container attribute which makes
+/// `check_initial_state()` panic with `InitialStatePanic` payload instead of a
+/// message.
+const PANIC_PAYLOAD_OPTION: &str = "panic_payload";
+
+/// Option of `check_initial_state` container attribute which specifies
+/// predicates added to the where clause of generated impls, e.g.
+/// `bound = "T: Default"`.
+const BOUND_OPTION: &str = "bound";
+
+/// Option of `check_initial_state` container attribute which specifies text
+/// preceding each failure message, e.g. `message_prefix = "[hygiene] "`.
+const MESSAGE_PREFIX_OPTION: &str = "message_prefix";
+
+/// Option of `check_initial_state` container attribute which specifies text
+/// following each failure message.
+const MESSAGE_SUFFIX_OPTION: &str = "message_suffix";
+
+/// Option of `check_initial_state` container attribute which adds values of
+/// fields to the state table. Is available with `table` feature only.
+const TABLE_VALUES_OPTION: &str = "table_values";
+
+/// Option of `check_initial_state` container attribute which specifies the
+/// number of characters values in the state table are truncated to, e.g.
+/// `max_value_len = 40`.
+const MAX_VALUE_LEN_OPTION: &str = "max_value_len";
+
+/// Option of `check_initial_state` container attribute which requests
+/// `violations()` method lazily yielding names of violating fields.
+const VIOLATIONS_ITER_OPTION: &str = "violations_iter";
+
+/// Option of `check_initial_state` container attribute which records whether
+/// the struct was checked for `report_unchecked()` of the runtime crate.
+const TRACK_USAGE_OPTION: &str = "track_usage";
+
+/// Option of `check_initial_state` container attribute which requests an enum
+/// of checked fields and `try_check_initial_state_fields()` method reporting
+/// its variants.
+const FIELD_ENUM_OPTION: &str = "field_enum";
+
+/// Option of `check_initial_state` container attribute which requests
+/// `Display` implementation rendering the state of all checked fields.
+const DERIVE_DISPLAY_OPTION: &str = "derive_display";
+
+/// Option of `check_initial_state` container attribute which requests a test
+/// of the builder generated by `bon::Builder` derive. Is available with `bon`
+/// feature only.
+const BON_OPTION: &str = "bon";
+
+/// Option of `check_initial_state` container attribute which requests
+/// `check_initial_state_no_panic()` method verified by `no_panic` crate. Is
+/// available with `no-panic` feature only.
+const NO_PANIC_OPTION: &str = "no_panic";
+
+/// Option of `check_initial_state` container attribute which adds a constructor
+/// creating the struct through the builder of `buildstructor` crate without
+/// setting anything, e.g. `buildstructor` or
+/// `buildstructor(entry = "builder", exit = "build")`.
+const BUILDSTRUCTOR_OPTION: &str = "buildstructor";
+
+/// Option of `check_initial_state` container attribute which specifies the
+/// path generated code refers to the runtime crate by, e.g.
+/// `crate = "::my_facade::check"`.
+const CRATE_OPTION: &str = "crate";
+
+/// Option of `check_initial_state` container attribute which specifies the
+/// visibility of generated functions, constants and enums, e.g.
+/// `visibility = "pub(crate)"`. They have the visibility of the struct
+/// otherwise.
+const VISIBILITY_OPTION: &str = "visibility";
+
+/// Name of the function creating the builder of `buildstructor` crate for `new`
+/// constructor, unless `entry` option renames it.
+const DEFAULT_BUILDSTRUCTOR_ENTRY: &str = "builder";
+
+/// Name of the builder method of `buildstructor` crate calling the
+/// constructor, unless `exit` option renames it.
+const DEFAULT_BUILDSTRUCTOR_EXIT: &str = "build";
+
+/// All options of `check_initial_state` container attribute.
+const KNOWN_OPTIONS: [&str; 40] = [
+	CONSTRUCTOR_OPTION,
+	CONSTRUCTORS_OPTION,
+	TRY_CONSTRUCTORS_OPTION,
+	CHECK_CLONE_OPTION,
+	MUTATION_TEST_OPTION,
+	REGISTER_OPTION,
+	SETTER_OPTION,
+	SETTER_HINT_OPTION,
+	INITIAL_CONST_OPTION,
+	BUILDER_FN_OPTION,
+	DERIVE_DEFAULT_OPTION,
+	COMPARE_WITH_OPTION,
+	EMPTY_VIA_OPTION,
+	IGNORE_TYPES_OPTION,
+	LENIENT_OPTION,
+	AUTO_UNSET_OPTION,
+	WARN_IGNORED_OPTION_OPTION,
+	WARN_EMPTY_CHECK_OPTION,
+	DENY_EMPTY_CHECK_OPTION,
+	FIXTURE_OPTION,
+	MSRV_OPTION,
+	ERROR_BITS_OPTION,
+	SERDE_OPTION,
+	KANI_PROOF_OPTION,
+	REMOTE_OPTION,
+	PANIC_PAYLOAD_OPTION,
+	BOUND_OPTION,
+	MESSAGE_PREFIX_OPTION,
+	MESSAGE_SUFFIX_OPTION,
+	TABLE_VALUES_OPTION,
+	MAX_VALUE_LEN_OPTION,
+	VIOLATIONS_ITER_OPTION,
+	TRACK_USAGE_OPTION,
+	FIELD_ENUM_OPTION,
+	DERIVE_DISPLAY_OPTION,
+	BON_OPTION,
+	NO_PANIC_OPTION,
+	BUILDSTRUCTOR_OPTION,
+	CRATE_OPTION,
+	VISIBILITY_OPTION,
+];
+
+/// Attribute which specifies the struct layout.
+const REPR_ATTRIBUTE: &str = "repr";
+
+/// `repr` hint which requests the struct layout without padding.
+const PACKED_REPR_HINT: &str = "packed";
+
+/// `repr` hint which requests the layout of the single non&#x2011;zero&#x2011;sized
+/// field, i.e. of the wrapped type of a newtype.
+const TRANSPARENT_REPR_HINT: &str = "transparent";
+
+/// Number of characters values in the state table are truncated to, unless
+/// `max_value_len` option specifies another one.
+pub const DEFAULT_MAX_VALUE_LEN: usize = 100;
+
+/// Placeholder which is replaced with a field name in `setter` and
+/// `setter_hint` option values.
+const FIELD_NAME_PLACEHOLDER: &str = "{}";
+
+/// Options gathered from all `check_initial_state` container attributes of a
+/// struct.
+#[derive(Default)]
+pub struct ContainerOptions {
+	/// Expressions which construct an instance of the struct. A test is
+	/// generated for each of them, checking the constructed instance with
+	/// `check_initial_state()`.
+	pub constructors: Vec<Expr>,
+	/// Whether to generate a fallible associated function for each
+	/// constructor, which is a path to a function, e.g. `try_new()` for
+	/// `Self::new`. The function returns the constructed instance if it is in
+	/// initial state, and `InitialStateError` otherwise.
+	pub try_constructors: bool,
+	/// Whether to generate a test for each constructor checking that a clone
+	/// of the constructed instance is in initial state as well.
+	pub check_clone: bool,
+	/// Whether to generate a test for each constructor, which sets checked
+	/// fields one at a time and expects `check_initial_state()` to panic
+	/// naming the set field.
+	pub mutation_test: bool,
+	/// Whether to register the struct at link time, so `verify_all()` of the
+	/// runtime crate checks an instance built with each constructor.
+	pub register: bool,
+	/// Setters naming convention used by the mutation test, with `{}` standing
+	/// for a field name, e.g. `set_{}`. Fields are assigned directly if
+	/// absent.
+	pub setter: Option<String>,
+	/// Setters naming convention, with `{}` standing for a field name, which
+	/// panic messages suggest as the likely origin of a stray value, e.g.
+	/// "was it set via `set_endpoint(...)`?". Is `setter` if not specified
+	/// explicitly. Nothing is suggested if absent.
+	pub setter_hint: Option<String>,
+	/// Whether to generate `INITIAL` associated constant.
+	pub initial_const: bool,
+	/// Name of the associated function creating an instance in initial state,
+	/// if it is requested by `builder_fn` option.
+	pub builder_fn: Option<Ident>,
+	/// Whether to implement `Default`, which creates an instance in initial
+	/// state.
+	pub derive_default: bool,
+	/// Constructor of the reference instance. If present, checked fields are
+	/// compared with the ones of the reference instance instead of being
+	/// expected to be `None`.
+	pub compare_with: Option<Expr>,
+	/// Types, which fields are checked with the paired method instead of being
+	/// expected to be `None`, unless they are annotated with a check
+	/// attribute. The method is expected to return `true` for an empty value.
+	pub empty_via: Vec<(Path, Ident)>,
+	/// Types, which fields are ignored unless they are annotated with a check
+	/// attribute, `ignore_field` or `delegate_check`.
+	pub ignore_types: Vec<Path>,
+	/// Whether non&#x2011;annotated fields, which are not `Option`, are
+	/// skipped by the check.
+	pub lenient: bool,
+	/// Whether non&#x2011;annotated fields, which are not `Option`, are
+	/// checked through `IsUnset` trait or the emptiness of common standard
+	/// types. Takes precedence over `lenient` for such fields.
+	pub auto_unset: bool,
+	/// Whether to warn about `Option` fields, which are annotated with
+	/// `ignore_field` without a `reason`.
+	pub warn_ignored_option: bool,
+	/// How to treat a struct which has no checked fields, so its check is
+	/// vacuous.
+	pub empty_check: EmptyCheckPolicy,
+	/// Whether to generate `rstest` fixture returning an instance constructed
+	/// by the first constructor.
+	pub fixture: bool,
+	/// Whether to generate a Kani proof harness for each constructor, which
+	/// proves the constructed instance to be in initial state.
+	pub kani_proof: bool,
+	/// Minimal Rust version the generated code must compile with. Is
+	/// `rust-version` of the package if not specified explicitly. The newest
+	/// codegen is used if unknown.
+	pub msrv: Option<RustVersion>,
+	/// Whether to generate `initial_state_errors()` method, which reports
+	/// violations as bits of `u32` without panicking or allocating.
+	pub error_bits: bool,
+	/// Whether fields skipped or defaulted by `serde` attributes are ignored,
+	/// unless they are annotated explicitly.
+	pub serde: bool,
+	/// Whether the struct is annotated with `#[serde(default)]`, so all its
+	/// fields are defaulted. Is taken into account in `serde` mode only.
+	pub serde_default: bool,
+	/// Foreign struct which the struct mirrors. If present, the check is
+	/// generated as associated functions of the struct taking a reference to
+	/// the foreign one, since `CheckInitialState` trait cannot be implemented
+	/// for a foreign type.
+	pub remote: Option<Path>,
+	/// Whether `check_initial_state()` panics with `InitialStatePanic` payload,
+	/// which tests may downcast, instead of a message.
+	pub panic_payload: bool,
+	/// Predicates which are added to the where clause of the struct in
+	/// generated impls, like `#[serde(bound = "...")]` does. Allow generated
+	/// code, e.g. `compare_with` constructor, to rely on bounds the struct
+	/// itself doesn't declare.
+	pub bound: Punctuated<WherePredicate, Comma>,
+	/// Text preceding each panic message and report line, e.g. a tag which CI
+	/// log scrapers look for.
+	pub message_prefix: String,
+	/// Text following each panic message and report line.
+	pub message_suffix: String,
+	/// Whether the state table, which is appended to panic messages with
+	/// `table` feature, prints values of fields implementing `Debug`.
+	pub table_values: bool,
+	/// Number of characters values in the state table are truncated to. Is
+	/// `DEFAULT_MAX_VALUE_LEN` if not specified explicitly.
+	pub max_value_len: Option<usize>,
+	/// Whether to generate `violations()` method, which lazily yields names of
+	/// fields which are not in initial state without allocating.
+	pub violations_iter: bool,
+	/// Whether to record that the struct was checked, so `report_unchecked()`
+	/// of the runtime crate lists it until its check is called.
+	pub track_usage: bool,
+	/// Whether to generate an enum of checked fields and
+	/// `try_check_initial_state_fields()` method, which reports fields that
+	/// are not in initial state as its variants.
+	pub field_enum: bool,
+	/// Whether to implement `Display`, which renders the state of all checked
+	/// fields like the state table does.
+	pub derive_display: bool,
+	/// Whether to generate a test verifying the initial state of the builder
+	/// generated by `bon::Builder` derive.
+	pub bon: bool,
+	/// Whether to generate `check_initial_state_no_panic()` method, which
+	/// returns the first violation instead of panicking and is annotated with
+	/// `no_panic` attribute.
+	pub no_panic: bool,
+	/// Path of `check-initial-state` runtime crate, which generated code
+	/// refers to instead of `::check_initial_state`. Allows frameworks
+	/// re&#x2011;exporting the runtime crate under their own name to use the
+	/// derive, like `#[serde(crate = "...")]` does.
+	pub crate_path: Option<Path>,
+	/// Visibility of generated associated functions and constants, free
+	/// functions of `remote` mode and the enum of `field_enum` option. Is the
+	/// visibility of the struct if not specified explicitly.
+	pub visibility: Option<Visibility>,
+	/// Structs deriving `CheckInitialState`, which fields are embedded into the
+	/// struct, e.g. by a macro. Fields checked by them must be checked by the
+	/// struct as well, which is verified at compile time. Is not an option,
+	/// but is listed by `inherit_checks` attribute.
+	pub inherit_checks: Vec<Path>,
+	/// Whether the struct is `#[repr(packed)]`. Is not an option, but is
+	/// derived from `repr` attribute. Fields of a packed struct are copied
+	/// before being checked, since references to them may be unaligned.
+	pub packed: bool,
+	/// Whether the struct is `#[repr(transparent)]`. Is not an option, but is
+	/// derived from `repr` attribute. Checks of a transparent newtype may be
+	/// delegated to the wrapped field.
+	pub transparent: bool,
+	/// Path of `check_initial_state.toml`, which defaults of the options are
+	/// read from. Is not an option, but is found by the package directory.
+	pub config_file: Option<PathBuf>,
+	/// Whether checks are disabled, so the generated methods verify nothing.
+	/// Is not an option, but is requested by `CHECK_INITIAL_STATE_DISABLED`
+	/// environment variable.
+	pub disabled: bool,
+}
+
+/// Tells how to treat a struct which has all fields ignored.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum EmptyCheckPolicy {
+	/// Nothing is reported. Is the default.
+	Allow,
+	/// A warning is reported. Is requested by `warn_empty_check` option.
+	Warn,
+	/// A compile error is reported. Is requested by `deny_empty_check` option.
+	Deny,
+}
+
+impl Default for EmptyCheckPolicy {
+	fn default() -> Self {
+		return Self::Allow;
+	}
+}
+
+/// Parses all `check_initial_state` attributes attached to the struct itself.
+/// Parsing error is returned in case of unknown option or malformed option
+/// value. All such problems are reported at once.
+pub fn parse_container_options(input: &DeriveInput) -> Result<ContainerOptions, TokenStream> {
+	let mut options = ContainerOptions::default();
+	let mut errors = OptionErrors::default();
+	// Defaults of the workspace, which container attributes are applied on top of
+	let mut default_setter_hint = None;
+	match ConfigFile::from_package() {
+		Ok(Some(config)) => {
+			options.lenient = config.lenient;
+			options.warn_ignored_option = config.warn_ignored_option;
+			options.serde = config.serde;
+			options.empty_check = config.empty_check;
+			options.msrv = config.msrv;
+			// The config file has validated the visibility
+			options.visibility = config.visibility
+				.and_then(|visibility| syn::parse_str::<Visibility>(&visibility).ok());
+			default_setter_hint = config.setter_hint;
+			options.config_file = Some(config.path);
+		},
+		Ok(None) => {},
+		Err(message) => errors.push(Error::new(Span::call_site(), message)),
+	}
+	// Options which make sense only when constructors are specified
+	let mut constructor_dependent = Vec::new();
+	let mut setter_path = None;
+	let mut initial_const_path = None;
+	let mut builder_fn_path = None;
+	let mut derive_default_path = None;
+	let mut max_value_len_path = None;
+	// Options which generate code constructing or referring to `Self`, so they
+	// make no sense for a mirror of a foreign struct
+	let mut self_dependent = Vec::new();
+	for attribute in input.attrs.iter().filter(|attribute| is_container_attribute(attribute)) {
+		if attribute.meta.require_list().is_err() {
+			errors.push(Error::new_spanned(
+				&attribute.meta,
+				"`check_initial_state` attribute expects a list of options, e.g. \
+					`#[check_initial_state(constructor = \"Self::new\")]`",
+			));
+			continue;
+		}
+		let result = attribute.parse_nested_meta(|option| {
+			parse_option(&option, &mut errors, |option| {
+				let path = option.path.clone();
+				if path.is_ident(CONSTRUCTOR_OPTION) {
+					options.constructors.push(parse_constructor(option.value()?)?);
+					self_dependent.push(path);
+				} else if path.is_ident(CONSTRUCTORS_OPTION) {
+					self_dependent.push(path);
+					let content;
+					parenthesized!(content in option.input);
+					let constructors = Punctuated::<LitStr, Comma>::parse_terminated(&content)
+						.map_err(|err| {
+							return Error::new(
+								err.span(),
+								"`constructors` option expects string literals, e.g. \
+									`constructors(\"Self::new\", \"Self::default\")`",
+							);
+						})?;
+					for constructor in constructors {
+						options.constructors.push(parse_constructor_literal(&constructor)?);
+					}
+				} else if path.is_ident(TRY_CONSTRUCTORS_OPTION) {
+					options.try_constructors = true;
+					constructor_dependent.push(path);
+				} else if path.is_ident(CHECK_CLONE_OPTION) {
+					options.check_clone = true;
+					constructor_dependent.push(path);
+				} else if path.is_ident(MUTATION_TEST_OPTION) {
+					options.mutation_test = true;
+					constructor_dependent.push(path);
+				} else if path.is_ident(REGISTER_OPTION) {
+					options.register = true;
+					constructor_dependent.push(path);
+				} else if path.is_ident(COMPARE_WITH_OPTION) {
+					options.compare_with = Some(parse_constructor(option.value()?)?);
+					self_dependent.push(path);
+				} else if path.is_ident(EMPTY_VIA_OPTION) {
+					option.parse_nested_meta(|mapping| {
+						options.empty_via.push(parse_empty_via_mapping(&mapping)?);
+						return Ok(());
+					})?;
+				} else if path.is_ident(IGNORE_TYPES_OPTION) {
+					let content;
+					parenthesized!(content in option.input);
+					let types = Punctuated::<Path, Comma>::parse_terminated_with(
+						&content,
+						Path::parse_mod_style,
+					);
+					let types = types.map_err(|err| {
+						return Error::new(
+							err.span(),
+							"`ignore_types` option expects type names, e.g. \
+								`ignore_types(PhantomData, Instant)`",
+						);
+					})?;
+					options.ignore_types.extend(types);
+				} else if path.is_ident(INITIAL_CONST_OPTION) {
+					options.initial_const = true;
+					initial_const_path = Some(path.clone());
+					self_dependent.push(path);
+				} else if path.is_ident(BUILDER_FN_OPTION) {
+					let name = if option.input.peek(token::Eq) {
+						parse_name(
+							option.value()?,
+							"`builder_fn` option expects a function name, e.g. \
+								`builder_fn = \"new_builder\"`",
+						)?
+					} else {
+						Ident::new(DEFAULT_BUILDER_FN, path.get_ident().unwrap().span())
+					};
+					options.builder_fn = Some(name);
+					builder_fn_path = Some(path.clone());
+					self_dependent.push(path);
+				} else if path.is_ident(DERIVE_DEFAULT_OPTION) {
+					options.derive_default = true;
+					derive_default_path = Some(path.clone());
+					self_dependent.push(path);
+				} else if path.is_ident(LENIENT_OPTION) {
+					options.lenient = true;
+				} else if path.is_ident(AUTO_UNSET_OPTION) {
+					options.auto_unset = true;
+				} else if path.is_ident(WARN_IGNORED_OPTION_OPTION) {
+					options.warn_ignored_option = true;
+				} else if path.is_ident(MSRV_OPTION) {
+					let msrv = option.value()?.parse::<LitStr>().ok();
+					options.msrv = msrv.as_ref().and_then(|msrv| RustVersion::parse(&msrv.value()));
+					if options.msrv.is_none() {
+						let message = "`msrv` option expects a Rust version in a string literal, e.g. \
+							`msrv = \"1.40\"`";
+						return Err(match msrv {
+							Some(msrv) => Error::new_spanned(msrv, message),
+							None => option.error(message),
+						});
+					}
+				} else if path.is_ident(FIXTURE_OPTION) {
+					if !cfg!(feature = "rstest") {
+						return Err(option.error("`fixture` option requires `rstest` feature"));
+					}
+					options.fixture = true;
+					constructor_dependent.push(path);
+				} else if path.is_ident(SERDE_OPTION) {
+					options.serde = true;
+				} else if path.is_ident(ERROR_BITS_OPTION) {
+					options.error_bits = true;
+					self_dependent.push(path);
+				} else if path.is_ident(VIOLATIONS_ITER_OPTION) {
+					options.violations_iter = true;
+					self_dependent.push(path);
+				} else if path.is_ident(TRACK_USAGE_OPTION) {
+					options.track_usage = true;
+				} else if path.is_ident(FIELD_ENUM_OPTION) {
+					options.field_enum = true;
+					self_dependent.push(path);
+				} else if path.is_ident(DERIVE_DISPLAY_OPTION) {
+					options.derive_display = true;
+					self_dependent.push(path);
+				} else if path.is_ident(BON_OPTION) {
+					if !cfg!(feature = "bon") {
+						return Err(option.error("`bon` option requires `bon` feature"));
+					}
+					options.bon = true;
+					self_dependent.push(path);
+				} else if path.is_ident(NO_PANIC_OPTION) {
+					if !cfg!(feature = "no-panic") {
+						return Err(option.error("`no_panic` option requires `no-panic` feature"));
+					}
+					options.no_panic = true;
+					self_dependent.push(path);
+				} else if path.is_ident(BUILDSTRUCTOR_OPTION) {
+					options.constructors.push(parse_buildstructor(option)?);
+					self_dependent.push(path);
+				} else if path.is_ident(KANI_PROOF_OPTION) {
+					if !cfg!(feature = "kani") {
+						return Err(option.error("`kani_proof` option requires `kani` feature"));
+					}
+					options.kani_proof = true;
+					constructor_dependent.push(path);
+				} else if path.is_ident(REMOTE_OPTION) {
+					let remote = option.value()?.parse::<LitStr>()
+						.and_then(|remote| remote.parse::<Path>())
+						.map_err(|err| {
+							return Error::new(
+								err.span(),
+								"`remote` option expects a path to a struct in a string literal, e.g. \
+									`remote = \"other_crate::Builder\"`",
+							);
+						})?;
+					options.remote = Some(remote);
+				} else if path.is_ident(CRATE_OPTION) {
+					let crate_path = option.value()?.parse::<LitStr>()
+						.and_then(|crate_path| crate_path.parse_with(Path::parse_mod_style))
+						.map_err(|err| {
+							return Error::new(
+								err.span(),
+								"`crate` option expects a path to the runtime crate in a string \
+									literal, e.g. `crate = \"::my_facade::check\"`",
+							);
+						})?;
+					options.crate_path = Some(crate_path);
+				} else if path.is_ident(VISIBILITY_OPTION) {
+					let visibility = option.value()?.parse::<LitStr>()
+						.and_then(|visibility| visibility.parse::<Visibility>())
+						.map_err(|err| {
+							return Error::new(
+								err.span(),
+								"`visibility` option expects a visibility in a string literal, e.g. \
+									`visibility = \"pub(crate)\"`",
+							);
+						})?;
+					options.visibility = Some(visibility);
+				} else if path.is_ident(BOUND_OPTION) {
+					let predicates: Punctuated<WherePredicate, Comma> = option.value()?
+						.parse::<LitStr>()
+						.and_then(|bound| bound.parse_with(Punctuated::parse_terminated))
+						.map_err(|err| {
+							return Error::new(
+								err.span(),
+								"`bound` option expects where predicates in a string literal, e.g. \
+									`bound = \"T: Default\"`",
+							);
+						})?;
+					options.bound.extend(predicates);
+				} else if path.is_ident(MESSAGE_PREFIX_OPTION) {
+					options.message_prefix = parse_message_tag(option)?;
+				} else if path.is_ident(MESSAGE_SUFFIX_OPTION) {
+					options.message_suffix = parse_message_tag(option)?;
+				} else if path.is_ident(TABLE_VALUES_OPTION) {
+					if !cfg!(feature = "table") {
+						return Err(option.error("`table_values` option requires `table` feature"));
+					}
+					options.table_values = true;
+				} else if path.is_ident(MAX_VALUE_LEN_OPTION) {
+					let max_value_len = option.value()?.parse::<LitInt>().ok()
+						.and_then(|max_value_len| max_value_len.base10_parse::<usize>().ok())
+						.filter(|max_value_len| *max_value_len > 0);
+					options.max_value_len = match max_value_len {
+						Some(max_value_len) => Some(max_value_len),
+						None => {
+							return Err(option.error(
+								"`max_value_len` option expects a positive integer, e.g. \
+									`max_value_len = 40`",
+							));
+						},
+					};
+					max_value_len_path = Some(path);
+				} else if path.is_ident(PANIC_PAYLOAD_OPTION) {
+					options.panic_payload = true;
+				} else if path.is_ident(WARN_EMPTY_CHECK_OPTION) {
+					options.empty_check = options.empty_check.max(EmptyCheckPolicy::Warn);
+				} else if path.is_ident(DENY_EMPTY_CHECK_OPTION) {
+					options.empty_check = EmptyCheckPolicy::Deny;
+				} else if path.is_ident(SETTER_OPTION) {
+					options.setter = Some(parse_setter_convention(option)?);
+					setter_path = Some(path);
+				} else if path.is_ident(SETTER_HINT_OPTION) {
+					options.setter_hint = Some(parse_setter_convention(option)?);
+				} else {
+					return Err(unknown_option(option, CONTAINER_ATTRIBUTE, &KNOWN_OPTIONS));
+				}
+				return Ok(());
+			});
+			return Ok(());
+		});
+		errors.check(result);
+	}
+	let inherit_checks_attributes =
+		input.attrs.iter().filter(|attribute| attribute.path().is_ident(INHERIT_CHECKS_ATTRIBUTE));
+	for attribute in inherit_checks_attributes {
+		let bases = attribute.parse_args_with(|input: ParseStream| {
+			return Punctuated::<Path, Comma>::parse_terminated_with(input, Path::parse_mod_style);
+		});
+		match bases {
+			Ok(bases) if !bases.is_empty() => options.inherit_checks.extend(bases),
+			_ => {
+				errors.push(Error::new_spanned(
+					&attribute.meta,
+					"`inherit_checks` attribute expects paths to structs deriving \
+						`CheckInitialState`, e.g. `#[inherit_checks(Base)]`",
+				));
+			},
+		}
+	}
+	if let (Some(path), true) = (constructor_dependent.first(), options.constructors.is_empty()) {
+		let message = format!(
+			"`{}` option requires at least one constructor to be specified",
+			path.get_ident().unwrap(),
+		);
+		errors.push(Error::new_spanned(path, message));
+	}
+	if let (Some(path), false) = (&setter_path, options.mutation_test) {
+		errors.push(Error::new_spanned(path, "`setter` option requires `mutation_test` option"));
+	}
+	if let (Some(path), false) = (max_value_len_path, options.table_values) {
+		let message = "`max_value_len` option requires `table_values` option";
+		errors.push(Error::new_spanned(path, message));
+	}
+	if let (Some(path), true) = (options.inherit_checks.first(), options.remote.is_some()) {
+		let message = "`inherit_checks` attribute cannot be combined with `remote` option";
+		errors.push(Error::new_spanned(path, message));
+	}
+	if let (Some(path), true) = (self_dependent.first(), options.remote.is_some()) {
+		let message = format!(
+			"`{}` option cannot be combined with `remote` option",
+			path.get_ident().unwrap(),
+		);
+		errors.push(Error::new_spanned(path, message));
+	}
+	if options.msrv.is_none() {
+		options.msrv = RustVersion::from_package();
+	}
+	options.packed = input.attrs.iter().any(|attribute| has_repr_hint(attribute, PACKED_REPR_HINT));
+	options.transparent =
+		input.attrs.iter().any(|attribute| has_repr_hint(attribute, TRANSPARENT_REPR_HINT));
+	options.disabled = disabled_switch::is_disabled();
+	options.serde_default = options.serde && has_serde_option(&input.attrs, &DEFAULT_OPTIONS);
+	if options.lenient {
+		// Neither a setter argument nor an initial value can be generated for a
+		// field which may turn out not to be `Option`
+		let initial_value_path = initial_const_path.or(builder_fn_path).or(derive_default_path);
+		if let Some(path) = setter_path.or(initial_value_path) {
+			let message = format!(
+				"`{}` option cannot be combined with `lenient` option",
+				path.get_ident().unwrap(),
+			);
+			errors.push(Error::new_spanned(path, message));
+		}
+	}
+	errors.finish()?;
+	if options.setter_hint.is_none() {
+		options.setter_hint = options.setter.clone().or(default_setter_hint);
+	}
+	return Ok(options);
+}
+
+/// Parses the value of `setter` or `setter_hint` option, which is a string
+/// literal with `{}` placeholder for a field name, e.g. `"set_{}"`. The
+/// convention must produce a valid identifier once a field name is
+/// substituted.
+fn parse_setter_convention(option: &ParseNestedMeta) -> syn::Result<String> {
+	let setter = option.value()?.parse::<LitStr>().ok()
+		.filter(|setter| setter.value().contains(FIELD_NAME_PLACEHOLDER));
+	return match setter {
+		Some(setter) => {
+			let expanded = setter.value().replace(FIELD_NAME_PLACEHOLDER, "field");
+			if syn::parse_str::<Ident>(&expanded).is_err() {
+				return Err(Error::new(
+					setter.span(),
+					format!(
+						"`{}` option must produce a valid identifier, e.g. `set_{{}}`, while it \
+							produces `{}` for `field`",
+						option.path.get_ident().unwrap(),
+						expanded,
+					),
+				));
+			}
+			Ok(setter.value())
+		},
+		None => {
+			let name = option.path.get_ident().unwrap();
+			Err(option.error(format!(
+				"`{}` option expects a string literal with `{{}}` placeholder for a field name, \
+					e.g. `{} = \"set_{{}}\"`",
+				name,
+				name,
+			)))
+		},
+	};
+}
+
+/// Parses the value of `message_prefix` or `message_suffix` option, which is a
+/// string literal.
+fn parse_message_tag(option: &ParseNestedMeta) -> syn::Result<String> {
+	let tag = option.value()?.parse::<LitStr>().map_err(|err| {
+		let message =
+			format!("`{}` option expects a string literal", option.path.get_ident().unwrap());
+		return Error::new(err.span(), message);
+	})?;
+	return Ok(tag.value());
+}
+
+/// Tells whether `attribute` is `check_initial_state` container attribute.
+fn is_container_attribute(attribute: &Attribute) -> bool {
+	return attribute.path().is_ident(CONTAINER_ATTRIBUTE);
+}
+
+/// Tells whether `attribute` is `repr` attribute with `hint`, e.g. `packed` of
+/// `#[repr(packed)]` or `#[repr(C, packed(2))]`.
+fn has_repr_hint(attribute: &Attribute, hint_name: &str) -> bool {
+	if !attribute.path().is_ident(REPR_ATTRIBUTE) {
+		return false;
+	}
+	let mut found = false;
+	// Malformed `repr` is reported by the compiler itself
+	let _ = attribute.parse_nested_meta(|hint| {
+		found |= hint.path.is_ident(hint_name);
+		// Skip arguments of hints like `align(8)` or `packed(2)`
+		if hint.input.peek(token::Paren) {
+			let _arguments;
+			parenthesized!(_arguments in hint.input);
+		}
+		return Ok(());
+	});
+	return found;
+}
+
+/// Parses a single mapping of `empty_via` option, e.g. `Vec = "is_empty"`.
+/// The method name may be given without quotes as well, e.g. `Vec = is_empty`.
+fn parse_empty_via_mapping(mapping: &ParseNestedMeta) -> syn::Result<(Path, Ident)> {
+	let error_message = "`empty_via` option expects mappings of types to method names, e.g. \
+		`empty_via(Vec = \"is_empty\")`";
+	let method = parse_name(mapping.value()?, error_message)?;
+	return Ok((mapping.path.clone(), method));
+}
+
+/// Parses a name of a function or a method, which is either written as is,
+/// e.g. `is_empty`, or enclosed into a string literal, e.g. `"is_empty"`.
+/// `error_message` is reported if the name is malformed.
+fn parse_name(input: ParseStream, error_message: &str) -> syn::Result<Ident> {
+	if input.peek(LitStr) {
+		let name = input.parse::<LitStr>()?;
+		return name.parse::<Ident>().map_err(|_| Error::new_spanned(&name, error_message));
+	}
+	return input.parse::<Ident>().map_err(|err| Error::new(err.span(), error_message));
+}
+
+/// Parses `buildstructor` option into a constructor chaining the function
+/// creating the builder and the builder method calling the constructor, e.g.
+/// `Self::builder().build()`. Both names may be overridden by `entry` and
+/// `exit` options, which mirror the same options of `builder` attribute of
+/// `buildstructor` crate, e.g. `buildstructor(entry = "with_builder")`.
+fn parse_buildstructor(option: &ParseNestedMeta) -> syn::Result<Expr> {
+	let mut entry = Ident::new(DEFAULT_BUILDSTRUCTOR_ENTRY, Span::call_site());
+	let mut exit = Ident::new(DEFAULT_BUILDSTRUCTOR_EXIT, Span::call_site());
+	if option.input.peek(token::Paren) {
+		let error_message = "`buildstructor` option expects `entry` and `exit` method names, e.g. \
+			`buildstructor(entry = \"builder\", exit = \"build\")`";
+		option.parse_nested_meta(|name| {
+			let target = if name.path.is_ident("entry") {
+				&mut entry
+			} else if name.path.is_ident("exit") {
+				&mut exit
+			} else {
+				return Err(Error::new_spanned(&name.path, error_message));
+			};
+			*target = parse_name(name.value()?, error_message)?;
+			return Ok(());
+		})?;
+	}
+	return Ok(syn::parse_quote!(Self::#entry().#exit()));
+}
+
+/// Parses a constructor expression, which is either written as is, e.g.
+/// `constructor = Self::new`, or enclosed into a string literal, e.g.
+/// `constructor = "Self::new"`. A bare identifier, e.g. `new`, is treated as an
+/// associated function of the struct, i.e. `Self::new`.
+fn parse_constructor(input: ParseStream) -> syn::Result<Expr> {
+	if input.peek(LitStr) {
+		return parse_constructor_literal(&input.parse::<LitStr>()?);
+	}
+	return input.parse::<Expr>().map(qualify_constructor);
+}
+
+/// Parses a constructor expression out of a string literal.
+fn parse_constructor_literal(string: &LitStr) -> syn::Result<Expr> {
+	return match string.parse::<Expr>() {
+		Ok(expression) => Ok(qualify_constructor(expression)),
+		Err(_) => {
+			Err(Error::new_spanned(
+				string,
+				"Constructor is expected to be a path to a function, e.g. \"Self::new\", or a call \
+					expression, e.g. \"Self::with_capacity(10)\"",
+			))
+		},
+	};
+}
+
+/// Turns a bare identifier, e.g. `new`, into an associated function of the
+/// struct, i.e. `Self::new`. Any other expression is returned as is. An
+/// invisible group, which wraps `$constructor:expr` fragment passed through
+/// `macro_rules!`, is unwrapped, so the fragment is treated like the same
+/// expression written in place.
+fn qualify_constructor(expression: Expr) -> Expr {
+	return match expression {
+		Expr::Group(group) => qualify_constructor(*group.expr),
+		Expr::Path(path) if path.qself.is_none() && path.path.get_ident().is_some() => {
+			let ident = path.path.get_ident().unwrap();
+			syn::parse_quote!(Self::#ident)
+		},
+		expression => expression,
+	};
+}
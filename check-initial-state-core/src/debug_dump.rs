@@ -0,0 +1,28 @@
+use proc_macro2::{Ident, TokenStream};
+use std::env;
+use std::fs;
+use std::path::Path;
+
+/// Environment variable which requests the generated code to be dumped at
+/// compile time. `1` dumps to stderr, while any other value except `0` is a
+/// directory to write a file per struct into.
+const DEBUG_VARIABLE: &str = "CHECK_INITIAL_STATE_DEBUG";
+
+/// Dumps `tokens` generated for `struct_name` if requested by
+/// `CHECK_INITIAL_STATE_DEBUG` environment variable. Failures are reported to
+/// stderr, but never fail the compilation.
+pub fn dump(struct_name: &Ident, tokens: &TokenStream) {
+	let target = match env::var(DEBUG_VARIABLE) {
+		Ok(target) if !target.is_empty() && target != "0" => target,
+		_ => return,
+	};
+	let dump = format!("// `CheckInitialState` expansion for `{}`\n{}\n", struct_name, tokens);
+	if target == "1" {
+		eprint!("{}", dump);
+		return;
+	}
+	let path = Path::new(&target).join(format!("{}.rs", struct_name));
+	if let Err(err) = fs::create_dir_all(&target).and_then(|_| fs::write(&path, dump)) {
+		eprintln!("Failed to dump `CheckInitialState` expansion to {}: {}", path.display(), err);
+	}
+}
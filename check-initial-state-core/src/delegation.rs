@@ -0,0 +1,263 @@
+use crate::container_attribute::ContainerOptions;
+use crate::field_attribute::parse_field_options;
+use crate::field_check::FieldCheck;
+use crate::{
+	generate_constructor_tests, generate_coverage_off, generate_dependency_tracking,
+	generate_fixture, generate_kani_proofs, generate_registration, generics_with_bound,
+	is_option_type, to_compile_error, ungroup_type, RustVersion,
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Field, Index, Member, Type};
+
+/// Field which the check of the struct is delegated to, i.e. the one annotated
+/// with `delegate_check` or the wrapped field of a `#[repr(transparent)]`
+/// newtype.
+pub struct Delegate<'a> {
+	/// Accessor of the field, i.e. its name or index.
+	pub member: Member,
+	/// The wrapped field itself.
+	pub field: &'a Field,
+}
+
+impl<'a> Delegate<'a> {
+	/// Forms the delegate of `field`, which is declared at `index`.
+	fn new(index: usize, field: &'a Field) -> Self {
+		let member = match &field.ident {
+			Some(ident) => Member::Named(ident.clone()),
+			None => Member::Unnamed(Index::from(index)),
+		};
+		return Self { member, field };
+	}
+}
+
+/// Finds the field of `input`, which the check is delegated to. Such field is
+/// either annotated with `delegate_check`, or is the only field of a
+/// `#[repr(transparent)]` struct, which is neither ignored nor `PhantomData`.
+/// The wrapped field of a newtype must have neither check attributes nor
+/// `report_name` or `redact`, and must not be `Option`, which is checked as
+/// usual. `None` is returned if there is no such field, so the struct is
+/// expanded as usual, including reporting of malformed field attributes.
+/// Compile error is returned if several fields are annotated with
+/// `delegate_check`, or if it is combined with `remote` option.
+pub fn find_delegate<'a>(input: &'a DeriveInput, options: &ContainerOptions)
+	-> Result<Option<Delegate<'a>>, TokenStream> {
+	let fields = match &input.data {
+		Data::Struct(data_struct) => &data_struct.fields,
+		_ => return Ok(None),
+	};
+	let mut field_options = Vec::with_capacity(fields.len());
+	for field in fields.iter() {
+		match parse_field_options(field, options) {
+			Ok(options) => field_options.push(options),
+			Err(_) => return Ok(None),
+		}
+	}
+	let mut delegated_fields = fields.iter().zip(&field_options).enumerate()
+		.filter(|(_, (_, options))| options.delegated)
+		.map(|(index, (field, _))| (index, field));
+	if let Some((index, field)) = delegated_fields.next() {
+		if let Some((_, other_field)) = delegated_fields.next() {
+			return Err(to_compile_error(
+				other_field,
+				"Struct may have a single field annotated with `delegate_check` only",
+			));
+		}
+		if options.remote.is_some() {
+			return Err(to_compile_error(
+				field,
+				"`delegate_check` attribute cannot be combined with `remote` option",
+			));
+		}
+		return Ok(Some(Delegate::new(index, field)));
+	}
+	if !options.transparent || options.remote.is_some() {
+		return Ok(None);
+	}
+	let mut delegate = None;
+	for (index, (field, field_options)) in fields.iter().zip(&field_options).enumerate() {
+		if field_options.ignored || is_phantom_data(&field.ty) {
+			continue;
+		}
+		let annotated = !matches!(
+			field_options.check,
+			FieldCheck::IsNone | FieldCheck::IsUnset | FieldCheck::AutoUnset | FieldCheck::Lenient
+		)
+			|| field_options.report_name.is_some()
+			|| field_options.redacted;
+		if delegate.is_some() || annotated || is_option_type(&field.ty) {
+			return Ok(None);
+		}
+		delegate = Some(Delegate::new(index, field));
+	}
+	return Ok(delegate);
+}
+
+/// Generates the implementation of `CheckInitialState` derive for `input`
+/// struct, which calls the implementation of `delegate` field. So annotations
+/// of the field type needn't be duplicated. Options, which concern individual
+/// fields, are reported as compile errors in the returned tokens.
+pub fn generate_delegation(
+	input: &DeriveInput,
+	delegate: &Delegate,
+	options: &ContainerOptions,
+) -> TokenStream {
+	let struct_name = &input.ident;
+	if let Some(option) = find_field_option(options) {
+		let message = format!(
+			"`{}` option is not applicable to a struct, which delegates its check to a field",
+			option,
+		);
+		return to_compile_error(struct_name, &message);
+	}
+	let mut generics = generics_with_bound(input, options);
+	let ty = &delegate.field.ty;
+	if generics.type_params().next().is_some() {
+		generics.make_where_clause().predicates
+			.push(syn::parse_quote!(#ty: ::check_initial_state::CheckInitialState));
+	}
+	let (leading_generics, trailing_generics, where_clause) = &generics.split_for_impl();
+	let member = &delegate.member;
+	let track_caller = if RustVersion::supports(options.msrv, RustVersion::TRACK_CALLER) {
+		quote! { #[track_caller] }
+	} else {
+		TokenStream::new()
+	};
+	let coverage_off = generate_coverage_off();
+	let constructor_tests = if options.disabled {
+		TokenStream::new()
+	} else {
+		generate_constructor_tests(struct_name, &[], options)
+	};
+	let registration = generate_registration(struct_name, options);
+	let kani_proofs = if options.kani_proof && !options.disabled {
+		generate_kani_proofs(struct_name, options)
+	} else {
+		TokenStream::new()
+	};
+	let fixture = match options.constructors.first() {
+		Some(constructor) if options.fixture => {
+			generate_fixture(input, &generics, constructor, options)
+		},
+		_ => TokenStream::new(),
+	};
+	let dependency_tracking = generate_dependency_tracking(options);
+	let result = quote! {
+		#[automatically_derived]
+		#[allow(dead_code, clippy::panic)]
+		impl #leading_generics #struct_name #trailing_generics #where_clause {
+			/// Checks the delegated field to be in initial state with its own
+			/// `check_initial_state()`.
+			/// # Panics
+			/// The delegated field is not in initial state. Panic message will
+			/// contain the name of its first field which is not.
+			#track_caller
+			#coverage_off
+			fn check_initial_state(&self) {
+				::check_initial_state::CheckInitialState::check_initial_state(&self.#member)
+			}
+
+			/// Checks the delegated field like `check_initial_state()`, but
+			/// prepends `context` to the panic message. Nothing is prepended if
+			/// `context` is empty.
+			/// # Panics
+			/// The delegated field is not in initial state.
+			#track_caller
+			#coverage_off
+			fn check_initial_state_with_context(&self, context: &str) {
+				if context.is_empty() {
+					return Self::check_initial_state(self);
+				}
+				let result =
+					::check_initial_state::CheckInitialState::try_check_initial_state(&self.#member);
+				if let ::std::result::Result::Err(error) = result {
+					::std::panic!("{}: {}", context, error.violations()[0]);
+				}
+			}
+
+			/// Writes a line to `writer` for each field of the delegated field,
+			/// which is not in initial state. Nothing is written if it is in
+			/// initial state. Unlike `check_initial_state()`, all violations
+			/// are reported and nothing panics.
+			/// # Errors
+			/// Writing to `writer` failed.
+			#coverage_off
+			fn write_initial_state_report(
+				&self,
+				writer: &mut (impl ::std::io::Write + ?::std::marker::Sized),
+			) -> ::std::io::Result<()> {
+				let result =
+					::check_initial_state::CheckInitialState::try_check_initial_state(&self.#member);
+				if let ::std::result::Result::Err(error) = result {
+					for violation in error.violations() {
+						::std::writeln!(writer, "{}", violation)?;
+					}
+				}
+				::std::result::Result::Ok(())
+			}
+		}
+
+		#[automatically_derived]
+		#[allow(dead_code, clippy::panic)]
+		impl #leading_generics ::check_initial_state::CheckInitialState
+			for #struct_name #trailing_generics #where_clause {
+			#track_caller
+			#coverage_off
+			fn check_initial_state(&self) {
+				// Inherent method takes precedence
+				Self::check_initial_state(self)
+			}
+
+			#coverage_off
+			fn try_check_initial_state(&self)
+				-> ::std::result::Result<(), ::check_initial_state::InitialStateError> {
+				::check_initial_state::CheckInitialState::try_check_initial_state(&self.#member)
+			}
+		}
+
+		#constructor_tests
+
+		#registration
+
+		#fixture
+
+		#kani_proofs
+
+		#dependency_tracking
+	};
+	return result;
+}
+
+/// Finds the name of an option in `options`, which concerns individual fields
+/// of the struct, e.g. `mutation_test`.
+fn find_field_option(options: &ContainerOptions) -> Option<&'static str> {
+	let field_options = [
+		(options.mutation_test, "mutation_test"),
+		(options.initial_const, "initial_const"),
+		(options.builder_fn.is_some(), "builder_fn"),
+		(options.derive_default, "derive_default"),
+		(options.compare_with.is_some(), "compare_with"),
+		(options.error_bits, "error_bits"),
+		(options.violations_iter, "violations_iter"),
+		(options.field_enum, "field_enum"),
+		(options.panic_payload, "panic_payload"),
+		(options.table_values, "table_values"),
+		(options.derive_display, "derive_display"),
+		(!options.inherit_checks.is_empty(), "inherit_checks"),
+		(options.bon, "bon"),
+		(!options.message_prefix.is_empty(), "message_prefix"),
+		(!options.message_suffix.is_empty(), "message_suffix"),
+	];
+	return field_options.iter().find(|(present, _)| *present).map(|(_, option)| *option);
+}
+
+/// Tells whether `ty` is `PhantomData` by the name of its last path segment,
+/// since proc macros cannot resolve types.
+fn is_phantom_data(ty: &Type) -> bool {
+	return match ungroup_type(ty) {
+		Type::Path(path) if path.qself.is_none() => {
+			path.path.segments.last().is_some_and(|segment| segment.ident == "PhantomData")
+		},
+		_ => false,
+	};
+}
@@ -0,0 +1,14 @@
+use std::env;
+
+/// Environment variable which disables all checks at compile time. Any
+/// non&#x2011;empty value except `0` turns the generated methods into no-ops.
+pub const DISABLED_VARIABLE: &str = "CHECK_INITIAL_STATE_DISABLED";
+
+/// Tells whether checks are disabled by `CHECK_INITIAL_STATE_DISABLED`
+/// environment variable.
+pub fn is_disabled() -> bool {
+	return match env::var(DISABLED_VARIABLE) {
+		Ok(value) => !value.is_empty() && value != "0",
+		Err(_) => false,
+	};
+}
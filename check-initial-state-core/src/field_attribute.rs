@@ -0,0 +1,545 @@
+use crate::container_attribute::ContainerOptions;
+use crate::field_check::FieldCheck;
+use crate::option_errors::{parse_option, unknown_option, OptionErrors};
+use crate::serde_attribute::{has_serde_option, DEFAULT_OPTIONS, SKIP_OPTIONS};
+use crate::{is_option_type, to_compile_error, ungroup_type, write_tokens};
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::parse::{ParseStream, Parser};
+use syn::{
+	Attribute, DeriveInput, Error, Expr, ExprLit, Field, GenericParam, Ident, Lit, LitStr,
+	MacroDelimiter, Meta, Path, Token, Type,
+};
+
+/// Fields which are annotated with this attribute are ignored by
+/// `CheckInitialState` proc macro check.
+const IGNORE_FIELD_ATTRIBUTE: &str = "ignore_field";
+
+/// `bool` fields which are annotated with this attribute are expected to be
+/// `false` initially.
+const EXPECT_FALSE_ATTRIBUTE: &str = "expect_false";
+
+/// `bool` fields which are annotated with this attribute are expected to be
+/// `true` initially.
+const EXPECT_TRUE_ATTRIBUTE: &str = "expect_true";
+
+/// Numeric fields which are annotated with this attribute are expected to be
+/// `0` initially.
+const EXPECT_ZERO_ATTRIBUTE: &str = "expect_zero";
+
+/// Fields which are annotated with this attribute are expected to match the
+/// pattern specified as its argument, e.g. `#[expect_matches(State::Idle)]`.
+const EXPECT_MATCHES_ATTRIBUTE: &str = "expect_matches";
+
+/// Fields of `Option`&#x2011;like types, e.g. newtypes exposing `is_some()`,
+/// which are annotated with this attribute are expected to be `None`
+/// initially.
+const OPTION_ALIAS_ATTRIBUTE: &str = "option_alias";
+
+/// Fields which are annotated with this attribute are named by its value in
+/// panic messages and reports, e.g. `#[report_name = "Override timestamp"]`.
+const REPORT_NAME_ATTRIBUTE: &str = "report_name";
+
+/// Fields which are annotated with this attribute hold secrets, so their
+/// values are never printed, e.g. in the state table.
+const REDACT_ATTRIBUTE: &str = "redact";
+
+/// Field which is annotated with this attribute implements `CheckInitialState`
+/// itself, so the check of the struct is forwarded to it.
+const DELEGATE_CHECK_ATTRIBUTE: &str = "delegate_check";
+
+/// Field of `Provenance` type which is annotated with this attribute records
+/// where other fields were set, so it is not checked itself.
+const PROVENANCE_ATTRIBUTE: &str = "provenance";
+
+/// Fields which are annotated with this attribute belong to the named group in
+/// the metadata returned by `fields()`, e.g. `#[field_group = "network"]`. The
+/// group doesn't affect the check.
+const FIELD_GROUP_ATTRIBUTE: &str = "field_group";
+
+/// Attribute of `smart-default` crate, which specifies the default of a field,
+/// e.g. `#[default(10)]`. Fields with a non&#x2011;`None` default are expected
+/// to equal it initially.
+const SMART_DEFAULT_ATTRIBUTE: &str = "default";
+
+/// All attributes which are applicable to fields only.
+const FIELD_ATTRIBUTES: [&str; 11] = [
+	IGNORE_FIELD_ATTRIBUTE,
+	EXPECT_FALSE_ATTRIBUTE,
+	EXPECT_TRUE_ATTRIBUTE,
+	EXPECT_ZERO_ATTRIBUTE,
+	EXPECT_MATCHES_ATTRIBUTE,
+	OPTION_ALIAS_ATTRIBUTE,
+	REPORT_NAME_ATTRIBUTE,
+	REDACT_ATTRIBUTE,
+	DELEGATE_CHECK_ATTRIBUTE,
+	PROVENANCE_ATTRIBUTE,
+	FIELD_GROUP_ATTRIBUTE,
+];
+
+/// Characters which `report_name` must not contain, since the name is embedded
+/// into format strings and JSON reports as is.
+const REPORT_NAME_FORBIDDEN_CHARACTERS: [char; 5] = ['"', '\\', '{', '}', '`'];
+
+/// Option of `ignore_field` attribute which specifies the initial value of the
+/// field.
+const DEFAULT_OPTION: &str = "default";
+
+/// Option of `ignore_field` attribute which explains why the field is ignored.
+const REASON_OPTION: &str = "reason";
+
+/// All options of `ignore_field` attribute.
+const IGNORE_FIELD_OPTIONS: [&str; 2] = [DEFAULT_OPTION, REASON_OPTION];
+
+/// Options gathered from attributes of a single field.
+#[derive(Default)]
+pub struct FieldOptions {
+	/// Whether the field is annotated with `ignore_field`, other than
+	/// `#[ignore_field = false]`.
+	pub ignored: bool,
+	/// Initial value of an ignored field, specified with
+	/// `#[ignore_field(default = "...")]`.
+	pub default: Option<Expr>,
+	/// Why the field is ignored, specified with
+	/// `#[ignore_field(reason = "...")]`.
+	pub reason: Option<String>,
+	/// How the field is verified, unless it is ignored.
+	pub check: FieldCheck,
+	/// Name of the field in panic messages and reports, specified with
+	/// `#[report_name = "..."]`. The field name itself is used if absent.
+	pub report_name: Option<String>,
+	/// Whether the field is annotated with `redact`, so `<redacted>` is
+	/// printed instead of its value.
+	pub redacted: bool,
+	/// Whether the field is annotated with `delegate_check`, so the check of
+	/// the struct is forwarded to the field and other fields are not checked.
+	pub delegated: bool,
+	/// Whether the field is annotated with `provenance`, so it records where
+	/// other fields were set. Such field is ignored.
+	pub provenance: bool,
+	/// Group of the field in the metadata returned by `fields()`, specified
+	/// with `#[field_group = "..."]`.
+	pub group: Option<String>,
+}
+
+/// Parses `ignore_field` and check attributes, e.g. `expect_false`, of
+/// `field`. Parsing error is returned in case of unknown option, malformed
+/// option value or conflicting attributes. All such problems are reported at
+/// once.
+/// # Parameters
+/// * `container` &ndash; Options of the struct. Some of them, e.g.
+/// `empty_via`, define the check of `field` if it has no check attribute.
+pub fn parse_field_options(field: &Field, container: &ContainerOptions)
+	-> Result<FieldOptions, TokenStream> {
+	let mut options = FieldOptions::default();
+	let mut errors = OptionErrors::default();
+	// Attribute which has specified `options.check`
+	let mut check_attribute: Option<&Attribute> = None;
+	for attribute in field.attrs.iter() {
+		let check = if is_attribute(attribute, EXPECT_FALSE_ATTRIBUTE) {
+			FieldCheck::ExpectFalse
+		} else if is_attribute(attribute, EXPECT_TRUE_ATTRIBUTE) {
+			FieldCheck::ExpectTrue
+		} else if is_attribute(attribute, EXPECT_ZERO_ATTRIBUTE) {
+			FieldCheck::ExpectZero
+		} else if is_attribute(attribute, OPTION_ALIAS_ATTRIBUTE) {
+			FieldCheck::OptionAlias
+		} else if is_attribute(attribute, EXPECT_MATCHES_ATTRIBUTE) {
+			match parse_pattern(attribute) {
+				Ok(check) => check,
+				Err(error) => {
+					errors.push(error);
+					continue;
+				},
+			}
+		} else {
+			continue;
+		};
+		let has_arguments = !matches!(attribute.meta, Meta::Path(_));
+		if has_arguments && !matches!(check, FieldCheck::ExpectMatches { .. }) {
+			errors.push(Error::new_spanned(attribute, "Check attribute expects no arguments"));
+		} else if check_attribute.is_some() {
+			errors.push(Error::new_spanned(attribute, "Field may have a single check attribute only"));
+		} else {
+			options.check = check;
+			check_attribute = Some(attribute);
+		}
+	}
+	if check_attribute.is_none() {
+		if let Some(check) = parse_smart_default(field) {
+			options.check = check;
+		} else if let Some(method) = find_empty_via_method(&field.ty, &container.empty_via) {
+			options.check = FieldCheck::EmptyVia { method: method.clone() };
+		} else if container.auto_unset && !may_be_option(&field.ty) {
+			options.check = FieldCheck::AutoUnset;
+		} else if container.lenient {
+			options.check = FieldCheck::Lenient;
+		} else if !may_be_option(&field.ty) {
+			options.check = FieldCheck::IsUnset;
+		}
+	}
+	// The last `ignore_field` attribute wins, so `#[cfg_attr(..., ignore_field = false)]` may
+	// override a preceding `ignore_field`
+	for attribute in field.attrs.iter().filter(|attribute| is_ignore_field_attribute(attribute)) {
+		match &attribute.meta {
+			Meta::Path(_) => {
+				options.ignored = true;
+				continue;
+			},
+			Meta::List(_) => options.ignored = true,
+			Meta::NameValue(name_value) => {
+				match &name_value.value {
+					Expr::Lit(ExprLit { lit: Lit::Bool(ignored), .. }) => {
+						options.ignored = ignored.value;
+					},
+					_ => {
+						errors.push(Error::new_spanned(
+							&attribute.meta,
+							"`ignore_field` attribute expects either no options, a list of options, e.g. \
+								`#[ignore_field(default = \"0\")]`, or a boolean, e.g. \
+								`#[ignore_field = false]`",
+						));
+					},
+				}
+				continue;
+			},
+		}
+		let result = attribute.parse_nested_meta(|option| {
+			parse_option(&option, &mut errors, |option| {
+				if option.path.is_ident(DEFAULT_OPTION) {
+					options.default = Some(parse_default(option.value()?)?);
+				} else if option.path.is_ident(REASON_OPTION) {
+					let reason = option.value()?.parse::<LitStr>().map_err(|err| {
+						return Error::new(err.span(), "`reason` option expects a string literal");
+					})?;
+					options.reason = Some(reason.value());
+				} else {
+					return Err(unknown_option(option, IGNORE_FIELD_ATTRIBUTE, &IGNORE_FIELD_OPTIONS));
+				}
+				return Ok(());
+			});
+			return Ok(());
+		});
+		errors.check(result);
+	}
+	let mut report_name_attributes =
+		field.attrs.iter().filter(|attribute| is_attribute(attribute, REPORT_NAME_ATTRIBUTE));
+	if let Some(attribute) = report_name_attributes.next() {
+		match parse_report_name(attribute) {
+			Ok(report_name) => options.report_name = Some(report_name),
+			Err(error) => errors.push(error),
+		}
+	}
+	for attribute in report_name_attributes {
+		let message = "Field may have a single `report_name` attribute only";
+		errors.push(Error::new_spanned(attribute, message));
+	}
+	let mut group_attributes =
+		field.attrs.iter().filter(|attribute| is_attribute(attribute, FIELD_GROUP_ATTRIBUTE));
+	if let Some(attribute) = group_attributes.next() {
+		match parse_group(attribute) {
+			Ok(group) => options.group = Some(group),
+			Err(error) => errors.push(error),
+		}
+	}
+	for attribute in group_attributes {
+		let message = "Field may have a single `field_group` attribute only";
+		errors.push(Error::new_spanned(attribute, message));
+	}
+	let redact_attributes =
+		field.attrs.iter().filter(|attribute| is_attribute(attribute, REDACT_ATTRIBUTE));
+	for attribute in redact_attributes {
+		if !matches!(attribute.meta, Meta::Path(_)) {
+			errors.push(Error::new_spanned(attribute, "`redact` attribute expects no arguments"));
+		} else if options.redacted {
+			let message = "Field may have a single `redact` attribute only";
+			errors.push(Error::new_spanned(attribute, message));
+		}
+		options.redacted = true;
+	}
+	let delegate_check_attributes =
+		field.attrs.iter().filter(|attribute| is_attribute(attribute, DELEGATE_CHECK_ATTRIBUTE));
+	for attribute in delegate_check_attributes {
+		if !matches!(attribute.meta, Meta::Path(_)) {
+			let message = "`delegate_check` attribute expects no arguments";
+			errors.push(Error::new_spanned(attribute, message));
+		} else if options.delegated {
+			let message = "Field may have a single `delegate_check` attribute only";
+			errors.push(Error::new_spanned(attribute, message));
+		} else if check_attribute.is_some() || options.report_name.is_some() || options.redacted {
+			errors.push(Error::new_spanned(
+				attribute,
+				"`delegate_check` attribute cannot be combined with check attributes, `report_name` \
+					or `redact`, since the field is checked by its own implementation",
+			));
+		}
+		options.delegated = true;
+	}
+	let explicitly_annotated = check_attribute.is_some()
+		|| options.delegated
+		|| field.attrs.iter().any(is_ignore_field_attribute);
+	if container.serde && !explicitly_annotated {
+		// A field which is never deserialized, or which is defaulted while not
+		// being `Option`, needn't be in initial state
+		let skipped = has_serde_option(&field.attrs, &SKIP_OPTIONS);
+		let defaulted = container.serde_default || has_serde_option(&field.attrs, &DEFAULT_OPTIONS);
+		if skipped || (defaulted && !is_option_type(&field.ty)) {
+			options.ignored = true;
+			options.reason = Some("Is skipped or defaulted by `serde`".to_owned());
+		}
+	}
+	let listed = container.ignore_types.iter().any(|path| is_listed_type(&field.ty, path));
+	if listed && !explicitly_annotated && !options.ignored {
+		options.ignored = true;
+		options.reason = Some("Has a type listed in `ignore_types`".to_owned());
+	}
+	if let (true, Some(check_attribute)) = (options.ignored, check_attribute) {
+		errors.push(Error::new_spanned(
+			check_attribute,
+			"Check attribute cannot be combined with `ignore_field`",
+		));
+	}
+	if options.ignored && options.delegated {
+		let attribute = field.attrs.iter()
+			.find(|attribute| is_attribute(attribute, DELEGATE_CHECK_ATTRIBUTE));
+		errors.push(Error::new_spanned(
+			attribute,
+			"`delegate_check` attribute cannot be combined with `ignore_field`",
+		));
+	}
+	if options.ignored && options.report_name.is_some() {
+		let attribute = field.attrs.iter()
+			.find(|attribute| is_attribute(attribute, REPORT_NAME_ATTRIBUTE));
+		errors.push(Error::new_spanned(
+			attribute,
+			"`report_name` attribute cannot be combined with `ignore_field`, since ignored fields \
+				are never reported",
+		));
+	}
+	let provenance_attributes =
+		field.attrs.iter().filter(|attribute| is_attribute(attribute, PROVENANCE_ATTRIBUTE));
+	for attribute in provenance_attributes {
+		if !cfg!(feature = "provenance") {
+			let message = "`provenance` attribute requires `provenance` feature";
+			errors.push(Error::new_spanned(attribute, message));
+		} else if !matches!(attribute.meta, Meta::Path(_)) {
+			let message = "`provenance` attribute expects no arguments";
+			errors.push(Error::new_spanned(attribute, message));
+		} else if options.provenance {
+			let message = "Field may have a single `provenance` attribute only";
+			errors.push(Error::new_spanned(attribute, message));
+		} else if explicitly_annotated || options.report_name.is_some() || options.redacted {
+			errors.push(Error::new_spanned(
+				attribute,
+				"`provenance` attribute cannot be combined with other field attributes, since the \
+					field is not checked",
+			));
+		}
+		options.provenance = true;
+		options.ignored = true;
+		options.reason = Some("Records where other fields were set".to_owned());
+	}
+	errors.finish()?;
+	return Ok(options);
+}
+
+/// Rejects field attributes, e.g. `ignore_field`, placed on the struct itself or
+/// on its generic parameters, where they would be silently ignored otherwise.
+pub fn check_misplaced_attributes(input: &DeriveInput) -> Result<(), TokenStream> {
+	let generic_attributes = input.generics.params.iter().flat_map(|param| {
+		return match param {
+			GenericParam::Type(param) => param.attrs.iter(),
+			GenericParam::Lifetime(param) => param.attrs.iter(),
+			GenericParam::Const(param) => param.attrs.iter(),
+		};
+	});
+	for attribute in input.attrs.iter().chain(generic_attributes) {
+		let name = FIELD_ATTRIBUTES.iter().find(|name| is_attribute(attribute, name));
+		if let Some(name) = name {
+			let message = format!("`{}` attribute is applicable to struct fields only", name);
+			return Err(to_compile_error(attribute, &message));
+		}
+	}
+	return Ok(());
+}
+
+/// Tells whether `attribute` is `ignore_field` attribute.
+fn is_ignore_field_attribute(attribute: &Attribute) -> bool {
+	return is_attribute(attribute, IGNORE_FIELD_ATTRIBUTE);
+}
+
+/// Tells whether `attribute` is named `name`. The whole path is matched, so
+/// helper attributes of other derives, e.g. `#[serde(...)]`, as well as foreign
+/// attributes, which merely end with the same name, e.g.
+/// `#[rustfmt::ignore_field]`, are never taken for attributes of this crate.
+fn is_attribute(attribute: &Attribute, name: &str) -> bool {
+	return attribute.path().is_ident(name);
+}
+
+/// Parses `default` attribute of `smart-default` crate on `field`, e.g.
+/// `#[default(10)]`, `#[default = "name"]` or `#[default(_code = "vec![1]")]`,
+/// into the check of the value it specifies. `None` is returned if `field` has
+/// no such attribute, or it specifies `Default::default()` or `None`, which
+/// are covered by the usual checks. Malformed attributes are left to be
+/// reported by `smart-default`.
+fn parse_smart_default(field: &Field) -> Option<FieldCheck> {
+	let attribute =
+		field.attrs.iter().find(|attribute| is_attribute(attribute, SMART_DEFAULT_ATTRIBUTE))?;
+	let code = match &attribute.meta {
+		Meta::Path(_) => return None,
+		Meta::List(list) => {
+			let parse_code = |input: ParseStream| {
+				let key = input.parse::<Ident>()?;
+				if key != "_code" {
+					return Err(Error::new_spanned(key, "Expected `_code`"));
+				}
+				input.parse::<Token![=]>()?;
+				return input.parse::<LitStr>()?.parse::<TokenStream>();
+			};
+			match parse_code.parse2(list.tokens.clone()) {
+				// Code is used as is
+				Ok(code) => return Some(value_check(field, code.clone(), code)),
+				Err(_) => list.tokens.clone(),
+			}
+		},
+		Meta::NameValue(name_value) => name_value.value.to_token_stream(),
+	};
+	if let Ok(path) = syn::parse2::<Path>(code.clone()) {
+		if path.segments.last().is_some_and(|segment| segment.ident == "None") {
+			return None;
+		}
+	}
+	// String literals are converted, e.g. into `String`, like `smart-default` does
+	return match syn::parse2::<Lit>(code.clone()) {
+		Ok(Lit::Str(_) | Lit::ByteStr(_)) => {
+			Some(value_check(field, quote! { ::std::convert::Into::into(#code) }, code))
+		},
+		_ => Some(value_check(field, code.clone(), code)),
+	};
+}
+
+/// Creates the check of `field` to equal the value `code` evaluates to.
+/// # Parameters
+/// * `text` &ndash; The value as it is written in the attribute.
+fn value_check(field: &Field, code: TokenStream, text: TokenStream) -> FieldCheck {
+	let ty = &field.ty;
+	let mut rendered = String::new();
+	write_tokens(text, &mut rendered);
+	// The value is bound to the field type, so conversions are inferred
+	let value = quote! {
+		{
+			let expected: #ty = #code;
+			expected
+		}
+	};
+	return FieldCheck::ExpectValue { value, text: rendered };
+}
+
+/// Finds the emptiness method mapped to `ty` by `empty_via` container option.
+/// Types are matched by [`is_listed_type()`].
+fn find_empty_via_method<'a>(ty: &Type, empty_via: &'a [(Path, Ident)]) -> Option<&'a Ident> {
+	return empty_via.iter().find(|(path, _)| is_listed_type(ty, path)).map(|(_, method)| method);
+}
+
+/// Whether a field of `ty` may be `Option`, so the check of a
+/// non&#x2011;annotated field describes its states as `Some` and `None`. A
+/// reference to `Option` and an unknown type, e.g. of a field listed in
+/// `check_initial_state_for!`, may be.
+fn may_be_option(ty: &Type) -> bool {
+	return match ungroup_type(ty) {
+		Type::Infer(_) => true,
+		Type::Reference(reference) => may_be_option(&reference.elem),
+		ty => is_option_type(ty),
+	};
+}
+
+/// Whether `ty` is the type listed in a container option as `path`. Types are
+/// matched by the last path segment, ignoring generic arguments, so `Vec`
+/// applies to `Vec<u8>` and `std::vec::Vec<String>`.
+fn is_listed_type(ty: &Type, path: &Path) -> bool {
+	let segment = match ungroup_type(ty) {
+		Type::Path(type_path) if type_path.qself.is_none() => type_path.path.segments.last(),
+		_ => None,
+	};
+	return match (segment, path.segments.last()) {
+		(Some(segment), Some(listed)) => segment.ident == listed.ident,
+		_ => false,
+	};
+}
+
+/// Parses `expect_matches` attribute, which expects a pattern in parentheses.
+/// The pattern itself is not validated here, but by `matches!` macro the
+/// generated code expands to.
+fn parse_pattern(attribute: &Attribute) -> syn::Result<FieldCheck> {
+	return match &attribute.meta {
+		Meta::List(list)
+			if matches!(list.delimiter, MacroDelimiter::Paren(_)) && !list.tokens.is_empty() => {
+			let mut text = String::new();
+			write_tokens(list.tokens.clone(), &mut text);
+			Ok(FieldCheck::ExpectMatches { pattern: list.tokens.clone(), text })
+		},
+		_ => {
+			Err(Error::new_spanned(
+				attribute,
+				"`expect_matches` attribute expects a pattern, e.g. `#[expect_matches(State::Idle)]`",
+			))
+		},
+	};
+}
+
+/// Parses `report_name` attribute, which expects a non&#x2011;empty string
+/// literal, e.g. `#[report_name = "Override timestamp"]`.
+fn parse_report_name(attribute: &Attribute) -> syn::Result<String> {
+	let report_name = match &attribute.meta {
+		Meta::NameValue(name_value) => {
+			match &name_value.value {
+				Expr::Lit(ExprLit { lit: Lit::Str(report_name), .. }) => Some(report_name.value()),
+				_ => None,
+			}
+		},
+		_ => None,
+	};
+	return match report_name {
+		Some(report_name) if report_name.contains(REPORT_NAME_FORBIDDEN_CHARACTERS) => {
+			Err(Error::new_spanned(
+				attribute,
+				"`report_name` must not contain quotes, backslashes, braces or backticks",
+			))
+		},
+		Some(report_name) if !report_name.trim().is_empty() => Ok(report_name),
+		_ => {
+			Err(Error::new_spanned(
+				attribute,
+				"`report_name` attribute expects a non-empty string literal, e.g. \
+					`#[report_name = \"Override timestamp\"]`",
+			))
+		},
+	};
+}
+
+/// Parses the value of `field_group` attribute, which is a non&#x2011;empty
+/// string literal, e.g. `#[field_group = "network"]`.
+fn parse_group(attribute: &Attribute) -> syn::Result<String> {
+	if let Meta::NameValue(name_value) = &attribute.meta {
+		if let Expr::Lit(ExprLit { lit: Lit::Str(group), .. }) = &name_value.value {
+			if !group.value().trim().is_empty() {
+				return Ok(group.value());
+			}
+		}
+	}
+	return Err(Error::new_spanned(
+		attribute,
+		"`field_group` attribute expects a non-empty string literal, e.g. \
+			`#[field_group = \"network\"]`",
+	));
+}
+
+/// Parses `default` option value, which is an expression either written as is,
+/// e.g. `default = 0`, or enclosed into a string literal, e.g.
+/// `default = "0"`.
+fn parse_default(input: ParseStream) -> syn::Result<Expr> {
+	if input.peek(LitStr) {
+		return input.parse::<LitStr>()?.parse::<Expr>();
+	}
+	return input.parse::<Expr>();
+}
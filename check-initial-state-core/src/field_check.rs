@@ -0,0 +1,232 @@
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{quote, quote_spanned};
+use std::borrow::Cow;
+
+/// Describes how a checked field is verified. Each check treats a field value
+/// as either *set* or *unset*, e.g. `Some` and `None` respectively.
+#[derive(Clone, Debug)]
+pub enum FieldCheck {
+	/// Field is expected to be `None`. Is applied to non&#x2011;annotated
+	/// `Option` fields.
+	IsNone,
+	/// Field is expected to be unset according to its implementation of
+	/// `check_initial_state::IsUnset` trait. Is applied to non&#x2011;annotated
+	/// fields, which are not `Option` by the name of their type.
+	IsUnset,
+	/// Field is expected to be unset according to `IsUnset` trait, or to be
+	/// `false`, zero or empty if it is of a common standard type, e.g. `bool`,
+	/// `u32` or `Vec`. Is applied to non&#x2011;annotated fields, which are not
+	/// `Option` by the name of their type, in `auto_unset` mode.
+	AutoUnset,
+	/// Field is expected to be `None` if it is `Option`, otherwise it is not
+	/// checked. Is applied to non&#x2011;annotated fields in `lenient` mode.
+	Lenient,
+	/// `bool` field is expected to be `false`. Is requested by `expect_false`
+	/// attribute.
+	ExpectFalse,
+	/// `bool` field is expected to be `true`. Is requested by `expect_true`
+	/// attribute.
+	ExpectTrue,
+	/// Numeric field is expected to be `0`. Is requested by `expect_zero`
+	/// attribute.
+	ExpectZero,
+	/// Field is expected to match a pattern. Is requested by
+	/// `expect_matches(pattern)` attribute. A field which doesn't match the
+	/// pattern is considered set.
+	ExpectMatches {
+		/// Pattern as it is written in the attribute.
+		pattern: TokenStream,
+		/// Pattern rendered for messages, e.g. `None | Some(0)`.
+		text: String,
+	},
+	/// Field is expected to equal a value. Is applied to fields which have a
+	/// non&#x2011;`None` default specified by `default` attribute of
+	/// `smart-default` crate, e.g. `#[default(10)]`. A field which doesn't
+	/// equal the value is considered set.
+	ExpectValue {
+		/// Expression evaluating to the value, which is converted to the field
+		/// type the way `smart-default` does.
+		value: TokenStream,
+		/// Value rendered for messages as it is written in the attribute, e.g.
+		/// `10`.
+		text: String,
+	},
+	/// Field of an `Option`&#x2011;like type, e.g. a newtype, is expected to
+	/// be `None` according to its `is_some()` method. Is requested by
+	/// `option_alias` attribute.
+	OptionAlias,
+	/// Field is expected to be empty according to its method, e.g.
+	/// `is_empty()`. Is requested by `empty_via` container option for the
+	/// field type.
+	EmptyVia {
+		/// Method which returns `true` for an empty value.
+		method: Ident,
+	},
+}
+
+impl Default for FieldCheck {
+	fn default() -> Self {
+		return Self::IsNone;
+	}
+}
+
+impl FieldCheck {
+	/// Generates `bool` expression which is `true` if the field is set.
+	/// # Parameters
+	/// * `value` &ndash; Expression evaluating to the field, e.g. `self.option`
+	/// or a copy of it, `{ self.option }`, for a packed struct.
+	/// * `span` &ndash; Span of the field type. Errors of a trait bound, which
+	/// the field type doesn't satisfy, e.g. a non&#x2011;`Option` field which is
+	/// not annotated, point at it rather than at the derive. The value is
+	/// parenthesized with the span, so it becomes the span of the argument.
+	pub fn is_set(&self, value: &TokenStream, span: Span) -> TokenStream {
+		return match self {
+			Self::IsNone | Self::IsUnset => {
+				quote_spanned! {span=> !::check_initial_state::IsUnset::is_unset(&(#value)) }
+			},
+			Self::AutoUnset => {
+				quote_spanned! {span=>
+					!::check_initial_state::__private::AutoUnset::is_auto_unset(&(#value))
+				}
+			},
+			Self::Lenient => {
+				quote! {
+					{
+						use ::check_initial_state::__private::{OptionProbe as _, OtherProbe as _};
+						(&::check_initial_state::__private::Probe(&#value)).is_set()
+					}
+				}
+			},
+			Self::ExpectFalse | Self::ExpectTrue => quote! { #value },
+			Self::ExpectZero => {
+				quote_spanned! {span=> !::check_initial_state::__private::Zero::is_zero(&(#value)) }
+			},
+			Self::ExpectMatches { pattern, .. } => quote! { !::std::matches!(#value, #pattern) },
+			Self::ExpectValue { value: expected, .. } => quote! { #value != #expected },
+			Self::OptionAlias => quote_spanned! {span=> #value.is_some() },
+			Self::EmptyVia { method } => quote! { !#value.#method() },
+		};
+	}
+
+	/// Whether the field is expected to be set initially.
+	pub fn expected_set(&self) -> bool {
+		return matches!(self, Self::ExpectTrue);
+	}
+
+	/// Describes the field which is set while expected to be unset.
+	pub fn set_reason(&self) -> Cow<'static, str> {
+		return match self {
+			Self::IsNone | Self::Lenient | Self::OptionAlias => {
+				Cow::Borrowed("has Some value instead of None")
+			},
+			Self::ExpectFalse | Self::ExpectTrue => Cow::Borrowed("is true instead of false"),
+			Self::ExpectZero => Cow::Borrowed("is non-zero instead of zero"),
+			Self::IsUnset | Self::AutoUnset => Cow::Borrowed("is set instead of unset"),
+			Self::ExpectMatches { text, .. } => Cow::Owned(format!("doesn't match `{}`", text)),
+			Self::ExpectValue { text, .. } => Cow::Owned(format!("doesn't equal `{}`", text)),
+			Self::EmptyVia { method } => {
+				Cow::Owned(format!("is not empty according to `{}()`", method))
+			},
+		};
+	}
+
+	/// Describes the field which is unset while expected to be set.
+	pub fn unset_reason(&self) -> Cow<'static, str> {
+		return match self {
+			Self::IsNone | Self::Lenient | Self::OptionAlias => {
+				Cow::Borrowed("has None value instead of Some")
+			},
+			Self::ExpectFalse | Self::ExpectTrue => Cow::Borrowed("is false instead of true"),
+			Self::ExpectZero => Cow::Borrowed("is zero instead of non-zero"),
+			Self::IsUnset | Self::AutoUnset => Cow::Borrowed("is unset instead of set"),
+			Self::ExpectMatches { text, .. } => Cow::Owned(format!("matches `{}` unexpectedly", text)),
+			Self::ExpectValue { text, .. } => Cow::Owned(format!("equals `{}` unexpectedly", text)),
+			Self::EmptyVia { method } => {
+				Cow::Owned(format!("is empty according to `{}()`", method))
+			},
+		};
+	}
+
+	/// Short descriptions of set and unset states respectively, e.g. `Some`
+	/// and `None`.
+	pub fn state_labels(&self) -> (Cow<'static, str>, Cow<'static, str>) {
+		return match self {
+			Self::IsNone | Self::Lenient | Self::OptionAlias => {
+				(Cow::Borrowed("Some"), Cow::Borrowed("None"))
+			},
+			Self::ExpectFalse | Self::ExpectTrue => (Cow::Borrowed("true"), Cow::Borrowed("false")),
+			Self::ExpectZero => (Cow::Borrowed("non-zero"), Cow::Borrowed("0")),
+			Self::IsUnset | Self::AutoUnset => (Cow::Borrowed("set"), Cow::Borrowed("unset")),
+			Self::ExpectMatches { text, .. } | Self::ExpectValue { text, .. } => {
+				(Cow::Owned(format!("not {}", text)), Cow::Owned(text.clone()))
+			},
+			Self::EmptyVia { .. } => (Cow::Borrowed("non-empty"), Cow::Borrowed("empty")),
+		};
+	}
+
+	/// Generates the value the field has in initial state. `None` is returned
+	/// if the value cannot be derived from the check, e.g. from a pattern, an
+	/// emptiness method, an `Option`&#x2011;like type or in `lenient` mode. A
+	/// `smart-default` value isn't returned either, since it is not necessarily
+	/// a constant expression.
+	pub fn initial_value(&self) -> Option<TokenStream> {
+		return match self {
+			Self::IsNone => Some(quote! { ::std::option::Option::None }),
+			Self::ExpectFalse => Some(quote! { false }),
+			Self::ExpectTrue => Some(quote! { true }),
+			Self::ExpectZero => Some(quote! { 0 as _ }),
+			Self::IsUnset
+			| Self::AutoUnset
+			| Self::Lenient
+			| Self::OptionAlias
+			| Self::ExpectMatches { .. }
+			| Self::ExpectValue { .. }
+			| Self::EmptyVia { .. } => None,
+		};
+	}
+
+	/// Generates a value which violates initial state of the field. The value
+	/// is used by mutation tests, either assigned directly or passed to a
+	/// setter. `None` is returned if such value cannot be derived from the
+	/// check, e.g. from a pattern or an emptiness method. `Option` fields,
+	/// which are assigned directly, and `lenient` mode fields are mutated in
+	/// place by [`FieldCheck::violate()`] instead.
+	/// # Parameters
+	/// * `setter` &ndash; Whether the value is passed to a setter. `Option`
+	/// payload is passed to a setter rather than the `Option` itself.
+	pub fn violating_value(&self, setter: bool) -> Option<TokenStream> {
+		return match (self, setter) {
+			(Self::IsNone, true) => Some(quote! { ::std::default::Default::default() }),
+			(Self::ExpectFalse, _) => Some(quote! { true }),
+			(Self::ExpectTrue, _) => Some(quote! { false }),
+			(Self::ExpectZero, _) => Some(quote! { 1 as _ }),
+			(
+				Self::IsNone
+				| Self::IsUnset
+				| Self::AutoUnset
+				| Self::Lenient
+				| Self::OptionAlias
+				| Self::ExpectMatches { .. }
+				| Self::ExpectValue { .. }
+				| Self::EmptyVia { .. },
+				_,
+			) => None,
+		};
+	}
+
+	/// Generates `bool` expression which sets `place`, e.g. `instance.option`,
+	/// to a value violating initial state, if such value is known at runtime
+	/// only. The expression is `true` if the field has been mutated. `Option`
+	/// fields are set to `Some(Default::default())` if their payload implements
+	/// `Default`, e.g. unlike `Box<dyn Trait>`, while others are left intact.
+	/// Is applicable to `Option` fields and to `lenient` mode. The expression
+	/// relies on the probe generated along with the mutation test.
+	pub fn violate(&self, place: &TokenStream) -> Option<TokenStream> {
+		return match self {
+			Self::IsNone | Self::Lenient => {
+				Some(quote! { (&mut __MutProbe(&mut #place)).violate() })
+			},
+			_ => None,
+		};
+	}
+}
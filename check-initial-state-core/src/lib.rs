@@ -0,0 +1,2731 @@
+//! Parsing and code generation behind `CheckInitialState` derive of
+//! [check-initial-state](https://crates.io/crates/check-initial-state).
+//!
+//! The derive itself is a thin wrapper around [`expand()`]. Other procedural
+//! macros and build tools may use this crate to apply the same attribute model
+//! and field selection rules, e.g. to know which fields `check_initial_state()`
+//! checks:
+//! ```
+//! use check_initial_state_core::{parse_container_options, parse_fields};
+//!
+//! let input = syn::parse_quote! {
+//!     struct Builder {
+//!         option: Option<u8>,
+//!         #[ignore_field]
+//!         counter: u8,
+//!     }
+//! };
+//! let options = parse_container_options(&input).unwrap();
+//! let checked_fields = parse_fields(&input, &options).unwrap()
+//!     .into_iter()
+//!     .filter(|field| !field.options.ignored)
+//!     .map(|field| field.ident.to_string())
+//!     .collect::<Vec<_>>();
+//! assert_eq!(checked_fields, ["option"]);
+//! ```
+//!
+//! Errors are returned as tokens of compile errors, which are expected to be
+//! emitted by the calling procedural macro as is.
+#![allow(clippy::needless_return, clippy::doc_lazy_continuation)]
+
+mod bon_builder;
+#[cfg(feature = "item-macros")]
+mod check_all_builders;
+mod check_any_set;
+mod check_invariants;
+mod check_phase;
+mod check_for;
+#[cfg(feature = "item-macros")]
+mod checked_constructor;
+mod config_file;
+mod container_attribute;
+mod debug_dump;
+mod delegation;
+mod disabled_switch;
+mod field_attribute;
+mod field_check;
+mod option_errors;
+#[cfg(feature = "item-macros")]
+mod post_build_check;
+mod rust_version;
+mod serde_attribute;
+#[cfg(feature = "item-macros")]
+mod verify_initial_literals;
+
+#[cfg(feature = "item-macros")]
+pub use check_all_builders::expand_check_all_builders;
+pub use check_any_set::expand_check_any_set;
+pub use check_invariants::expand_check_invariants;
+pub use check_phase::expand_check_phase;
+pub use check_for::expand_check_for;
+#[cfg(feature = "item-macros")]
+pub use checked_constructor::expand_checked_constructor;
+pub use config_file::{ConfigFile, CONFIG_FILE_NAME};
+pub use container_attribute::{parse_container_options, ContainerOptions, EmptyCheckPolicy};
+pub use field_attribute::{check_misplaced_attributes, parse_field_options, FieldOptions};
+pub use field_check::FieldCheck;
+#[cfg(feature = "item-macros")]
+pub use post_build_check::expand_post_build_check;
+pub use rust_version::RustVersion;
+#[cfg(feature = "item-macros")]
+pub use verify_initial_literals::expand_verify_initial_literals;
+
+use container_attribute::DEFAULT_MAX_VALUE_LEN;
+use proc_macro2::{Delimiter, Group, Ident, Spacing, Span, TokenStream, TokenTree};
+use quote::{format_ident, quote, quote_spanned};
+use syn::ext::IdentExt;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::token::Comma;
+use syn::visit_mut::{self, VisitMut};
+use syn::{
+	Data, DataStruct, DeriveInput, Error, Expr, Field, Fields, Generics, Path, PathSegment, QSelf,
+	Type, TypePath, Visibility, WhereClause,
+};
+
+/// Counter of `check_initial_state()` and `try_check_initial_state()` calls,
+/// which is reported with `metrics` feature.
+const CHECKS_METRIC: &str = "check_initial_state.checks";
+
+/// Counter of fields found not in initial state, which is reported with
+/// `metrics` feature.
+const VIOLATIONS_METRIC: &str = "check_initial_state.violations";
+
+/// Label of metrics holding the checked struct name.
+const STRUCT_LABEL: &str = "struct";
+
+/// Label of violation metrics holding the field name.
+const FIELD_LABEL: &str = "field";
+
+/// Prefix of the hidden constant, which is generated next to the struct and
+/// lists the fields expected to be `None`. Is followed by the struct name.
+const NONE_FIELDS_CONST_PREFIX: &str = "__CHECK_INITIAL_STATE_NONE_FIELDS_";
+
+/// Prefix of the hidden constant, which is generated next to the struct and
+/// lists its checked fields. Is followed by the struct name.
+const CHECKED_FIELDS_CONST_PREFIX: &str = "__CHECK_INITIAL_STATE_CHECKED_FIELDS_";
+
+/// Prefix of the hidden static, which is generated next to the struct with
+/// `track_usage` option and records whether the struct was checked. Is
+/// followed by the struct name.
+const USAGE_STATIC_PREFIX: &str = "__CHECK_INITIAL_STATE_USAGE_";
+
+/// Generates the implementation of `CheckInitialState` derive for `input`.
+/// Problems of `input` are reported as compile errors in the returned tokens.
+pub fn expand(input: &DeriveInput) -> TokenStream {
+	if let Err(err) = check_misplaced_attributes(input) {
+		return err;
+	}
+	let options = match parse_container_options(input) {
+		Ok(options) => options,
+		Err(err) => return err,
+	};
+	let result = expand_struct(input, &options);
+	let result = match &options.crate_path {
+		Some(crate_path) => replace_crate_path(result, crate_path),
+		None => result,
+	};
+	debug_dump::dump(&input.ident, &result);
+	return result;
+}
+
+/// Generates the implementation of `CheckInitialState` derive for `input`
+/// configured with `options`. Generated code refers to the runtime crate as
+/// `::check_initial_state`.
+fn expand_struct(input: &DeriveInput, options: &ContainerOptions) -> TokenStream {
+	match delegation::find_delegate(input, options) {
+		Ok(Some(delegate)) => return delegation::generate_delegation(input, &delegate, options),
+		Ok(None) => (),
+		Err(err) => return err,
+	}
+	let fields = match parse_fields(input, options) {
+		Ok(fields) => fields,
+		Err(err) => return err,
+	};
+	// Filter out fields with `ignore_field` attribute
+	let checked_fields = fields.iter().filter(|field| !field.options.ignored).collect::<Vec<_>>();
+	let struct_name = &input.ident;
+	let mut provenance_fields = fields.iter().filter(|field| field.options.provenance);
+	let provenance = provenance_fields.next().map(|field| field.ident);
+	if let Some(field) = provenance_fields.next() {
+		return to_compile_error(field.ident, "Struct may have a single `provenance` field only");
+	}
+	let empty_check_message = format!(
+		"All fields of `{}` are ignored, so `check_initial_state()` checks nothing",
+		struct_name,
+	);
+	let empty_check_warning = match (checked_fields.is_empty(), options.empty_check) {
+		(true, EmptyCheckPolicy::Deny) => return to_compile_error(struct_name, &empty_check_message),
+		(true, EmptyCheckPolicy::Warn) => {
+			let item_name = format_ident!("__check_initial_state_warning_{}", struct_name);
+			generate_warning(struct_name.span(), &empty_check_message, &item_name, options.msrv)
+		},
+		_ => TokenStream::new(),
+	};
+	let generics = generics_with_bound(input, options);
+	let (leading_generics, trailing_generics, where_clause) = &generics.split_for_impl();
+	// Lifetimes, bounds and where clauses, including higher-ranked ones,
+	// are kept as they are written in the struct
+	let impl_header =
+		quote! { impl #leading_generics #struct_name #trailing_generics #where_clause };
+	// Makes panic location point to the caller of `check_initial_state()`
+	let track_caller = if RustVersion::supports(options.msrv, RustVersion::TRACK_CALLER) {
+		quote! { #[track_caller] }
+	} else {
+		TokenStream::new()
+	};
+	let warnings = if options.warn_ignored_option {
+		generate_ignored_option_warnings(struct_name, &fields, options.msrv)
+	} else {
+		TokenStream::new()
+	};
+	// Fields which checks are generated for. Nothing is verified if checks are
+	// disabled, while the generated API is kept
+	let verified_fields: &[&ParsedField] = if options.disabled { &[] } else { &checked_fields };
+	if let Some(remote) = &options.remote {
+		if let Some(provenance) = provenance {
+			let message = "`provenance` attribute cannot be combined with `remote` option";
+			return to_compile_error(provenance, message);
+		}
+		let remote_check =
+			generate_remote_check(input, remote, &impl_header, verified_fields, options, &track_caller);
+		let dependency_tracking = generate_dependency_tracking(options);
+		return quote! {
+			#remote_check
+
+			#warnings
+
+			#empty_check_warning
+
+			#dependency_tracking
+		};
+	}
+	let struct_name_string = struct_name.to_string();
+	let check_count = generate_check_count(&struct_name_string);
+	let (usage_static, usage_mark) = generate_usage_tracking(input, &struct_name_string, options);
+	let check_prelude = if options.disabled {
+		TokenStream::new()
+	} else {
+		generate_check_prelude(options)
+	};
+	let violations = verified_fields.iter()
+		.map(|field| (field.report_name(), generate_violation(field, options, &quote!(self))))
+		.collect::<Vec<_>>();
+	let (field_checks, failure_fn) = if options.compare_with.is_some() && !options.disabled {
+		let diff_checks =
+			generate_diff_checks(&struct_name_string, verified_fields, &violations, options);
+		(diff_checks, TokenStream::new())
+	} else {
+		let table = generate_state_table(&struct_name_string, verified_fields, options, &quote!(self));
+		let provenance = match provenance {
+			Some(provenance) => quote!(self.#provenance),
+			None => TokenStream::new(),
+		};
+		generate_field_panics(
+			&struct_name_string,
+			verified_fields,
+			&violations,
+			options,
+			&table,
+			&provenance,
+			&quote!(&self),
+			&quote!(self),
+			&track_caller,
+		)
+	};
+	#[cfg(feature = "json")]
+	let json_report = generate_json_report(struct_name, &check_prelude, &violations);
+	#[cfg(not(feature = "json"))]
+	let json_report = TokenStream::new();
+	#[cfg(feature = "defmt")]
+	let defmt_report = generate_defmt_report(&check_prelude, &violations, options);
+	#[cfg(not(feature = "defmt"))]
+	let defmt_report = TokenStream::new();
+	let report_format = tag_message(options, "Field `{}` {}");
+	let report_writes = violations.iter().map(|(field_name, violation)| {
+		return quote! {
+			if let ::std::option::Option::Some(reason) = #violation {
+				::std::writeln!(writer, #report_format, #field_name, reason)?;
+			}
+		};
+	});
+	let violation_pushes = generate_violation_pushes(&struct_name_string, verified_fields, &violations);
+	let introspection_consts = generate_introspection_consts(&checked_fields);
+	let field_metadata = generate_field_metadata(&fields);
+	let none_fields_const = generate_none_fields_const(input, verified_fields, options);
+	let checked_fields_const = generate_checked_fields_const(input, &checked_fields, options);
+	let inherited_checks = match generate_inherited_checks(input, &fields, options) {
+		Ok(inherited_checks) => inherited_checks,
+		Err(err) => return err,
+	};
+	let initial_const = if options.initial_const {
+		match generate_initial_const(&fields) {
+			Ok(initial_const) => initial_const,
+			Err(err) => return err,
+		}
+	} else {
+		TokenStream::new()
+	};
+	let builder_fn = match &options.builder_fn {
+		Some(name) => {
+			match generate_builder_fn(input, &fields, name, options) {
+				Ok(builder_fn) => builder_fn,
+				Err(err) => return err,
+			}
+		},
+		None => TokenStream::new(),
+	};
+	let try_constructors = if options.try_constructors {
+		generate_try_constructors(input, options)
+	} else {
+		TokenStream::new()
+	};
+	let error_bits = if options.error_bits {
+		match generate_error_bits(struct_name, &check_prelude, &violations) {
+			Ok(error_bits) => error_bits,
+			Err(err) => return err,
+		}
+	} else {
+		TokenStream::new()
+	};
+	let violations_iter = if options.violations_iter {
+		generate_violations_iter(&check_prelude, &violations)
+	} else {
+		TokenStream::new()
+	};
+	let no_panic_check = if options.no_panic {
+		generate_no_panic_check(verified_fields, &check_prelude, &violations)
+	} else {
+		TokenStream::new()
+	};
+	let (field_enum, field_enum_check) = if options.field_enum {
+		let field_enum = generate_field_enum(
+			input,
+			&checked_fields,
+			verified_fields,
+			&check_prelude,
+			&violations,
+			options,
+		);
+		match field_enum {
+			Ok(field_enum) => field_enum,
+			Err(err) => return err,
+		}
+	} else {
+		(TokenStream::new(), TokenStream::new())
+	};
+	let same_state = generate_same_state(verified_fields, options, &track_caller);
+	let field_state_visitor = generate_field_state_visitor(verified_fields, options);
+	let observer_check = generate_observer_check(
+		&struct_name_string,
+		verified_fields,
+		&check_prelude,
+		&violations,
+		options,
+	);
+	let tracked_setters = match provenance {
+		Some(provenance) => generate_tracked_setters(&checked_fields, provenance, &track_caller),
+		None => TokenStream::new(),
+	};
+	let constructor_tests = if options.disabled {
+		TokenStream::new()
+	} else {
+		generate_constructor_tests(struct_name, &checked_fields, options)
+	};
+	let bon_test = if options.bon {
+		match bon_builder::generate_bon_test(input, &fields, options) {
+			Ok(bon_test) => bon_test,
+			Err(err) => return err,
+		}
+	} else {
+		TokenStream::new()
+	};
+	let registration = generate_registration(struct_name, options);
+	let kani_proofs = if options.kani_proof && !options.disabled {
+		generate_kani_proofs(struct_name, options)
+	} else {
+		TokenStream::new()
+	};
+	let fixture = match options.constructors.first() {
+		Some(constructor) if options.fixture => {
+			generate_fixture(input, &generics, constructor, options)
+		},
+		_ => TokenStream::new(),
+	};
+	let coverage_off = generate_coverage_off();
+	let display = if options.derive_display {
+		let table = generate_table(&struct_name_string, &checked_fields, options, &quote!(self));
+		quote! {
+			#[automatically_derived]
+			impl #leading_generics ::std::fmt::Display
+				for #struct_name #trailing_generics #where_clause {
+				#coverage_off
+				fn fmt(&self, formatter: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+					::std::fmt::Display::fmt(&#table, formatter)
+				}
+			}
+		}
+	} else {
+		TokenStream::new()
+	};
+	let default_impl = if options.derive_default {
+		match generate_default_impl(input, &generics, &fields) {
+			Ok(default_impl) => default_impl,
+			Err(err) => return err,
+		}
+	} else {
+		TokenStream::new()
+	};
+	let dependency_tracking = generate_dependency_tracking(options);
+	// Context is not a part of the payload
+	let unused_context = if options.panic_payload || verified_fields.is_empty() {
+		quote! { let _ = context; }
+	} else {
+		TokenStream::new()
+	};
+	return quote! {
+		#[automatically_derived]
+		#[allow(dead_code, clippy::panic)]
+		#impl_header {
+			#introspection_consts
+
+			#field_metadata
+
+			#initial_const
+
+			#builder_fn
+
+			#try_constructors
+
+			/// Checks all `Option` fields to have `None` at the time of this
+			/// method call. Is expected to be used for testing purposes.
+			/// # Panics
+			/// Any of `self` fields, which are not annotated with
+			/// `ignore_field`, are `Some`. Panic message will contain the name
+			/// of an `Option` field which has some value.
+			#track_caller
+			#coverage_off
+			#[inline]
+			fn check_initial_state(&self) {
+				Self::check_initial_state_with_context(self, "")
+			}
+
+			/// Checks fields like `check_initial_state()`, but prepends
+			/// `context` to the panic message, e.g. the name of the scenario
+			/// which constructed the instance. Nothing is prepended if
+			/// `context` is empty.
+			/// # Panics
+			/// Any of `self` fields, which are not annotated with
+			/// `ignore_field`, is not in initial state.
+			#track_caller
+			#coverage_off
+			#[inline]
+			fn check_initial_state_with_context(&self, context: &str) {
+				#unused_context
+				#usage_mark
+				#check_count
+				#check_prelude
+				#field_checks
+			}
+
+			#failure_fn
+
+			/// Writes a line to `writer` for each `Option` field, which is not
+			/// annotated with `ignore_field` and is `Some`. Nothing is written
+			/// if all such fields are `None`. Unlike `check_initial_state()`,
+			/// all violations are reported and nothing panics.
+			/// # Errors
+			/// Writing to `writer` failed.
+			#coverage_off
+			fn write_initial_state_report(
+				&self,
+				writer: &mut (impl ::std::io::Write + ?::std::marker::Sized),
+			) -> ::std::io::Result<()> {
+				#check_prelude
+				#(#report_writes)*
+				::std::result::Result::Ok(())
+			}
+
+			#json_report
+
+			#defmt_report
+
+			#error_bits
+
+			#violations_iter
+
+			#no_panic_check
+
+			#field_enum_check
+
+			#same_state
+
+			#field_state_visitor
+
+			#observer_check
+
+			#tracked_setters
+		}
+
+		#[automatically_derived]
+		#[allow(dead_code, clippy::panic)]
+		impl #leading_generics ::check_initial_state::CheckInitialState
+			for #struct_name #trailing_generics #where_clause {
+			#track_caller
+			#coverage_off
+			fn check_initial_state(&self) {
+				// Inherent method takes precedence
+				Self::check_initial_state(self)
+			}
+
+			#coverage_off
+			fn try_check_initial_state(&self)
+				-> ::std::result::Result<(), ::check_initial_state::InitialStateError> {
+				#usage_mark
+				#check_count
+				#check_prelude
+				let mut violations = ::std::vec::Vec::new();
+				#violation_pushes
+				if violations.is_empty() {
+					::std::result::Result::Ok(())
+				} else {
+					::std::result::Result::Err(
+						::check_initial_state::InitialStateError::new(#struct_name_string, violations),
+					)
+				}
+			}
+		}
+
+		#display
+
+		#default_impl
+
+		#field_enum
+
+		#none_fields_const
+
+		#checked_fields_const
+
+		#inherited_checks
+
+		#usage_static
+
+		#constructor_tests
+
+		#bon_test
+
+		#registration
+
+		#fixture
+
+		#kani_proofs
+
+		#warnings
+
+		#empty_check_warning
+
+		#dependency_tracking
+	};
+}
+
+/// Generates panics of `check_initial_state()` on the first field which is not
+/// in initial state.
+/// # Parameters
+/// * `struct_name` &ndash; Name of the checked struct, which labels metrics.
+/// * `violations` &ndash; Pairs of a checked field name and an expression,
+/// which evaluates to `Some` reason if the field is not in initial state.
+/// Correspond to `checked_fields` by index.
+/// * `options` &ndash; Options of the struct, which tell whether to panic
+/// with `InitialStatePanic` payload, how to tag the message and which setter
+/// to suggest for a stray value.
+/// * `table` &ndash; Expression evaluating to the state table, which is
+/// appended to the message. Nothing is appended if empty.
+/// * `provenance` &ndash; Expression evaluating to `Provenance` of the struct,
+/// which tells where the violating field was set. The location follows the
+/// reason, if recorded. Nothing is appended if empty.
+/// * `receiver` &ndash; Parameter of the failure function, which binds the
+/// checked instance, e.g. `&self`.
+/// * `instance` &ndash; Expression passing the checked instance to the failure
+/// function, e.g. `self`.
+/// * `track_caller` &ndash; `track_caller` attribute, which makes the panic
+/// point to the caller of the check rather than to the failure function.
+/// # Returns
+/// The field checks and the failure function they call. The failure function
+/// is `#[cold]` and is never inlined, so the checks, which merely scan the
+/// fields, stay small enough to be inlined into the caller. Nothing is
+/// generated if there are no checked fields.
+#[allow(clippy::too_many_arguments)]
+fn generate_field_panics(
+	struct_name: &str,
+	checked_fields: &[&ParsedField],
+	violations: &[(String, TokenStream)],
+	options: &ContainerOptions,
+	table: &TokenStream,
+	provenance: &TokenStream,
+	receiver: &TokenStream,
+	instance: &TokenStream,
+	track_caller: &TokenStream,
+) -> (TokenStream, TokenStream) {
+	if checked_fields.is_empty() {
+		return (TokenStream::new(), TokenStream::new());
+	}
+	let mut format = String::from("Field `{}` {}");
+	let mut arguments = vec![quote!(field_name), quote!(reason)];
+	if !provenance.is_empty() {
+		format.push_str("{}");
+		arguments.push(quote! {
+			::check_initial_state::__private::LocationNote(#provenance.location(field_name))
+		});
+	}
+	if options.setter_hint.is_some() {
+		format.push_str("{}");
+		arguments.push(quote!(setter_hint));
+	}
+	let mut format = tag_message(options, &format);
+	if !table.is_empty() {
+		format.push_str("\n{}");
+		arguments.push(table.clone());
+	}
+	let message = quote!(#format, #(#arguments),*);
+	// The panic is the same for all fields, which name is bound to `field_name`
+	let panic = generate_panic(struct_name, &quote!(field_name), options.panic_payload, message);
+	let setter_hint_parameter = options.setter_hint.as_ref()
+		.map(|_| quote! { setter_hint: &'static str, });
+	let coverage_off = generate_coverage_off();
+	let failure_fn = quote! {
+		#[cold]
+		#[inline(never)]
+		#track_caller
+		#coverage_off
+		#[allow(unused_variables)]
+		fn __check_initial_state_failed(
+			#receiver,
+			context: &str,
+			field_name: &'static str,
+			reason: &'static str,
+			#setter_hint_parameter
+		) -> ! {
+			#panic
+		}
+	};
+	let field_panics = checked_fields.iter().zip(violations).map(|(field, violation)| {
+		let (field_name, violation) = violation;
+		let violation_count = generate_violation_count(struct_name, field_name);
+		let setter_hint = options.setter_hint.as_ref()
+			.map(|setter| generate_setter_hint(field, setter))
+			.map(|hint| quote!(#hint,));
+		return quote! {
+			if let ::std::option::Option::Some(reason) = #violation {
+				#violation_count
+				Self::__check_initial_state_failed(#instance, context, #field_name, reason, #setter_hint);
+			};
+		};
+	});
+	return (quote! { #(#field_panics)* }, failure_fn);
+}
+
+/// Generates the hint suggesting the setter, named by `setter` convention, which
+/// probably assigned a stray value to `field`, e.g.
+/// ", was it set via `set_endpoint(...)`?". The hint is empty if `field` is
+/// expected to be set, since then the violation is a missing value.
+fn generate_setter_hint(field: &ParsedField, setter: &str) -> String {
+	if field.options.check.expected_set() {
+		return String::new();
+	}
+	let setter = setter.replace("{}", &field.ident.unraw().to_string());
+	return format!(", was it set via `{}(...)`?", setter);
+}
+
+/// Generates `set_<field>_tracked()` method for each of `checked_fields`,
+/// which assigns the field and records the caller location in `provenance`
+/// field. So the panic of `check_initial_state()` names where the violating
+/// field was set.
+/// # Parameters
+/// * `track_caller` &ndash; `track_caller` attribute, which makes the caller
+/// location available. The location of the setter itself is recorded if
+/// empty.
+fn generate_tracked_setters(
+	checked_fields: &[&ParsedField],
+	provenance: &Ident,
+	track_caller: &TokenStream,
+) -> TokenStream {
+	let setters = checked_fields.iter().map(|field| {
+		let ident = field.ident;
+		let ty = field.ty;
+		let setter = format_ident!("set_{}_tracked", ident.unraw());
+		let field_name = field.report_name();
+		return quote! {
+			/// Assigns `value` to the field, recording the caller location,
+			/// which the panic of `check_initial_state()` names if the field is
+			/// not in initial state.
+			#track_caller
+			fn #setter(&mut self, value: #ty) {
+				self.#ident = value;
+				self.#provenance.record(#field_name, ::std::panic::Location::caller());
+			}
+		};
+	});
+	return quote! { #(#setters)* };
+}
+
+/// Generates an expression evaluating to the table of all `checked_fields` of
+/// `instance`, which is appended to panic messages. Values of fields
+/// implementing `Debug` are printed with `table_values` option, truncated to
+/// `max_value_len` characters, unless the field is redacted. Nothing is
+/// generated without `table` feature, or if the panic payload is not a message.
+fn generate_state_table(
+	struct_name: &str,
+	checked_fields: &[&ParsedField],
+	options: &ContainerOptions,
+	instance: &TokenStream,
+) -> TokenStream {
+	if !cfg!(feature = "table") || options.panic_payload {
+		return TokenStream::new();
+	}
+	return generate_table(struct_name, checked_fields, options, instance);
+}
+
+/// Generates an expression evaluating to the table of all `checked_fields` of
+/// `instance`, regardless of `table` feature. The table is either appended to
+/// panic messages or rendered by `Display` implementation.
+fn generate_table(
+	struct_name: &str,
+	checked_fields: &[&ParsedField],
+	options: &ContainerOptions,
+	instance: &TokenStream,
+) -> TokenStream {
+	let rows = checked_fields.iter().map(|field| {
+		let field_name = field.report_name();
+		let value = field_value(instance, field.ident, options.packed);
+		let check = &field.options.check;
+		let is_set = check.is_set(&value, field.ty.span());
+		let (set_label, unset_label) = check.state_labels();
+		let violating = if check.expected_set() { quote!(!is_set) } else { quote!(is_set) };
+		let debug_value = if options.table_values && field.options.redacted {
+			quote!(::std::option::Option::Some(::std::string::String::from("<redacted>")))
+		} else if options.table_values {
+			let max_value_len = options.max_value_len.unwrap_or(DEFAULT_MAX_VALUE_LEN);
+			quote! {
+				{
+					use ::check_initial_state::__private::{DebugValue as _, OtherDebugValue as _};
+					(&::check_initial_state::__private::DebugProbe(&#value)).debug_value(#max_value_len)
+				}
+			}
+		} else {
+			quote!(::std::option::Option::None)
+		};
+		return quote! {
+			let is_set = #is_set;
+			table.row(
+				#field_name,
+				if is_set { #set_label } else { #unset_label },
+				#violating,
+				#debug_value,
+			);
+		};
+	});
+	return quote! {
+		{
+			let mut table = ::check_initial_state::__private::StateTable::new(#struct_name);
+			#(#rows)*
+			table
+		}
+	};
+}
+
+/// Surrounds `format` string of a failure message with `message_prefix` and
+/// `message_suffix` options, escaping their braces, so the result is still a
+/// format string.
+fn tag_message(options: &ContainerOptions, format: &str) -> String {
+	let escape = |text: &str| text.replace('{', "{{").replace('}', "}}");
+	return format!(
+		"{}{}{}",
+		escape(&options.message_prefix),
+		format,
+		escape(&options.message_suffix),
+	);
+}
+
+/// Generates a panic on a field which is not in initial state. The reason is
+/// expected to be bound to `reason` variable, while the caller&#x2011;supplied
+/// context is expected to be bound to `context` variable. The context precedes
+/// the message, unless it is empty.
+/// # Parameters
+/// * `struct_name` &ndash; Name of the checked struct.
+/// * `field_name` &ndash; Expression evaluating to the name of the field.
+/// * `panic_payload` &ndash; Whether to panic with `InitialStatePanic` payload,
+/// which tests may downcast, instead of a message.
+/// * `message` &ndash; Arguments of `panic!` formatting the message.
+fn generate_panic(
+	struct_name: &str,
+	field_name: &TokenStream,
+	panic_payload: bool,
+	message: TokenStream,
+) -> TokenStream {
+	if !panic_payload {
+		return quote! {
+			::std::panic!(
+				"{}{}{}",
+				context,
+				if context.is_empty() { "" } else { ": " },
+				::std::format_args!(#message),
+			)
+		};
+	}
+	return quote! {
+		::std::panic::panic_any(
+			::check_initial_state::InitialStatePanic::new(#struct_name, #field_name, reason),
+		)
+	};
+}
+
+/// Generates statements of `try_check_initial_state()`, which push a
+/// `Violation` into `violations` vector for each field which is not in initial
+/// state.
+/// # Parameters
+/// * `struct_name` &ndash; Name of the checked struct, which labels metrics.
+/// * `fields` &ndash; Checked fields in the order of `violations`.
+/// * `violations` &ndash; Pairs of a checked field name and an expression,
+/// which evaluates to `Some` reason if the field is not in initial state.
+fn generate_violation_pushes(
+	struct_name: &str,
+	fields: &[&ParsedField],
+	violations: &[(String, TokenStream)],
+) -> TokenStream {
+	let violation_pushes = fields.iter().zip(violations).map(|(field, (field_name, violation))| {
+		let violation_count = generate_violation_count(struct_name, field_name);
+		let new_violation = generate_new_violation(field);
+		return quote! {
+			if let ::std::option::Option::Some(reason) = #violation {
+				#violation_count
+				violations.push(#new_violation);
+			}
+		};
+	});
+	return quote! { #(#violation_pushes)* };
+}
+
+/// Generates an expression which creates `Violation` of `field` out of `reason`
+/// variable. The name of the field is passed separately from its
+/// `report_name`, so `assert_initial_state!` may spell out an access of the
+/// field.
+fn generate_new_violation(field: &ParsedField) -> TokenStream {
+	let field_name = field.report_name();
+	return match &field.options.report_name {
+		Some(_) => {
+			let ident = field.ident.to_string();
+			quote! { ::check_initial_state::Violation::renamed(#ident, #field_name, reason) }
+		},
+		None => quote! { ::check_initial_state::Violation::new(#field_name, reason) },
+	};
+}
+
+/// Generates a statement which increments `check_initial_state.checks` counter
+/// of [metrics](https://crates.io/crates/metrics) facade, labeled with
+/// `struct_name`. Nothing is generated without `metrics` feature.
+fn generate_check_count(struct_name: &str) -> TokenStream {
+	if !cfg!(feature = "metrics") {
+		return TokenStream::new();
+	}
+	return quote! {
+		::metrics::counter!(#CHECKS_METRIC, #STRUCT_LABEL => #struct_name).increment(1);
+	};
+}
+
+/// Generates a hidden static next to `input` struct, which registers it in
+/// `usage` module of `check-initial-state` runtime crate via `linkme`
+/// distributed slice, and a statement marking the struct checked. So
+/// `report_unchecked()` lists the structs which checks were never called.
+/// Nothing is generated without `track_usage` option.
+/// # Parameters
+/// * `struct_name` &ndash; Name of the struct reported by
+/// `report_unchecked()`.
+/// * `options` &ndash; Options of the struct, which tell whether to track it.
+fn generate_usage_tracking(input: &DeriveInput, struct_name: &str, options: &ContainerOptions)
+	-> (TokenStream, TokenStream) {
+	if !options.track_usage {
+		return (TokenStream::new(), TokenStream::new());
+	}
+	let static_name = format_ident!("{}{}", USAGE_STATIC_PREFIX, input.ident);
+	// Items of the runtime crate are referred to through its macro, which
+	// expands to nothing without its `usage-tracking` feature
+	let usage_static = quote! {
+		::check_initial_state::__private::usage_tracked! {
+			#[doc(hidden)]
+			#[allow(non_upper_case_globals)]
+			#[::check_initial_state::__private::linkme::distributed_slice(
+				::check_initial_state::usage::TRACKED_STRUCTS
+			)]
+			#[linkme(crate = ::check_initial_state::__private::linkme)]
+			static #static_name: ::check_initial_state::usage::TrackedStruct =
+				::check_initial_state::usage::TrackedStruct::new(#struct_name);
+		}
+	};
+	let usage_mark = quote! {
+		::check_initial_state::__private::usage_tracked! { #static_name.mark_checked(); }
+	};
+	return (usage_static, usage_mark);
+}
+
+/// Generates a statement which increments `check_initial_state.violations`
+/// counter of [metrics](https://crates.io/crates/metrics) facade, labeled with
+/// `struct_name` and `field_name`. Nothing is generated without `metrics`
+/// feature.
+fn generate_violation_count(struct_name: &str, field_name: &str) -> TokenStream {
+	if !cfg!(feature = "metrics") {
+		return TokenStream::new();
+	}
+	return quote! {
+		::metrics::counter!(
+			#VIOLATIONS_METRIC,
+			#STRUCT_LABEL => #struct_name,
+			#FIELD_LABEL => #field_name
+		)
+			.increment(1);
+	};
+}
+
+/// Generates the check of `remote` struct, which `input` struct mirrors, in
+/// `serde` remote derive manner. `CheckInitialState` trait cannot be
+/// implemented for a foreign type, so `check_initial_state()` and
+/// `try_check_initial_state()` are generated as associated functions of the
+/// mirror, which take a reference to `remote` struct. Fields of the mirror
+/// define the checked fields of `remote` struct, which must be accessible.
+fn generate_remote_check(
+	input: &DeriveInput,
+	remote: &Path,
+	impl_header: &TokenStream,
+	checked_fields: &[&ParsedField],
+	options: &ContainerOptions,
+	track_caller: &TokenStream,
+) -> TokenStream {
+	let violations = checked_fields.iter()
+		.map(|field| (field.report_name(), generate_violation(field, options, &quote!(instance))))
+		.collect::<Vec<_>>();
+	// Parsed paths have at least one segment
+	let remote_name = remote.segments.last().unwrap().ident.to_string();
+	let table = generate_state_table(&remote_name, checked_fields, options, &quote!(instance));
+	let (field_panics, failure_fn) = generate_field_panics(
+		&remote_name,
+		checked_fields,
+		&violations,
+		options,
+		&table,
+		&TokenStream::new(),
+		&quote!(instance: &#remote),
+		&quote!(instance),
+		track_caller,
+	);
+	let violation_pushes = generate_violation_pushes(&remote_name, checked_fields, &violations);
+	let check_count = generate_check_count(&remote_name);
+	let (usage_static, usage_mark) = generate_usage_tracking(input, &remote_name, options);
+	let visibility = generated_visibility(input, options);
+	let coverage_off = generate_coverage_off();
+	// Context is not a part of the payload
+	let unused_context = if options.panic_payload || checked_fields.is_empty() {
+		quote! { let _ = context; }
+	} else {
+		TokenStream::new()
+	};
+	return quote! {
+		#[automatically_derived]
+		#[allow(dead_code, clippy::panic)]
+		#impl_header {
+			/// Checks fields of `instance`, which are mirrored by this struct
+			/// and are not annotated with `ignore_field`, to be in initial
+			/// state. Is expected to be used for testing purposes.
+			/// # Panics
+			/// Any of the checked fields is not in initial state. Panic message
+			/// will contain the name of such field.
+			#track_caller
+			#coverage_off
+			#[inline]
+			#visibility fn check_initial_state(instance: &#remote) {
+				Self::check_initial_state_with_context(instance, "")
+			}
+
+			/// Checks fields of `instance` like `check_initial_state()`, but
+			/// prepends `context` to the panic message. Nothing is prepended if
+			/// `context` is empty.
+			/// # Panics
+			/// Any of the checked fields is not in initial state.
+			#track_caller
+			#coverage_off
+			#[inline]
+			#visibility fn check_initial_state_with_context(instance: &#remote, context: &str) {
+				#unused_context
+				#usage_mark
+				#check_count
+				#field_panics
+			}
+
+			#failure_fn
+
+			/// Checks fields of `instance` like `check_initial_state()`, but
+			/// reports all violations instead of panicking.
+			/// # Errors
+			/// Any of the checked fields is not in initial state.
+			#coverage_off
+			#visibility fn try_check_initial_state(instance: &#remote)
+				-> ::std::result::Result<(), ::check_initial_state::InitialStateError> {
+				#usage_mark
+				#check_count
+				let mut violations = ::std::vec::Vec::new();
+				#violation_pushes
+				if violations.is_empty() {
+					::std::result::Result::Ok(())
+				} else {
+					::std::result::Result::Err(
+						::check_initial_state::InitialStateError::new(#remote_name, violations),
+					)
+				}
+			}
+		}
+
+		#usage_static
+	};
+}
+
+/// Parses options of all fields of `input` struct, including ignored ones, in
+/// the order of declaration. Errors of all fields are reported at once.
+pub fn parse_fields<'a>(input: &'a DeriveInput, options: &ContainerOptions)
+	-> Result<Vec<ParsedField<'a>>, TokenStream> {
+	let data = extract_struct(input)?;
+	let fields = fetch_fields(data, input)?;
+	let mut parsed_fields = Vec::with_capacity(fields.len());
+	let mut errors = TokenStream::new();
+	for field in fields.iter() {
+		match parse_field_options(field, options) {
+			Ok(options) => {
+				parsed_fields.push(ParsedField { ident: field_ident(field), ty: &field.ty, options });
+			},
+			Err(err) => errors.extend(err),
+		}
+	}
+	if !errors.is_empty() {
+		return Err(errors);
+	}
+	return Ok(parsed_fields);
+}
+
+/// Parses options and fields of `input` struct for a derive, which shares the
+/// attribute model of `CheckInitialState` derive, e.g. `CheckAnySet`. Compile
+/// error is returned in case of malformed attributes, as well as `remote`
+/// option or `delegate_check` attribute, which such derives don't support.
+/// # Parameters
+/// * `derive_name` &ndash; Name of the derive, which errors refer to.
+fn parse_sibling_derive<'a>(input: &'a DeriveInput, derive_name: &str)
+	-> Result<(ContainerOptions, Vec<ParsedField<'a>>), TokenStream> {
+	check_misplaced_attributes(input)?;
+	let options = parse_container_options(input)?;
+	if options.remote.is_some() {
+		let message = format!("`{}` derive cannot be combined with `remote` option", derive_name);
+		return Err(to_compile_error(&input.ident, &message));
+	}
+	let fields = parse_fields(input, &options)?;
+	if let Some(field) = fields.iter().find(|field| field.options.delegated) {
+		let message =
+			format!("`delegate_check` attribute is not supported by `{}` derive", derive_name);
+		return Err(to_compile_error(field.ident, &message));
+	}
+	return Ok((options, fields));
+}
+
+/// Generates a warning for each `Option` field, which is annotated with
+/// `ignore_field` without a `reason`. Fields are considered `Option` by the
+/// name of their type.
+fn generate_ignored_option_warnings(
+	struct_name: &Ident,
+	fields: &[ParsedField],
+	msrv: Option<RustVersion>,
+) -> TokenStream {
+	let warnings = fields.iter()
+		.filter(|field| field.options.ignored && field.options.reason.is_none())
+		.filter(|field| is_option_type(field.ty))
+		.map(|field| {
+			let message = format!(
+				"`Option` field `{}` is annotated with `ignore_field` without a reason. Specify \
+					one with `#[ignore_field(reason = \"...\")]`",
+				field.ident,
+			);
+			let item_name =
+				format_ident!("__check_initial_state_warning_{}_{}", struct_name, field.ident);
+			return generate_warning(field.ident.span(), &message, &item_name, msrv);
+		});
+	return quote! { #(#warnings)* };
+}
+
+/// Generates an item, which makes the compiler emit a warning with `message`
+/// pointing to `span`. Stable Rust provides no diagnostics API for proc macros,
+/// so usage of a deprecated item is generated.
+/// # Parameters
+/// * `item_name` &ndash; Name of the function which hosts the deprecated item
+/// usage if `msrv` doesn't support `const _` items. Must be unique within the
+/// module.
+fn generate_warning(span: Span, message: &str, item_name: &Ident, msrv: Option<RustVersion>)
+	-> TokenStream {
+	let warning = Ident::new("__check_initial_state_warning", span);
+	let usage = quote_spanned! {span=>
+		#[deprecated(note = #message)]
+		#[allow(non_camel_case_types)]
+		struct #warning;
+		let _ = #warning;
+	};
+	return if RustVersion::supports(msrv, RustVersion::UNDERSCORE_CONST) {
+		quote! { const _: () = { #usage }; }
+	} else {
+		quote! {
+			#[allow(dead_code, non_snake_case)]
+			fn #item_name() { #usage }
+		}
+	};
+}
+
+/// Generates items which refer to `check_initial_state.toml`, if the options
+/// are read from it, and to `CHECK_INITIAL_STATE_DISABLED` environment
+/// variable. So the compiler re&#x2011;expands the derive whenever either of
+/// them changes. Stable Rust provides no API for proc macros to declare such
+/// dependencies.
+fn generate_dependency_tracking(options: &ContainerOptions) -> TokenStream {
+	if !RustVersion::supports(options.msrv, RustVersion::UNDERSCORE_CONST) {
+		return TokenStream::new();
+	}
+	let config_tracking = match &options.config_file {
+		Some(path) => {
+			let path = path.display().to_string();
+			quote! { const _: &[u8] = ::std::include_bytes!(#path); }
+		},
+		None => TokenStream::new(),
+	};
+	let variable = disabled_switch::DISABLED_VARIABLE;
+	return quote! {
+		#config_tracking
+		const _: ::std::option::Option<&str> = ::std::option_env!(#variable);
+	};
+}
+
+/// Generates `FIELD_NAMES` and `FIELD_TYPES` associated constants, which
+/// list names and types of `checked_fields` respectively.
+fn generate_introspection_consts(checked_fields: &[&ParsedField]) -> TokenStream {
+	let count = checked_fields.len();
+	let names = checked_fields.iter().map(|field| field.ident.to_string());
+	let types = checked_fields.iter().map(|field| type_to_string(field.ty));
+	return quote! {
+		/// Names of the fields which are checked, i.e. are not annotated with
+		/// `ignore_field`, in the order of declaration.
+		const FIELD_NAMES: [&'static str; #count] = [#(#names),*];
+
+		/// Types of the fields which are checked, as they are written in the
+		/// struct. Correspond to `FIELD_NAMES` by index.
+		const FIELD_TYPES: [&'static str; #count] = [#(#types),*];
+	};
+}
+
+/// Generates `fields()` associated `const fn`, which returns the metadata of
+/// all `fields`, including ignored ones, in the order of declaration.
+fn generate_field_metadata(fields: &[ParsedField]) -> TokenStream {
+	let metadata = fields.iter().map(|field| {
+		let name = field.ident.to_string();
+		let ignored = field.options.ignored;
+		let reason = match (&field.options.reason, ignored) {
+			(Some(reason), true) => quote! { ::std::option::Option::Some(#reason) },
+			_ => quote! { ::std::option::Option::None },
+		};
+		let group = match &field.options.group {
+			Some(group) => quote! { ::std::option::Option::Some(#group) },
+			None => quote! { ::std::option::Option::None },
+		};
+		return quote! { ::check_initial_state::FieldMeta::new(#name, #ignored, #reason, #group) };
+	});
+	return quote! {
+		/// Metadata of all fields, including ignored ones, in the order of
+		/// declaration. Is `const`, so coverage may be audited at build time.
+		const fn fields() -> &'static [::check_initial_state::FieldMeta] {
+			const FIELDS: &[::check_initial_state::FieldMeta] = &[#(#metadata),*];
+			FIELDS
+		}
+	};
+}
+
+/// Generates a hidden constant next to the struct, which lists the names of
+/// `verified_fields` expected to be `None`. `verify_initial_literals`
+/// attribute checks struct literals of constructors against it. The constant
+/// is not associated with the struct, so it is accessible from `const` items
+/// regardless of generic parameters.
+fn generate_none_fields_const(
+	input: &DeriveInput,
+	verified_fields: &[&ParsedField],
+	options: &ContainerOptions,
+)
+	-> TokenStream {
+	let visibility = generated_visibility(input, options);
+	let const_name = format_ident!("{}{}", NONE_FIELDS_CONST_PREFIX, input.ident);
+	let names = verified_fields.iter()
+		.filter(|field| {
+			return !field.options.delegated
+				&& matches!(field.options.check, FieldCheck::IsNone | FieldCheck::Lenient);
+		})
+		.map(|field| field.ident.to_string());
+	return quote! {
+		#[doc(hidden)]
+		#[allow(non_upper_case_globals, dead_code)]
+		#visibility const #const_name: &[&str] = &[#(#names),*];
+	};
+}
+
+/// Generates a hidden constant next to the struct, which lists the names of
+/// `checked_fields`. Structs annotated with `inherit_checks` attribute verify
+/// their own checked fields against it. Like the constant listing fields
+/// expected to be `None`, it is accessible regardless of generic parameters.
+fn generate_checked_fields_const(
+	input: &DeriveInput,
+	checked_fields: &[&ParsedField],
+	options: &ContainerOptions,
+)
+	-> TokenStream {
+	let visibility = generated_visibility(input, options);
+	let const_name = format_ident!("{}{}", CHECKED_FIELDS_CONST_PREFIX, input.ident);
+	let names = checked_fields.iter().map(|field| field.ident.to_string());
+	return quote! {
+		#[doc(hidden)]
+		#[allow(non_upper_case_globals, dead_code)]
+		#visibility const #const_name: &[&str] = &[#(#names),*];
+	};
+}
+
+/// Generates a `const` item for each struct listed in `inherit_checks`
+/// attribute, which evaluation fails compilation if a field checked by that
+/// struct is ignored by `fields` or is absent. So a builder composed of fields
+/// of other builders, e.g. by a macro, cannot silently stop checking the
+/// embedded ones. Panics in constants cannot format names, which are not
+/// known to the macro, so an absent field is reported without its name.
+/// # Errors
+/// Minimal Rust version doesn't support panics in constants.
+fn generate_inherited_checks(
+	input: &DeriveInput,
+	fields: &[ParsedField],
+	options: &ContainerOptions,
+) -> Result<TokenStream, TokenStream> {
+	let base = match options.inherit_checks.first() {
+		Some(base) => base,
+		None => return Ok(TokenStream::new()),
+	};
+	if !RustVersion::supports(options.msrv, RustVersion::CONST_PANIC) {
+		return Err(to_compile_error(base, "`inherit_checks` attribute requires Rust 1.57 or newer"));
+	}
+	let struct_name = &input.ident;
+	let checked_names = fields.iter()
+		.filter(|field| !field.options.ignored)
+		.map(|field| field.ident.to_string())
+		.collect::<Vec<_>>();
+	let assertions = options.inherit_checks.iter().map(|base| {
+		// Generic arguments of the struct are dropped, since the constant has none
+		let mut base_fields = base.clone();
+		let last_segment = base_fields.segments.last_mut().unwrap();
+		let base_name = last_segment.ident.clone();
+		*last_segment =
+			PathSegment::from(format_ident!("{}{}", CHECKED_FIELDS_CONST_PREFIX, base_name));
+		let ignored_assertions = fields.iter().filter(|field| field.options.ignored).map(|field| {
+			let field_name = field.ident.to_string();
+			let message = format!(
+				"Field `{}` is checked by `{}`, but is ignored in `{}`",
+				field_name,
+				base_name,
+				struct_name,
+			);
+			return quote_spanned! {field.ident.span()=>
+				if ::check_initial_state::__private::contains_field(#base_fields, #field_name) {
+					::core::panic!(#message);
+				}
+			};
+		});
+		let absent_message =
+			format!("`{}` checks a field, which is absent in `{}`", base_name, struct_name);
+		return quote_spanned! {base.span()=>
+			const _: () = {
+				#(#ignored_assertions)*
+				let checked_fields: &[&str] = &[#(#checked_names),*];
+				let mut index = 0;
+				while index < #base_fields.len() {
+					if !::check_initial_state::__private::contains_field(
+						checked_fields,
+						#base_fields[index],
+					) {
+						::core::panic!(#absent_message);
+					}
+					index += 1;
+				}
+			};
+		};
+	});
+	return Ok(quote! { #(#assertions)* });
+}
+
+/// Renders `ty` close to the way it is usually written in source code, e.g.
+/// `Option<Vec<u8>>` rather than `Option < Vec < u8 > >` produced by
+/// `to_string()` of a token stream.
+fn type_to_string(ty: &Type) -> String {
+	let mut string = String::new();
+	write_tokens(quote!(#ty), &mut string);
+	return string;
+}
+
+/// Appends `tokens` to `string`, putting spaces only where they are
+/// conventionally written.
+fn write_tokens(tokens: TokenStream, string: &mut String) {
+	// Whether the last written token is a word or a closing angle bracket, e.g.
+	// of `<Vec<T> as IntoIterator>` or `for<'a>`, so the next word must be
+	// separated
+	let mut after_word = false;
+	for token in tokens {
+		match token {
+			TokenTree::Group(group) => {
+				let (open, close) = match group.delimiter() {
+					Delimiter::Parenthesis => ("(", ")"),
+					Delimiter::Bracket => ("[", "]"),
+					Delimiter::Brace => ("{", "}"),
+					Delimiter::None => ("", ""),
+				};
+				string.push_str(open);
+				write_tokens(group.stream(), string);
+				string.push_str(close);
+				after_word = false;
+			},
+			TokenTree::Punct(punct) => {
+				match punct.as_char() {
+					',' | ';' => {
+						string.push(punct.as_char());
+						string.push(' ');
+					},
+					'+' | '=' | '|' => {
+						string.push(' ');
+						string.push(punct.as_char());
+						string.push(' ');
+					},
+					'-' if punct.spacing() == Spacing::Joint => string.push_str(" -"),
+					'>' if string.ends_with(" -") => string.push_str("> "),
+					character => string.push(character),
+				}
+				after_word = string.ends_with('>');
+			},
+			TokenTree::Ident(_) | TokenTree::Literal(_) => {
+				if after_word {
+					string.push(' ');
+				}
+				string.push_str(&token.to_string());
+				after_word = true;
+			},
+		}
+	}
+}
+
+/// Retrieves the name of `field`.
+/// # Panics
+/// `field` has no name. Must not happen, since tuple structs are rejected by
+/// [`fetch_fields()`].
+fn field_ident(field: &Field) -> &Ident {
+	return match field.ident.as_ref() {
+		Some(ident) => ident,
+		None => {
+			panic!(
+				"Unexpected implementation error occurred. Reason: Field `{:?}` is expected to \
+					have name while it does not",
+				field,
+			);
+		},
+	};
+}
+
+/// Generates `INITIAL` associated constant, which has all checked fields set
+/// to `None` and ignored fields set to their `default` values. Compile error
+/// is returned if any of ignored fields lacks `default` value.
+fn generate_initial_const(fields: &[ParsedField]) -> Result<TokenStream, TokenStream> {
+	let initializers = generate_initializers(fields, "initial_const", true)?;
+	return Ok(quote! {
+		/// Instance in initial state. All checked fields are `None`, while
+		/// fields annotated with `ignore_field` have their `default` values.
+		const INITIAL: Self = Self { #(#initializers),* };
+	});
+}
+
+/// Generates an associated function named `name`, which creates an instance
+/// in initial state. Unlike `INITIAL` constant, the function may evaluate
+/// non&#x2011;constant expressions, so fields with `smart-default` defaults
+/// are supported. The function has the visibility of generated items.
+fn generate_builder_fn(
+	input: &DeriveInput,
+	fields: &[ParsedField],
+	name: &Ident,
+	options: &ContainerOptions,
+)
+	-> Result<TokenStream, TokenStream> {
+	let initializers = generate_initializers(fields, "builder_fn", false)?;
+	let visibility = generated_visibility(input, options);
+	return Ok(quote! {
+		/// Creates an instance in initial state. All checked fields are
+		/// `None`, while fields annotated with `ignore_field` have their
+		/// `default` values.
+		// Structs are commonly named `Builder`, while the function `builder()`
+		#[allow(clippy::self_named_constructors)]
+		#visibility fn #name() -> Self {
+			Self { #(#initializers),* }
+		}
+	});
+}
+
+/// Generates a fallible associated function for each constructor, which
+/// is a path to a function, named after its last segment with `try_` prefix,
+/// e.g. `try_new()` for `Self::new`. The function calls the constructor and
+/// returns the instance if it passes `try_check_initial_state()`. Constructors
+/// written as call expressions are skipped, since their arguments are fixed.
+/// Functions have the visibility of generated items.
+fn generate_try_constructors(input: &DeriveInput, options: &ContainerOptions) -> TokenStream {
+	let visibility = generated_visibility(input, options);
+	let functions = options.constructors.iter().filter_map(|constructor| {
+		let name = match constructor {
+			Expr::Path(path) => &path.path.segments.last()?.ident,
+			_ => return None,
+		};
+		let try_name = format_ident!("try_{}", name.unraw());
+		let doc = format!(
+			"Constructs an instance with `{}` and verifies it to be in initial state.",
+			quote!(#constructor),
+		);
+		return Some(quote! {
+			#[doc = #doc]
+			/// # Errors
+			/// Any of the checked fields of the constructed instance is not in
+			/// initial state.
+			#visibility fn #try_name()
+				-> ::std::result::Result<Self, ::check_initial_state::InitialStateError> {
+				let instance: Self = #constructor();
+				::check_initial_state::CheckInitialState::try_check_initial_state(&instance)?;
+				::std::result::Result::Ok(instance)
+			}
+		});
+	});
+	return quote! { #(#functions)* };
+}
+
+/// Generates `Default` implementation, which creates an instance in initial
+/// state like the function of `builder_fn` option does. So ignored fields need
+/// no `Default` implementation, as long as they specify their `default`
+/// values.
+fn generate_default_impl(input: &DeriveInput, generics: &Generics, fields: &[ParsedField])
+	-> Result<TokenStream, TokenStream> {
+	let initializers = generate_initializers(fields, "derive_default", false)?;
+	let struct_name = &input.ident;
+	let (leading_generics, trailing_generics, where_clause) = generics.split_for_impl();
+	let coverage_off = generate_coverage_off();
+	return Ok(quote! {
+		#[automatically_derived]
+		impl #leading_generics ::std::default::Default
+			for #struct_name #trailing_generics #where_clause {
+			#coverage_off
+			fn default() -> Self {
+				Self { #(#initializers),* }
+			}
+		}
+	});
+}
+
+/// Generates initializers of all `fields` for a struct literal in initial
+/// state. Checked fields get their initial values, while ignored ones get
+/// their `default` values.
+/// # Parameters
+/// * `option` &ndash; Name of the option requesting the literal, which is
+/// reported in errors.
+/// * `constant` &ndash; Whether the literal must be a constant expression.
+/// Otherwise fields with `smart-default` defaults get their default values.
+fn generate_initializers(fields: &[ParsedField], option: &str, constant: bool)
+	-> Result<Vec<TokenStream>, TokenStream> {
+	let mut initializers = Vec::with_capacity(fields.len());
+	for ParsedField { ident, options, .. } in fields {
+		let value = match (options.ignored, &options.default, &options.check) {
+			(false, _, FieldCheck::ExpectValue { value, .. }) if !constant => value.clone(),
+			(false, _, check) => {
+				match check.initial_value() {
+					Some(value) => value,
+					None => {
+						let message = format!(
+							"`{}` option is not supported for fields checked with `expect_matches`, \
+								`option_alias` or `empty_via`",
+							option,
+						);
+						return Err(to_compile_error(ident, &message));
+					},
+				}
+			},
+			(true, Some(default), _) => quote! { #default },
+			(true, None, _) => {
+				let message = format!(
+					"`{}` option requires all fields annotated with `ignore_field` to specify \
+						initial value, e.g. `#[ignore_field(default = \"0\")]`",
+					option,
+				);
+				return Err(to_compile_error(ident, &message));
+			},
+		};
+		initializers.push(quote! { #ident: #value });
+	}
+	return Ok(initializers);
+}
+
+/// Generates `initial_state_report_json()` method, which reports all checked
+/// fields which are not in initial state in JSON format.
+/// # Parameters
+/// * `check_prelude` &ndash; Statements preceding `violations` evaluation.
+/// * `violations` &ndash; Pairs of a checked field name and an expression,
+/// which evaluates to `Some` reason if the field is not in initial state.
+#[cfg(feature = "json")]
+fn generate_json_report(
+	struct_name: &Ident,
+	check_prelude: &TokenStream,
+	violations: &[(String, TokenStream)],
+) -> TokenStream {
+	// Struct name is an identifier, while report names are rejected if they
+	// contain quotes or backslashes. So only reasons need escaping
+	let report_format = format!(
+		"{{{{\"struct\":\"{}\",\"violations\":[{{}}]}}}}",
+		struct_name,
+	);
+	let violation_pushes = violations.iter().map(|(field_name, violation)| {
+		return quote! {
+			if let ::std::option::Option::Some(reason) = #violation {
+				violations.push(
+					format!(
+						"{{\"field\":\"{}\",\"reason\":\"{}\"}}",
+						#field_name,
+						::check_initial_state::__private::JsonString(reason),
+					),
+				);
+			}
+		};
+	});
+	let coverage_off = generate_coverage_off();
+	return quote! {
+		/// Reports checked fields which are not in initial state in JSON
+		/// format, e.g.
+		/// `{"struct":"Builder","violations":[{"field":"option","reason":"..."}]}`.
+		/// Unlike `check_initial_state()`, all violations are reported and
+		/// nothing panics. So the output may be aggregated by CI tooling.
+		#coverage_off
+		fn initial_state_report_json(&self) -> ::std::string::String {
+			#check_prelude
+			let mut violations: ::std::vec::Vec<::std::string::String> = ::std::vec::Vec::new();
+			#(#violation_pushes)*
+			::std::format!(#report_format, violations.join(","))
+		}
+	};
+}
+
+/// Generates `initial_state_report_defmt()` method, which logs checked fields
+/// that are not in initial state with `defmt::error!`. Field names are a part
+/// of format strings, so they are interned rather than transmitted.
+/// # Parameters
+/// * `check_prelude` &ndash; Statements preceding `violations` evaluation.
+/// * `violations` &ndash; Pairs of a checked field name and an expression,
+/// which evaluates to `Some` reason if the field is not in initial state.
+/// * `options` &ndash; Options of the struct, which tell how to tag the
+/// messages.
+#[cfg(feature = "defmt")]
+fn generate_defmt_report(
+	check_prelude: &TokenStream,
+	violations: &[(String, TokenStream)],
+	options: &ContainerOptions,
+) -> TokenStream {
+	let violation_logs = violations.iter().map(|(field_name, violation)| {
+		let format = tag_message(options, &format!("Field `{}` {{=str}}", field_name));
+		return quote! {
+			if let ::std::option::Option::Some(reason) = #violation {
+				::defmt::error!(#format, reason);
+				in_initial_state = false;
+			}
+		};
+	});
+	let coverage_off = generate_coverage_off();
+	return quote! {
+		/// Logs each checked field which is not in initial state with
+		/// `defmt::error!`, e.g. "Field `option` has Some value instead of
+		/// None". Unlike `check_initial_state()`, nothing panics. So embedded
+		/// test rigs capture the problems, e.g. over RTT. Returns whether all
+		/// checked fields are in initial state.
+		#coverage_off
+		fn initial_state_report_defmt(&self) -> bool {
+			#check_prelude
+			let mut in_initial_state = true;
+			#(#violation_logs)*
+			in_initial_state
+		}
+	};
+}
+
+/// Generates `initial_state_errors()` method, which reports checked fields
+/// that are not in initial state as bits of `u32`, and `INITIAL_STATE_ERROR_BITS`
+/// constant mapping each bit to the name of its field. Neither panics nor
+/// allocates, so is suitable for firmware. Compile error is returned if there
+/// are more checked fields than bits.
+/// # Parameters
+/// * `check_prelude` &ndash; Statements preceding `violations` evaluation.
+/// * `violations` &ndash; Pairs of a checked field name and an expression,
+/// which evaluates to `Some` reason if the field is not in initial state.
+fn generate_error_bits(
+	struct_name: &Ident,
+	check_prelude: &TokenStream,
+	violations: &[(String, TokenStream)],
+) -> Result<TokenStream, TokenStream> {
+	if violations.len() > u32::BITS as usize {
+		return Err(to_compile_error(
+			struct_name,
+			&format!(
+				"`error_bits` option supports up to {} checked fields, while `{}` has {}",
+				u32::BITS,
+				struct_name,
+				violations.len(),
+			),
+		));
+	}
+	let count = violations.len();
+	let bits = (0..count).map(|index| 1u32 << index).collect::<Vec<_>>();
+	let names = violations.iter().map(|(field_name, _)| field_name);
+	let bit_sets = violations.iter().zip(&bits).map(|((_, violation), bit)| {
+		return quote! {
+			if ::std::option::Option::is_some(&#violation) {
+				errors |= #bit;
+			}
+		};
+	});
+	let coverage_off = generate_coverage_off();
+	return Ok(quote! {
+		/// Bits of `initial_state_errors()` result, each paired with the name
+		/// of the checked field it stands for, in the order of declaration.
+		const INITIAL_STATE_ERROR_BITS: [(u32, &'static str); #count] = [#((#bits, #names)),*];
+
+		/// Reports checked fields which are not in initial state as bits of the
+		/// result, listed in `INITIAL_STATE_ERROR_BITS`. `0` means all fields are
+		/// in initial state. Unlike `check_initial_state()`, neither panics nor
+		/// allocates.
+		#coverage_off
+		fn initial_state_errors(&self) -> u32 {
+			#check_prelude
+			let mut errors: u32 = 0;
+			#(#bit_sets)*
+			errors
+		}
+	});
+}
+
+/// Generates `violations()` method, which lazily yields names of checked fields
+/// which are not in initial state. Each field is checked only when the
+/// iterator reaches it, and nothing is allocated, so the method is suitable for
+/// hot loops, e.g. in fuzzers.
+/// # Parameters
+/// * `check_prelude` &ndash; Statements preceding `violations` evaluation.
+/// * `violations` &ndash; Pairs of a checked field name and an expression,
+/// which evaluates to `Some` reason if the field is not in initial state.
+fn generate_violations_iter(check_prelude: &TokenStream, violations: &[(String, TokenStream)])
+	-> TokenStream {
+	let count = violations.len();
+	let arms = violations.iter().enumerate().map(|(index, (field_name, violation))| {
+		return quote! {
+			#index => if ::std::option::Option::is_some(&#violation) {
+				::std::option::Option::Some(#field_name)
+			} else {
+				::std::option::Option::None
+			},
+		};
+	});
+	let coverage_off = generate_coverage_off();
+	return quote! {
+		/// Lazily yields names of checked fields which are not in initial
+		/// state, in the order of declaration. Unlike
+		/// `try_check_initial_state()`, neither panics nor allocates.
+		#coverage_off
+		fn violations(&self) -> impl ::std::iter::Iterator<Item = &'static str> + '_ {
+			#check_prelude
+			(0..#count).filter_map(move |index| match index {
+				#(#arms)*
+				_ => ::std::option::Option::None,
+			})
+		}
+	};
+}
+
+/// Generates `check_initial_state_no_panic()` method, which returns the first
+/// violation instead of panicking, for code bases which forbid panics in all
+/// linked code. The method is annotated with `no_panic` attribute, which fails
+/// linking if the method may panic. Since the attribute relies on
+/// optimizations, it is applied without debug assertions only. Metrics are not
+/// recorded, since recording may allocate.
+/// # Parameters
+/// * `fields` &ndash; Checked fields in the order of `violations`.
+/// * `check_prelude` &ndash; Statements preceding `violations` evaluation.
+/// * `violations` &ndash; Pairs of a checked field name and an expression,
+/// which evaluates to `Some` reason if the field is not in initial state.
+fn generate_no_panic_check(
+	fields: &[&ParsedField],
+	check_prelude: &TokenStream,
+	violations: &[(String, TokenStream)],
+) -> TokenStream {
+	let returns = fields.iter().zip(violations).map(|(field, (_, violation))| {
+		let new_violation = generate_new_violation(field);
+		return quote! {
+			if let ::std::option::Option::Some(reason) = #violation {
+				return ::std::result::Result::Err(#new_violation);
+			}
+		};
+	});
+	let coverage_off = generate_coverage_off();
+	return quote! {
+		/// Checks all fields, which are not annotated with `ignore_field`, to
+		/// be in initial state like `check_initial_state()`, but returns the
+		/// first violation instead of panicking. Is verified by `no_panic`
+		/// attribute in optimized builds without debug assertions.
+		/// # Errors
+		/// Any of checked fields is not in initial state.
+		#[cfg_attr(not(debug_assertions), ::no_panic::no_panic)]
+		#coverage_off
+		fn check_initial_state_no_panic(&self)
+			-> ::std::result::Result<(), ::check_initial_state::Violation> {
+			#check_prelude
+			#(#returns)*
+			::std::result::Result::Ok(())
+		}
+	};
+}
+
+/// Generates an enum named after the struct with `Field` suffix, e.g.
+/// `BuilderField`, which has a variant for each of `checked_fields` named in
+/// upper camel case. Also generates `try_check_initial_state_fields()` method,
+/// which reports fields that are not in initial state as the variants. So
+/// tests may match violations exhaustively, and renamed fields break them at
+/// compile time. The enum is converted from and to field names as they are
+/// written in the struct, like `FIELD_NAMES` lists them. The enum has the
+/// visibility of generated items. Returns the enum
+/// and the method, which belongs to the inherent implementation.
+/// # Parameters
+/// * `verified_fields` &ndash; Fields which checks are generated for, which
+/// correspond to `violations` by index.
+/// * `check_prelude` &ndash; Statements preceding `violations` evaluation.
+/// * `violations` &ndash; Pairs of a checked field name and an expression,
+/// which evaluates to `Some` reason if the field is not in initial state.
+fn generate_field_enum(
+	input: &DeriveInput,
+	checked_fields: &[&ParsedField],
+	verified_fields: &[&ParsedField],
+	check_prelude: &TokenStream,
+	violations: &[(String, TokenStream)],
+	options: &ContainerOptions,
+) -> Result<(TokenStream, TokenStream), TokenStream> {
+	let struct_name = &input.ident;
+	let enum_name = format_ident!("{}Field", struct_name);
+	let mut variants: Vec<Ident> = Vec::with_capacity(checked_fields.len());
+	for field in checked_fields {
+		let variant_name = to_upper_camel_case(&field.ident.unraw().to_string());
+		if variant_name == "Self" {
+			let message = "`field_enum` option cannot name a variant `Self`, so the field must be \
+				renamed";
+			return Err(to_compile_error(field.ident, message));
+		}
+		let variant = Ident::new(&variant_name, field.ident.span());
+		if variants.contains(&variant) {
+			let message = format!(
+				"`field_enum` option generates `{}::{}` variant for several fields",
+				enum_name,
+				variant,
+			);
+			return Err(to_compile_error(field.ident, &message));
+		}
+		variants.push(variant);
+	}
+	let report_names = checked_fields.iter().map(|field| field.report_name());
+	let field_names = checked_fields.iter().map(|field| field.ident.to_string()).collect::<Vec<_>>();
+	let struct_name_string = struct_name.to_string();
+	let pushes = verified_fields.iter().zip(violations).map(|(field, (_, violation))| {
+		let index = checked_fields.iter().position(|checked| checked.ident == field.ident).unwrap();
+		let variant = &variants[index];
+		return quote! {
+			if ::std::option::Option::is_some(&#violation) {
+				fields.push(#enum_name::#variant);
+			}
+		};
+	});
+	let visibility = generated_visibility(input, options);
+	let doc = format!(
+		"Checked fields of `{}`, which `try_check_initial_state_fields()` reports.",
+		struct_name,
+	);
+	let coverage_off = generate_coverage_off();
+	let field_enum = quote! {
+		#[doc = #doc]
+		#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+		#[allow(dead_code)]
+		#visibility enum #enum_name {
+			#(#variants),*
+		}
+
+		#[automatically_derived]
+		#[allow(dead_code)]
+		impl #enum_name {
+			/// Name of the field in panic messages and reports, i.e. its
+			/// `report_name` if specified, or the field name otherwise.
+			#coverage_off
+			#visibility fn name(self) -> &'static str {
+				match self {
+					#(Self::#variants => #report_names),*
+				}
+			}
+		}
+
+		#[automatically_derived]
+		impl ::std::fmt::Display for #enum_name {
+			#coverage_off
+			fn fmt(&self, formatter: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+				match *self {
+					#(Self::#variants => formatter.pad(#field_names)),*
+				}
+			}
+		}
+
+		#[automatically_derived]
+		impl ::std::str::FromStr for #enum_name {
+			type Err = ::check_initial_state::UnknownFieldError;
+
+			#coverage_off
+			fn from_str(name: &str) -> ::std::result::Result<Self, Self::Err> {
+				match name {
+					#(#field_names => ::std::result::Result::Ok(Self::#variants),)*
+					_ => ::std::result::Result::Err(
+						::check_initial_state::UnknownFieldError::new(#struct_name_string, name),
+					),
+				}
+			}
+		}
+	};
+	let check = quote! {
+		/// Checks fields like `try_check_initial_state()`, but reports fields
+		/// which are not in initial state as variants of the field enum, in
+		/// the order of declaration.
+		/// # Errors
+		/// Any of checked fields is not in initial state.
+		#coverage_off
+		fn try_check_initial_state_fields(
+			&self,
+		) -> ::std::result::Result<(), ::std::vec::Vec<#enum_name>> {
+			#check_prelude
+			let mut fields = ::std::vec::Vec::new();
+			#(#pushes)*
+			if fields.is_empty() {
+				::std::result::Result::Ok(())
+			} else {
+				::std::result::Result::Err(fields)
+			}
+		}
+	};
+	return Ok((field_enum, check));
+}
+
+/// Converts a field name to upper camel case, e.g. `max_retries` to
+/// `MaxRetries`. Digits are kept, e.g. `option_2` becomes `Option2`, and
+/// leading underscores are dropped.
+fn to_upper_camel_case(name: &str) -> String {
+	let mut camel_case = String::with_capacity(name.len());
+	let mut upper = true;
+	for character in name.chars() {
+		if character == '_' {
+			upper = true;
+		} else if upper {
+			camel_case.extend(character.to_uppercase());
+			upper = false;
+		} else {
+			camel_case.push(character);
+		}
+	}
+	return camel_case;
+}
+
+/// Generates `same_state_as()` method, which compares only whether fields of two
+/// instances are set, ignoring their values, and panics on the first field
+/// which differs.
+/// # Parameters
+/// * `fields` &ndash; Fields to compare. `other` is unused if empty.
+/// * `options` &ndash; Options of the struct, which tell how to tag the message
+/// and whether fields are packed.
+fn generate_same_state(
+	fields: &[&ParsedField],
+	options: &ContainerOptions,
+	track_caller: &TokenStream,
+) -> TokenStream {
+	let format = tag_message(options, "Field `{}` is {} in this instance, but {} in the other one");
+	let comparisons = fields.iter().map(|field| {
+		let field_name = field.report_name();
+		let check = &field.options.check;
+		let span = field.ty.span();
+		let is_set = check.is_set(&field_value(&quote!(self), field.ident, options.packed), span);
+		let other_is_set =
+			check.is_set(&field_value(&quote!(other), field.ident, options.packed), span);
+		let (set_label, unset_label) = check.state_labels();
+		return quote! {
+			match (#is_set, #other_is_set) {
+				(true, false) => ::std::panic!(#format, #field_name, #set_label, #unset_label),
+				(false, true) => ::std::panic!(#format, #field_name, #unset_label, #set_label),
+				_ => (),
+			}
+		};
+	});
+	let unused_other = if fields.is_empty() {
+		quote! { let _ = other; }
+	} else {
+		TokenStream::new()
+	};
+	let coverage_off = generate_coverage_off();
+	return quote! {
+		/// Checks each field, which is not annotated with `ignore_field`, to be
+		/// set in `self` if and only if it is set in `other`, e.g. an `Option`
+		/// field to be `Some` in both instances or `None` in both. Values
+		/// themselves are not compared. Is expected to be used for testing
+		/// that a round&#x2011;trip or a reset leaves an equivalent instance.
+		/// # Panics
+		/// A field is set in one instance only. Panic message will contain the
+		/// name of the first such field.
+		#track_caller
+		#coverage_off
+		fn same_state_as(&self, other: &Self) {
+			#unused_other
+			#(#comparisons)*
+		}
+	};
+}
+
+/// Generates `for_each_field_state()` method, which passes the name of each of
+/// `fields` and whether it is set to a closure, in the order of declaration.
+/// So arbitrary reports may be built on top without allocations in the
+/// generated code.
+/// # Parameters
+/// * `fields` &ndash; Fields to visit. The closure is unused if empty.
+/// * `options` &ndash; Options of the struct, which tell whether fields are
+/// packed.
+fn generate_field_state_visitor(fields: &[&ParsedField], options: &ContainerOptions)
+	-> TokenStream {
+	let visits = fields.iter().map(|field| {
+		let field_name = field.report_name();
+		let value = field_value(&quote!(self), field.ident, options.packed);
+		let is_set = field.options.check.is_set(&value, field.ty.span());
+		return quote! { visitor(#field_name, #is_set); };
+	});
+	let unused_visitor = if fields.is_empty() {
+		quote! { let _ = &mut visitor; }
+	} else {
+		TokenStream::new()
+	};
+	let coverage_off = generate_coverage_off();
+	return quote! {
+		/// Calls `visitor` with the name of each field, which is not annotated
+		/// with `ignore_field`, and whether the field is set, e.g. an `Option`
+		/// field is `Some`, in the order of declaration. Unlike
+		/// `try_check_initial_state()`, neither panics nor allocates.
+		#coverage_off
+		fn for_each_field_state(&self, mut visitor: impl ::std::ops::FnMut(&'static str, bool)) {
+			#unused_visitor
+			#(#visits)*
+		}
+	};
+}
+
+/// Generates `check_with_observer()` method, which reports the state of each of
+/// `fields` and its violation, if any, to `InitialStateObserver`, in the order
+/// of declaration. Neither panics nor allocates, so the observer decides how
+/// to report violations and whether they fail.
+/// # Parameters
+/// * `struct_name` &ndash; Name of the checked struct passed to
+/// `on_complete()`.
+/// * `check_prelude` &ndash; Statements preceding `violations` evaluation.
+/// * `violations` &ndash; Pairs of a checked field name and an expression,
+/// which evaluates to `Some` reason if the field is not in initial state.
+/// Correspond to `fields` by index.
+/// * `options` &ndash; Options of the struct, which tell whether fields are
+/// packed.
+fn generate_observer_check(
+	struct_name: &str,
+	fields: &[&ParsedField],
+	check_prelude: &TokenStream,
+	violations: &[(String, TokenStream)],
+	options: &ContainerOptions,
+) -> TokenStream {
+	let observations = fields.iter().zip(violations).map(|(field, (field_name, violation))| {
+		let value = field_value(&quote!(self), field.ident, options.packed);
+		let is_set = field.options.check.is_set(&value, field.ty.span());
+		let new_violation = generate_new_violation(field);
+		return quote! {
+			::check_initial_state::InitialStateObserver::on_field(observer, #field_name, #is_set);
+			if let ::std::option::Option::Some(reason) = #violation {
+				let violation = #new_violation;
+				::check_initial_state::InitialStateObserver::on_violation(observer, &violation);
+			}
+		};
+	});
+	let coverage_off = generate_coverage_off();
+	return quote! {
+		/// Reports the state of each field, which is not annotated with
+		/// `ignore_field`, to `observer` in the order of declaration, followed
+		/// by a violation if the field is not in initial state. Unlike
+		/// `check_initial_state()`, nothing panics, so `observer` decides
+		/// whether violations fail.
+		#coverage_off
+		fn check_with_observer(
+			&self,
+			observer: &mut (impl ::check_initial_state::InitialStateObserver + ?::std::marker::Sized),
+		) {
+			#check_prelude
+			#(#observations)*
+			::check_initial_state::InitialStateObserver::on_complete(observer, #struct_name);
+		}
+	};
+}
+
+/// Generates statements which precede field checks in every generated check
+/// method. E.g. `reference` instance is constructed here in `compare_with`
+/// mode.
+fn generate_check_prelude(options: &ContainerOptions) -> TokenStream {
+	return match &options.compare_with {
+		Some(constructor) => {
+			let call = constructor_call(constructor);
+			quote! { let reference: Self = #call; }
+		},
+		None => TokenStream::new(),
+	};
+}
+
+/// Generates an expression evaluating to `field` of `instance`. A field of a
+/// packed struct is copied, since checks may take a reference to it, which
+/// would be unaligned.
+fn field_value(instance: &TokenStream, field: &Ident, packed: bool)
+	-> TokenStream {
+	return if packed {
+		quote! { { #instance.#field } }
+	} else {
+		quote! { #instance.#field }
+	};
+}
+
+/// Generates an expression which evaluates to `Some` reason if `field` is not
+/// in initial state, or to `None` otherwise. In `compare_with` mode, `field` is
+/// compared with the one of `reference` instance. Otherwise `field` is
+/// expected to be in the state defined by its check, e.g. `None`.
+/// # Parameters
+/// * `instance` &ndash; Expression evaluating to the checked instance, e.g.
+/// `self`.
+fn generate_violation(field: &ParsedField, options: &ContainerOptions, instance: &TokenStream)
+	-> TokenStream {
+	let check = &field.options.check;
+	let span = field.ty.span();
+	let is_set = check.is_set(&field_value(instance, field.ident, options.packed), span);
+	let set_reason = check.set_reason();
+	let unset_reason = check.unset_reason();
+	if options.compare_with.is_some() {
+		let reference_value = field_value(&quote!(reference), field.ident, options.packed);
+		let reference_is_set = check.is_set(&reference_value, span);
+		return quote! {
+			match (#is_set, #reference_is_set) {
+				(true, false) => ::std::option::Option::Some(#set_reason),
+				(false, true) => ::std::option::Option::Some(#unset_reason),
+				_ => ::std::option::Option::None,
+			}
+		};
+	}
+	return if check.expected_set() {
+		quote! {
+			if #is_set {
+				::std::option::Option::None
+			} else {
+				::std::option::Option::Some(#unset_reason)
+			}
+		}
+	} else {
+		quote! {
+			if #is_set {
+				::std::option::Option::Some(#set_reason)
+			} else {
+				::std::option::Option::None
+			}
+		}
+	};
+}
+
+/// Generates field checks of `check_initial_state()` method for
+/// `compare_with` mode. Unlike the default mode, all checked fields are
+/// evaluated before panicking. So the panic message contains a
+/// field&#x2011;by&#x2011;field diff with `reference` instance in addition to
+/// the first violation.
+/// # Parameters
+/// * `struct_name` &ndash; Name of the checked struct, which labels metrics.
+/// * `violations` &ndash; Pairs of a checked field name and an expression,
+/// which evaluates to `Some` reason if the field is not in initial state.
+/// Correspond to `checked_fields` by index.
+/// * `options` &ndash; Options of the struct, which tell whether to panic
+/// with `InitialStatePanic` payload and how to tag the message. The payload
+/// describes the first violation only.
+fn generate_diff_checks(
+	struct_name: &str,
+	checked_fields: &[&ParsedField],
+	violations: &[(String, TokenStream)],
+	options: &ContainerOptions,
+) -> TokenStream {
+	let diff_lines = checked_fields.iter().zip(violations).map(|(field, (field_name, violation))| {
+		let check = &field.options.check;
+		let (set_label, unset_label) = check.state_labels();
+		let set_reason = check.set_reason();
+		let set_to_unset = format!("\n  `{}`: {} => {}", field_name, set_label, unset_label);
+		let unset_to_set = format!("\n  `{}`: {} => {}", field_name, unset_label, set_label);
+		let violation_count = generate_violation_count(struct_name, field_name);
+		return quote! {
+			if let ::std::option::Option::Some(reason) = #violation {
+				#violation_count
+				first_violation =
+					first_violation.or(::std::option::Option::Some((#field_name, reason)));
+				diff.push_str(if reason == #set_reason { #unset_to_set } else { #set_to_unset });
+			}
+		};
+	});
+	let format = tag_message(
+		options,
+		"Field `{}` {}\nDifferences from the reference instance (reference => actual):{}",
+	);
+	let panic = generate_panic(struct_name, &quote!(field_name), options.panic_payload, quote! {
+		#format,
+		field_name,
+		reason,
+		diff,
+	});
+	return quote! {
+		let mut first_violation: ::std::option::Option<(&'static str, &'static str)> =
+			::std::option::Option::None;
+		let mut diff = ::std::string::String::new();
+		#(#diff_lines)*
+		if let ::std::option::Option::Some((field_name, reason)) = first_violation {
+			#panic;
+		}
+	};
+}
+
+/// Generates a call of `constructor`. A path to a function is called without
+/// arguments, while any other expression is used as is.
+fn constructor_call(constructor: &Expr) -> TokenStream {
+	return match constructor {
+		Expr::Path(_) => quote! { #constructor() },
+		_ => quote! { #constructor },
+	};
+}
+
+/// Generates `rstest` fixture, named after the struct in snake case, which
+/// returns an instance constructed by `constructor` and verified with
+/// `check_initial_state()`. The fixture is generic over `generics`.
+fn generate_fixture(
+	input: &DeriveInput,
+	generics: &Generics,
+	constructor: &Expr,
+	options: &ContainerOptions,
+) -> TokenStream {
+	let struct_name = &input.ident;
+	let visibility = generated_visibility(input, options);
+	let (leading_generics, trailing_generics, where_clause) = generics.split_for_impl();
+	let self_type = syn::parse_quote!(#struct_name #trailing_generics);
+	let where_clause = where_clause.map(|where_clause| replace_self_type(where_clause, &self_type));
+	let fixture_name = Ident::new(&to_snake_case(&struct_name.to_string()), struct_name.span());
+	let call = replace_self(constructor_call(constructor), struct_name);
+	let doc = format!(
+		"`rstest` fixture returning `{}` constructed by `{}` and verified to be in initial \
+			state.",
+		struct_name,
+		quote!(#constructor),
+	);
+	let coverage_off = generate_coverage_off();
+	return quote! {
+		#[doc = #doc]
+		#[cfg(test)]
+		#coverage_off
+		#[::rstest::fixture]
+		#visibility fn #fixture_name #leading_generics() -> #struct_name #trailing_generics
+			#where_clause {
+			let instance: #struct_name #trailing_generics = #call;
+			instance.check_initial_state();
+			instance
+		}
+	};
+}
+
+/// Visibility of generated functions, constants and enums, which is the one of
+/// the struct unless `visibility` option specifies another one.
+fn generated_visibility<'a>(input: &'a DeriveInput, options: &'a ContainerOptions)
+	-> &'a Visibility {
+	return options.visibility.as_ref().unwrap_or(&input.vis);
+}
+
+/// Generates an attribute which excludes the annotated generated item from code
+/// coverage instrumentation if `coverage` feature is enabled. The attribute is
+/// active only with `coverage_nightly` cfg, which `cargo llvm-cov` sets on a
+/// nightly toolchain, since `#[coverage(off)]` is unstable.
+fn generate_coverage_off() -> TokenStream {
+	if cfg!(feature = "coverage") {
+		return quote! { #[cfg_attr(coverage_nightly, coverage(off))] };
+	}
+	return TokenStream::new();
+}
+
+/// Converts `CamelCase` name into `snake_case`, e.g. `HttpBuilder` becomes
+/// `http_builder`.
+fn to_snake_case(name: &str) -> String {
+	let mut snake_case = String::with_capacity(name.len() + 4);
+	for (index, character) in name.chars().enumerate() {
+		if character.is_uppercase() {
+			if index > 0 {
+				snake_case.push('_');
+			}
+			snake_case.extend(character.to_lowercase());
+		} else {
+			snake_case.push(character);
+		}
+	}
+	return snake_case;
+}
+
+/// Generates a test module containing a `#[test]` for each constructor listed
+/// in `options`. Also the following tests are generated for each constructor
+/// if requested by `options`:
+/// * A `#[test]` checking the clone of the constructed instance.
+/// * A mutation `#[test]` ensuring that setting any of `checked_fields` is
+/// detected by `check_initial_state()`.
+///
+/// Nothing is generated if there are no constructors.
+fn generate_constructor_tests(
+	struct_name: &Ident,
+	checked_fields: &[&ParsedField],
+	options: &ContainerOptions,
+) -> TokenStream {
+	if options.constructors.is_empty() {
+		return TokenStream::new();
+	}
+	let module_name = Ident::new(
+		&format!("__check_initial_state_{}", struct_name),
+		Span::call_site(),
+	);
+	let test_names = constructor_test_names(&options.constructors);
+	let mutation_assertion = generate_mutation_assertion(options.panic_payload);
+	let tests = options.constructors.iter().zip(test_names).map(|(constructor, test_name)| {
+		let call = replace_self(constructor_call(constructor), struct_name);
+		let clone_test = if options.check_clone {
+			let clone_test_name = Ident::new(
+				&test_name.replacen("constructor_", "clone_", 1),
+				Span::call_site(),
+			);
+			quote! {
+				#[test]
+				fn #clone_test_name() {
+					::std::clone::Clone::clone(&(#call)).check_initial_state();
+				}
+			}
+		} else {
+			TokenStream::new()
+		};
+		let mutation_test = if options.mutation_test {
+			let mutation_test_name = Ident::new(
+				&test_name.replacen("constructor_", "mutation_", 1),
+				Span::call_site(),
+			);
+			let mutations = checked_fields.iter().map(|field| {
+				return generate_mutation(field, &call, options.setter.as_deref(), options.packed);
+			});
+			quote! {
+				#[test]
+				fn #mutation_test_name() {
+					#mutation_assertion
+					#(#mutations)*
+				}
+			}
+		} else {
+			TokenStream::new()
+		};
+		let test_name = Ident::new(&test_name, Span::call_site());
+		return quote! {
+			#[test]
+			fn #test_name() {
+				(#call).check_initial_state();
+			}
+
+			#clone_test
+
+			#mutation_test
+		};
+	})
+		.collect::<Vec<_>>();
+	let coverage_off = generate_coverage_off();
+	return quote! {
+		/// Tests generated by `CheckInitialState` proc macro for the
+		/// constructors listed in `check_initial_state` attribute.
+		#[cfg(test)]
+		#coverage_off
+		#[allow(non_snake_case, clippy::panic)]
+		mod #module_name {
+			use super::*;
+
+			#(#tests)*
+		}
+	};
+}
+
+/// Names a test of each of `constructors`, e.g. `constructor_new` for
+/// `Self::new`. Names of constructors, which differ by non&#x2011;alphanumeric
+/// characters only, are disambiguated with the smallest index, which doesn't
+/// clash with other names, e.g. with the one of `Self::new_1` constructor.
+fn constructor_test_names(constructors: &[Expr]) -> Vec<String> {
+	let mut test_names: Vec<String> = Vec::with_capacity(constructors.len());
+	for constructor in constructors {
+		let base_name = format!("constructor_{}", sanitize_test_name(constructor));
+		let mut test_name = base_name.clone();
+		let mut index = 1;
+		while test_names.contains(&test_name) {
+			test_name = format!("{}_{}", base_name, index);
+			index += 1;
+		}
+		test_names.push(test_name);
+	}
+	return test_names;
+}
+
+/// Generates the registration of the struct in the registry of
+/// `check-initial-state` runtime crate, so `verify_all()` constructs an instance
+/// with each constructor and checks it. The registration is an entry of
+/// `linkme` distributed slice, which the linker collects, so nothing runs
+/// before `main()`. Nothing is generated without `register` option.
+fn generate_registration(struct_name: &Ident, options: &ContainerOptions) -> TokenStream {
+	if !options.register || options.disabled {
+		return TokenStream::new();
+	}
+	let struct_name_string = struct_name.to_string();
+	let calls = options.constructors.iter()
+		.map(|constructor| replace_self(constructor_call(constructor), struct_name));
+	// Items of the runtime crate are referred to through its macro, which
+	// reports its missing `registry` feature
+	return quote! {
+		::check_initial_state::__private::registered! {
+			const _: () = {
+				#[::check_initial_state::__private::linkme::distributed_slice(
+					::check_initial_state::registry::REGISTERED_CHECKS
+				)]
+				#[linkme(crate = ::check_initial_state::__private::linkme)]
+				static REGISTRATION: ::check_initial_state::registry::RegisteredCheck =
+					::check_initial_state::registry::RegisteredCheck::new(#struct_name_string, || {
+						#(::check_initial_state::CheckInitialState::try_check_initial_state(&(#calls))?;)*
+						::std::result::Result::Ok(())
+					});
+			};
+		}
+	};
+}
+
+/// Generates a module containing a Kani proof harness for each constructor
+/// listed in `options`. Each harness proves that the constructed instance
+/// passes `check_initial_state()` for all possible executions. The module is
+/// compiled only by `cargo kani`.
+fn generate_kani_proofs(struct_name: &Ident, options: &ContainerOptions)
+	-> TokenStream {
+	let module_name = Ident::new(
+		&format!("__check_initial_state_proofs_{}", struct_name),
+		Span::call_site(),
+	);
+	let test_names = constructor_test_names(&options.constructors);
+	let proofs = options.constructors.iter().zip(test_names).map(|(constructor, test_name)| {
+		let call = replace_self(constructor_call(constructor), struct_name);
+		let proof_name = Ident::new(&test_name, Span::call_site());
+		return quote! {
+			#[::kani::proof]
+			fn #proof_name() {
+				(#call).check_initial_state();
+			}
+		};
+	});
+	return quote! {
+		/// Kani proof harnesses generated by `CheckInitialState` proc macro for
+		/// the constructors listed in `check_initial_state` attribute.
+		#[cfg(kani)]
+		#[allow(non_snake_case)]
+		mod #module_name {
+			use super::*;
+
+			#(#proofs)*
+		}
+	};
+}
+
+/// Generates a block of mutation test which sets `field` of an instance,
+/// constructed by `call`, and expects `check_initial_state()` to panic
+/// mentioning `field`.
+/// # Parameters
+/// * `setter` &ndash; Setters naming convention with `{}` standing for a field
+/// name. The setter is called with a value violating initial state, e.g.
+/// `Default::default()` for an `Option` payload. If absent, the field is
+/// assigned such value, e.g. `Some(Default::default())`, directly. No block is
+/// generated if the field check doesn't provide such value, e.g. for
+/// `expect_matches`. In `lenient` mode, the field is mutated only if it turns
+/// out to be `Option` at runtime.
+/// * `packed` &ndash; Whether the struct is packed, so its fields cannot be
+/// borrowed in place.
+fn generate_mutation(
+	field: &ParsedField,
+	call: &TokenStream,
+	setter: Option<&str>,
+	packed: bool,
+) -> TokenStream {
+	let ident = field.ident;
+	let check = &field.options.check;
+	// Expression which mutates the field and evaluates to whether it has been
+	// mutated
+	let mutation = match (check.violating_value(setter.is_some()), setter) {
+		(Some(value), Some(setter)) => {
+			let setter = Ident::new(&setter.replace("{}", &ident.unraw().to_string()), Span::call_site());
+			quote! { { instance.#setter(#value); true } }
+		},
+		(Some(value), None) => quote! { { instance.#ident = #value; true } },
+		(None, _) => {
+			let place = if packed { quote!(value) } else { quote!(instance.#ident) };
+			let violation = match check.violate(&place) {
+				Some(violation) => violation,
+				None => return TokenStream::new(),
+			};
+			if packed {
+				// A field of a packed struct is mutated through its copy
+				quote! {
+					{
+						let mut value = instance.#ident;
+						let mutated = #violation;
+						instance.#ident = value;
+						mutated
+					}
+				}
+			} else {
+				violation
+			}
+		},
+	};
+	let field_name = field.report_name();
+	return quote! {
+		{
+			let mut instance = #call;
+			if #mutation {
+				assert_detected(|| instance.check_initial_state(), #field_name);
+			}
+		}
+	};
+}
+
+/// Generates `assert_detected()` function of a mutation test, which calls
+/// `check` and expects it to panic mentioning the mutated field. It is shared
+/// by all mutated fields, so the code of a struct with many fields stays
+/// compact. Also generates the probe mutating fields whose violating value is
+/// known at runtime only, see [`FieldCheck::violate()`]. The generated code
+/// doesn't depend on `check_initial_state` crate unless `panic_payload` is set.
+/// # Parameters
+/// * `panic_payload` &ndash; Whether `check_initial_state()` panics with
+/// `InitialStatePanic` payload, which is not a string.
+fn generate_mutation_assertion(panic_payload: bool) -> TokenStream {
+	let payload_message = if panic_payload {
+		quote! {
+			else if let ::std::option::Option::Some(payload) =
+				payload.downcast_ref::<::check_initial_state::InitialStatePanic>() {
+				::std::string::ToString::to_string(payload)
+			}
+		}
+	} else {
+		TokenStream::new()
+	};
+	return quote! {
+		/// Wraps a mutable reference to a mutated field.
+		#[allow(dead_code)]
+		struct __MutProbe<'a, T>(&'a mut T);
+
+		/// Sets an `Option` field to `Some(Default::default())`.
+		#[allow(dead_code)]
+		trait __OptionMutProbe {
+			fn violate(&mut self) -> bool;
+		}
+
+		impl<T: ::std::default::Default> __OptionMutProbe for __MutProbe<'_, ::std::option::Option<T>> {
+			fn violate(&mut self) -> bool {
+				*self.0 = ::std::option::Option::Some(T::default());
+				true
+			}
+		}
+
+		/// Leaves a non-`Option` field, or an `Option` one with non-`Default`
+		/// payload, intact.
+		#[allow(dead_code)]
+		trait __OtherMutProbe {
+			fn violate(&mut self) -> bool;
+		}
+
+		impl<T> __OtherMutProbe for &mut __MutProbe<'_, T> {
+			fn violate(&mut self) -> bool {
+				false
+			}
+		}
+
+		fn assert_detected(check: impl ::std::ops::FnOnce(), field_name: &str) {
+			let payload = match ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(check)) {
+				::std::result::Result::Ok(()) => {
+					::std::panic!(
+						"Setting field `{}` is not detected by `check_initial_state()`",
+						field_name,
+					)
+				},
+				::std::result::Result::Err(payload) => payload,
+			};
+			let message = if let ::std::option::Option::Some(message) =
+				payload.downcast_ref::<&str>() {
+				::std::string::ToString::to_string(message)
+			} #payload_message else {
+				payload.downcast_ref::<::std::string::String>().cloned().unwrap_or_default()
+			};
+			// Unlike `assert!`, which expands to unqualified `panic!` before
+			// edition 2021
+			if !message.contains(&::std::format!("`{}`", field_name)) {
+				::std::panic!("Panic message `{}` does not mention field `{}`", message, field_name,);
+			}
+		}
+	};
+}
+
+/// Turns `constructor` expression into a string suitable to be a part of a
+/// test function name. E.g. `Self::with_capacity(10)` becomes
+/// `with_capacity_10`.
+fn sanitize_test_name(constructor: &Expr) -> String {
+	let mut name = String::new();
+	for character in quote!(#constructor).to_string().chars() {
+		if character.is_ascii_alphanumeric() {
+			name.push(character.to_ascii_lowercase());
+		} else if !name.is_empty() && !name.ends_with('_') {
+			name.push('_');
+		}
+	}
+	let name = name.trim_end_matches('_');
+	return name.strip_prefix("self_").unwrap_or(name).to_owned();
+}
+
+/// Replaces each `::check_initial_state` path prefix in `tokens`, which refers
+/// to the runtime crate, with `crate_path`, e.g. `::my_facade::check`.
+/// Prefixes nested into groups are replaced as well. A prefix must be followed
+/// by `::`, so `CheckInitialState::check_initial_state(...)` method calls are
+/// kept, and must not continue a path, so `other::check_initial_state::...`
+/// is kept as well.
+fn replace_crate_path(tokens: TokenStream, crate_path: &Path) -> TokenStream {
+	let tokens = tokens.into_iter().collect::<Vec<_>>();
+	let mut replaced = TokenStream::new();
+	let mut index = 0;
+	// Whether the previous token is an identifier, which `::` would continue
+	let mut follows_path = false;
+	while index < tokens.len() {
+		if !follows_path && is_crate_path_prefix(&tokens[index..]) {
+			replaced.extend(quote!(#crate_path));
+			index += 3;
+			follows_path = true;
+			continue;
+		}
+		let token = match &tokens[index] {
+			TokenTree::Group(group) => {
+				let mut replaced_group =
+					Group::new(group.delimiter(), replace_crate_path(group.stream(), crate_path));
+				replaced_group.set_span(group.span());
+				TokenTree::Group(replaced_group)
+			},
+			token => token.clone(),
+		};
+		// Keywords, e.g. `impl` or `for`, are rejected by `Ident` parser
+		follows_path = matches!(&token, TokenTree::Ident(ident)
+			if syn::parse2::<Ident>(TokenTree::Ident(ident.clone()).into()).is_ok());
+		replaced.extend([token]);
+		index += 1;
+	}
+	return replaced;
+}
+
+/// Tells whether `tokens` start with `::check_initial_state::`.
+fn is_crate_path_prefix(tokens: &[TokenTree]) -> bool {
+	let is_separator = |first: &TokenTree, second: &TokenTree| {
+		return matches!(
+			(first, second),
+			(TokenTree::Punct(first), TokenTree::Punct(second))
+				if first.as_char() == ':'
+					&& first.spacing() == Spacing::Joint
+					&& second.as_char() == ':'
+		);
+	};
+	return match tokens {
+		[first, second, TokenTree::Ident(ident), third, fourth, ..] => {
+			ident == "check_initial_state"
+				&& is_separator(first, second)
+				&& is_separator(third, fourth)
+		},
+		_ => false,
+	};
+}
+
+/// Replaces all `Self` occurrences in `tokens` with `struct_name`. Generated
+/// tests are free functions, so `Self` is not available there.
+fn replace_self(tokens: TokenStream, struct_name: &Ident) -> TokenStream {
+	return tokens.into_iter()
+		.map(|token| {
+			return match token {
+				TokenTree::Ident(ident) if ident == "Self" => {
+					TokenTree::Ident(Ident::new(&struct_name.to_string(), ident.span()))
+				},
+				TokenTree::Group(group) => {
+					let mut replaced =
+						Group::new(group.delimiter(), replace_self(group.stream(), struct_name));
+					replaced.set_span(group.span());
+					TokenTree::Group(replaced)
+				},
+				token => token,
+			};
+		})
+		.collect();
+}
+
+/// Clones generics of the struct, adding predicates of `bound` option to its
+/// where clause.
+fn generics_with_bound(input: &DeriveInput, options: &ContainerOptions) -> Generics {
+	let mut generics = input.generics.clone();
+	if !options.bound.is_empty() {
+		generics.make_where_clause().predicates.extend(options.bound.iter().cloned());
+	}
+	return generics;
+}
+
+/// Replaces all `Self` occurrences in `where_clause` with `self_type`, so the
+/// clause may be attached to a free function, e.g. a generated fixture. Paths
+/// like `Self::Output` become `<Struct<T>>::Output`.
+fn replace_self_type(where_clause: &WhereClause, self_type: &Type) -> WhereClause {
+	/// Visits all types of a where clause, replacing `Self`.
+	struct SelfReplacer<'a>(&'a Type);
+
+	impl VisitMut for SelfReplacer<'_> {
+		fn visit_type_mut(&mut self, ty: &mut Type) {
+			if let Type::Path(TypePath { qself: qself @ None, path }) = ty {
+				if path.leading_colon.is_none() && path.segments[0].ident == "Self" {
+					if path.segments.len() == 1 {
+						*ty = self.0.clone();
+						return;
+					}
+					*qself = Some(QSelf {
+						lt_token: Default::default(),
+						ty: Box::new(self.0.clone()),
+						position: 0,
+						as_token: None,
+						gt_token: Default::default(),
+					});
+					path.segments = path.segments.iter().skip(1).cloned().collect();
+					path.leading_colon = Some(Default::default());
+				}
+			}
+			visit_mut::visit_type_mut(self, ty);
+		}
+	}
+
+	let mut where_clause = where_clause.clone();
+	SelfReplacer(self_type).visit_where_clause_mut(&mut where_clause);
+	return where_clause;
+}
+
+/// Fetches struct from `input`. Parsing error is returned in case of data
+/// structure for this procedural macro is other than a struct with named
+/// fields.
+fn extract_struct(input: &DeriveInput) -> Result<&DataStruct, TokenStream> {
+	return match &input.data {
+		Data::Struct(data_struct) => Ok(data_struct),
+		_ => {
+			let message = Error::new_spanned(
+				input,
+				"`CheckInitialState` procedural macro is allowed for structs with named fields \
+					only",
+			)
+				.to_compile_error();
+			Err(message)
+		},
+	};
+}
+
+/// Field of the struct along with its options.
+pub struct ParsedField<'a> {
+	/// Name of the field.
+	pub ident: &'a Ident,
+	/// Declared type of the field.
+	pub ty: &'a Type,
+	/// Options of the field, including whether it is ignored.
+	pub options: FieldOptions,
+}
+
+impl ParsedField<'_> {
+	/// Name of the field in panic messages and reports, i.e. its `report_name`
+	/// if specified, or the field name otherwise.
+	pub fn report_name(&self) -> String {
+		return match &self.options.report_name {
+			Some(report_name) => report_name.clone(),
+			None => self.ident.to_string(),
+		};
+	}
+}
+
+/// Strips invisible groups off `ty`. Such group wraps a type which is passed to
+/// `macro_rules!` as `$ty:ty` fragment, so the struct fields generated by the
+/// macro would not be recognized by their type names otherwise.
+fn ungroup_type(ty: &Type) -> &Type {
+	return match ty {
+		Type::Group(group) => ungroup_type(&group.elem),
+		ty => ty,
+	};
+}
+
+/// Tells whether `ty` is `Option` by the name of its last path segment, since
+/// proc macros cannot resolve types.
+fn is_option_type(ty: &Type) -> bool {
+	return match ungroup_type(ty) {
+		Type::Path(path) if path.qself.is_none() => {
+			path.path.segments.last().is_some_and(|segment| segment.ident == "Option")
+		},
+		_ => false,
+	};
+}
+
+/// Forms compile error pointing at `tokens`.
+fn to_compile_error<T: quote::ToTokens>(tokens: T, message: &str) -> TokenStream {
+	return Error::new_spanned(tokens, message).to_compile_error();
+}
+
+/// Retrieves fields contained in `data_struct`. Parsing error is returned, if
+/// `data_struct` is not of expected type.
+/// # Parameters
+/// * `data_struct` &ndash; Expected to be a struct with named fields.
+/// * `input` &ndash; Is entire abstract syntax tree provided for this
+/// procedural macro. May be used to form a syntax error when `data_struct` is
+/// other than expected.
+fn fetch_fields<'a>(data_struct: &'a DataStruct, input: &DeriveInput)
+	-> Result<&'a Punctuated<Field, Comma>, TokenStream> {
+	return match &data_struct.fields {
+		Fields::Named(named_fields) => Ok(&named_fields.named),
+		Fields::Unit => {
+			let message = Error::new_spanned(
+				input,
+				"`CheckInitialState` procedural macro is no allowed for unit structs",
+			)
+				.to_compile_error();
+			Err(message)
+		},
+		fields => {
+			let message = Error::new_spanned(
+				fields,
+				"`CheckInitialState` procedural macro is allowed for structs with named fields \
+					only",
+			)
+				.to_compile_error();
+			Err(message)
+		},
+	};
+}
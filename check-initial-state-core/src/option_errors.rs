@@ -0,0 +1,99 @@
+use proc_macro2::{TokenStream, TokenTree};
+use syn::meta::ParseNestedMeta;
+use syn::parse::ParseStream;
+use syn::{Error, Token};
+
+/// Maximal edit distance between an unknown option and a known one, which
+/// still makes the latter to be suggested.
+const MAX_SUGGESTION_DISTANCE: usize = 2;
+
+/// Collects errors of attribute options, so all of them are reported at once
+/// instead of the first one only.
+#[derive(Default)]
+pub struct OptionErrors {
+	/// All collected errors combined.
+	error: Option<Error>,
+}
+
+impl OptionErrors {
+	/// Adds `error` to the collected ones.
+	pub fn push(&mut self, error: Error) {
+		match &mut self.error {
+			Some(collected) => collected.combine(error),
+			None => self.error = Some(error),
+		}
+	}
+
+	/// Adds the error of `result`, if any.
+	pub fn check(&mut self, result: syn::Result<()>) {
+		if let Err(error) = result {
+			self.push(error);
+		}
+	}
+
+	/// Turns the collected errors into compile errors. `Ok` is returned if
+	/// nothing is collected.
+	pub fn finish(self) -> Result<(), TokenStream> {
+		return match self.error {
+			Some(error) => Err(error.to_compile_error()),
+			None => Ok(()),
+		};
+	}
+}
+
+/// Parses a single option of a nested meta attribute, e.g. `constructor =
+/// "new"`, with `parse`. If the option is malformed, its error is added to
+/// `errors` and the rest of the option is skipped, so the following options
+/// are still parsed.
+pub fn parse_option<F>(option: &ParseNestedMeta, errors: &mut OptionErrors, parse: F)
+	where F: FnOnce(&ParseNestedMeta) -> syn::Result<()> {
+	if let Err(error) = parse(option) {
+		errors.push(error);
+		skip_option(option.input);
+	}
+}
+
+/// Creates an error of an unknown `option` of `attribute`. The error suggests
+/// a known option which is the closest to the unknown one, if such exists.
+pub fn unknown_option(option: &ParseNestedMeta, attribute: &str, known: &[&str]) -> Error {
+	let mut message = format!("Unknown `{}` option", attribute);
+	if let Some(name) = option.path.get_ident().map(|ident| ident.to_string()) {
+		let suggestion = known.iter()
+			.map(|candidate| (edit_distance(&name, candidate), candidate))
+			.filter(|(distance, _)| *distance <= MAX_SUGGESTION_DISTANCE)
+			.min_by_key(|(distance, _)| *distance);
+		if let Some((_, candidate)) = suggestion {
+			message.push_str(&format!(". Did you mean `{}`?", candidate));
+		}
+	}
+	return option.error(message);
+}
+
+/// Skips the rest of the current option, i.e. everything up to the next
+/// top&#x2011;level comma.
+pub fn skip_option(input: ParseStream) {
+	while !input.is_empty() && !input.peek(Token![,]) {
+		if input.parse::<TokenTree>().is_err() {
+			return;
+		}
+	}
+}
+
+/// Calculates Levenshtein distance between `left` and `right`.
+fn edit_distance(left: &str, right: &str) -> usize {
+	let right = right.chars().collect::<Vec<_>>();
+	// Distances between the processed prefix of `left` and each prefix of
+	// `right`
+	let mut distances = (0..=right.len()).collect::<Vec<_>>();
+	for (left_index, left_char) in left.chars().enumerate() {
+		let mut previous_diagonal = distances[0];
+		distances[0] = left_index + 1;
+		for (right_index, right_char) in right.iter().enumerate() {
+			let substitution = previous_diagonal + usize::from(left_char != *right_char);
+			previous_diagonal = distances[right_index + 1];
+			distances[right_index + 1] =
+				substitution.min(distances[right_index] + 1).min(distances[right_index + 1] + 1);
+		}
+	}
+	return distances[right.len()];
+}
@@ -0,0 +1,96 @@
+use crate::container_attribute::ContainerOptions;
+use crate::{disabled_switch, generate_dependency_tracking, to_compile_error};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{ItemFn, ReturnType};
+
+/// Error of `post_build_check` attribute placed on other item than a method.
+const MISPLACED_MESSAGE: &str = "`post_build_check` attribute is applicable to methods only";
+
+/// Option of `post_build_check` attribute, which makes the method return
+/// `MissingFieldsError` converted into its error type instead of panicking.
+const MAP_ERR_OPTION: &str = "map_err";
+
+/// Generates the implementation of `post_build_check` attribute macro. A check
+/// is prepended to the body of `item` method, so the method proceeds only if
+/// all fields of `self` checked by `CheckInitialState` derive are set. Unset
+/// fields are reported with a panic, or with `MissingFieldsError` returned via
+/// `From` conversion into the error type of the method with `map_err` option.
+/// The method is left intact if checks are disabled by
+/// `CHECK_INITIAL_STATE_DISABLED` environment variable. Problems of `args` or
+/// `item` are reported as compile errors in the returned tokens.
+pub fn expand_post_build_check(args: TokenStream, item: TokenStream) -> TokenStream {
+	let map_err = match syn::parse2::<Option<syn::Ident>>(args.clone()) {
+		Ok(None) => false,
+		Ok(Some(option)) if option == MAP_ERR_OPTION => true,
+		_ => {
+			return to_compile_error(
+				args,
+				"`post_build_check` attribute takes either no options or `map_err` option",
+			);
+		},
+	};
+	let function = match syn::parse2::<ItemFn>(item) {
+		Ok(function) => function,
+		Err(err) => return syn::Error::new(err.span(), MISPLACED_MESSAGE).to_compile_error(),
+	};
+	let signature = &function.sig;
+	if let Some(constness) = &signature.constness {
+		return to_compile_error(
+			constness,
+			"`post_build_check` attribute is not applicable to `const` functions",
+		);
+	}
+	if signature.receiver().is_none() {
+		return to_compile_error(
+			signature,
+			"`post_build_check` attribute expects a method taking `self`, e.g. `fn build(self)`",
+		);
+	}
+	if map_err && matches!(signature.output, ReturnType::Default) {
+		return to_compile_error(
+			signature,
+			"`post_build_check(map_err)` attribute expects a method returning `Result`",
+		);
+	}
+	let dependency_tracking = generate_dependency_tracking(&ContainerOptions::default());
+	let attrs = &function.attrs;
+	let vis = &function.vis;
+	let statements = &function.block.stmts;
+	if disabled_switch::is_disabled() {
+		return quote! {
+			#(#attrs)*
+			#vis #signature {
+				#dependency_tracking
+				#(#statements)*
+			}
+		};
+	}
+	let report = if map_err {
+		quote! { return ::std::result::Result::Err(::std::convert::From::from(error)); }
+	} else {
+		quote! { ::std::panic!("{}", error); }
+	};
+	return quote! {
+		#(#attrs)*
+		#vis #signature {
+			#dependency_tracking
+			{
+				let mut missing_fields = ::std::vec::Vec::new();
+				self.for_each_field_state(|field, is_set| {
+					if !is_set {
+						missing_fields.push(field);
+					}
+				});
+				if !missing_fields.is_empty() {
+					let error = ::check_initial_state::MissingFieldsError::new(
+						::std::any::type_name::<Self>(),
+						missing_fields,
+					);
+					#report
+				}
+			}
+			#(#statements)*
+		}
+	};
+}
@@ -0,0 +1,54 @@
+use std::env;
+
+/// Environment variable which Cargo sets to `rust-version` of the package
+/// being compiled.
+const RUST_VERSION_VARIABLE: &str = "CARGO_PKG_RUST_VERSION";
+
+/// Minimal Rust version which the generated code must compile with. Some
+/// conveniences of the generated code are omitted or replaced for older
+/// versions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RustVersion {
+	major: u32,
+	minor: u32,
+}
+
+impl RustVersion {
+	/// `#[track_caller]` attribute stabilization.
+	pub const TRACK_CALLER: Self = Self { major: 1, minor: 46 };
+
+	/// `const _` items stabilization.
+	pub const UNDERSCORE_CONST: Self = Self { major: 1, minor: 37 };
+
+	/// `panic!()` in constants stabilization.
+	pub const CONST_PANIC: Self = Self { major: 1, minor: 57 };
+
+	/// Parses a version like `1.56` or `1.56.1`. Patch version is ignored, since
+	/// no codegen depends on it. `None` is returned if `version` is malformed.
+	pub fn parse(version: &str) -> Option<Self> {
+		let mut parts = version.trim().split('.');
+		let major = parts.next()?.parse().ok()?;
+		let minor = parts.next()?.parse().ok()?;
+		return match parts.next() {
+			Some(patch) if patch.parse::<u32>().is_err() => None,
+			_ if parts.next().is_some() => None,
+			_ => Some(Self { major, minor }),
+		};
+	}
+
+	/// Reads `rust-version` of the package which uses the derive. `None` is
+	/// returned if the package doesn't specify it.
+	pub fn from_package() -> Option<Self> {
+		return env::var(RUST_VERSION_VARIABLE).ok().and_then(|version| Self::parse(&version));
+	}
+
+	/// Tells whether the generated code may rely on `feature`, i.e. whether
+	/// `msrv` is at least the version which stabilized `feature`. Any feature
+	/// is supported if `msrv` is unknown.
+	pub fn supports(msrv: Option<Self>, feature: Self) -> bool {
+		return match msrv {
+			Some(msrv) => msrv >= feature,
+			None => true,
+		};
+	}
+}
@@ -0,0 +1,30 @@
+use crate::option_errors::skip_option;
+use syn::Attribute;
+
+/// Attribute of `serde` derives.
+const SERDE_ATTRIBUTE: &str = "serde";
+
+/// `serde` field options which make the field never deserialized.
+pub const SKIP_OPTIONS: [&str; 2] = ["skip", "skip_deserializing"];
+
+/// `serde` option which makes the field, or all fields if placed on the
+/// struct, take its default value if missing from the input.
+pub const DEFAULT_OPTIONS: [&str; 1] = ["default"];
+
+/// Tells whether any of `serde` attributes among `attributes` has any of
+/// `names` options, e.g. `#[serde(skip)]`. Malformed `serde` attributes are
+/// left for `serde` itself to report.
+pub fn has_serde_option(attributes: &[Attribute], names: &[&str]) -> bool {
+	let mut found = false;
+	let serde_attributes =
+		attributes.iter().filter(|attribute| attribute.path().is_ident(SERDE_ATTRIBUTE));
+	for attribute in serde_attributes {
+		let _ = attribute.parse_nested_meta(|option| {
+			found |= names.iter().any(|name| option.path.is_ident(name));
+			// Option values, e.g. `rename = "name"`, are of no interest
+			skip_option(option.input);
+			return Ok(());
+		});
+	}
+	return found;
+}
@@ -0,0 +1,163 @@
+use crate::{to_compile_error, RustVersion, NONE_FIELDS_CONST_PREFIX};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote_spanned};
+use syn::spanned::Spanned;
+use syn::visit_mut::{self, VisitMut};
+use syn::{
+	Expr, ExprStruct, FnArg, ImplItem, ImplItemFn, Item, ItemImpl, Member, Path, PathSegment, Type,
+};
+
+/// Error of `verify_initial_literals` attribute placed on other item than an
+/// `impl` block.
+const MISPLACED_MESSAGE: &str = "`verify_initial_literals` attribute is applicable to `impl` blocks \
+	only";
+
+/// Attribute of an associated function, which excludes it from the analysis,
+/// e.g. a constructor which deliberately sets some fields.
+const IGNORE_CONSTRUCTOR_ATTRIBUTE: &str = "ignore_constructor";
+
+/// Generates the implementation of `verify_initial_literals` attribute macro.
+/// Each associated function of `item` block without a receiver, i.e. a
+/// constructor, is scanned for struct literals of the implemented type, e.g.
+/// `Self { .. }`. A field initialized with `Some(..)` in such literal gets a
+/// `const` item in the function body, which evaluation fails compilation if
+/// `CheckInitialState` derive expects the field to be `None`. Functions annotated with
+/// `ignore_constructor` are skipped. Problems of `args` or `item` are reported
+/// as compile errors in the returned tokens.
+pub fn expand_verify_initial_literals(args: TokenStream, item: TokenStream) -> TokenStream {
+	if !args.is_empty() {
+		return to_compile_error(args, "`verify_initial_literals` attribute takes no options");
+	}
+	let mut block = match syn::parse2::<ItemImpl>(item) {
+		Ok(block) => block,
+		Err(err) => return syn::Error::new(err.span(), MISPLACED_MESSAGE).to_compile_error(),
+	};
+	if !RustVersion::supports(RustVersion::from_package(), RustVersion::CONST_PANIC) {
+		return to_compile_error(
+			&block.self_ty,
+			"`verify_initial_literals` attribute requires Rust 1.57 or newer",
+		);
+	}
+	let struct_path = match &*block.self_ty {
+		Type::Path(path) if path.qself.is_none() && !path.path.segments.is_empty() => &path.path,
+		_ => {
+			return to_compile_error(
+				&block.self_ty,
+				"`verify_initial_literals` attribute expects an `impl` block of a struct",
+			);
+		},
+	};
+	let struct_name = struct_path.segments.last().unwrap().ident.to_string();
+	// Generic arguments of the struct are dropped, since the constant has none
+	let mut none_fields = struct_path.clone();
+	let last_segment = none_fields.segments.last_mut().unwrap();
+	*last_segment =
+		PathSegment::from(format_ident!("{}{}", NONE_FIELDS_CONST_PREFIX, last_segment.ident));
+	for impl_item in &mut block.items {
+		if let ImplItem::Fn(function) = impl_item {
+			let ignored = take_ignore_attribute(function);
+			let constructor =
+				!function.sig.inputs.iter().any(|input| matches!(input, FnArg::Receiver(_)));
+			if ignored || !constructor {
+				continue;
+			}
+			let mut finder = LiteralFinder {
+				struct_name: &struct_name,
+				none_fields: &none_fields,
+				assertions: Vec::new(),
+			};
+			finder.visit_block_mut(&mut function.block);
+			let assertions = finder.assertions;
+			if !assertions.is_empty() {
+				let body = &function.block;
+				function.block = syn::parse_quote!({
+					#(#assertions)*
+					#body
+				});
+			}
+		}
+	}
+	return quote::ToTokens::into_token_stream(block);
+}
+
+/// Removes `ignore_constructor` attributes off `function`, which would be
+/// rejected by the compiler otherwise. Tells whether any was found.
+fn take_ignore_attribute(function: &mut ImplItemFn) -> bool {
+	let count = function.attrs.len();
+	function.attrs.retain(|attribute| !attribute.path().is_ident(IGNORE_CONSTRUCTOR_ATTRIBUTE));
+	return function.attrs.len() != count;
+}
+
+/// Collects `const` assertions for fields initialized with `Some(..)` in struct
+/// literals of the implemented type.
+struct LiteralFinder<'a> {
+	/// Name of the implemented type, which literals may use instead of `Self`.
+	struct_name: &'a str,
+	/// Path to the constant listing the fields expected to be `None`.
+	none_fields: &'a Path,
+	/// Assertions in the order literals are found.
+	assertions: Vec<TokenStream>,
+}
+
+impl VisitMut for LiteralFinder<'_> {
+	fn visit_expr_struct_mut(&mut self, literal: &mut ExprStruct) {
+		let last_segment = literal.path.segments.last();
+		let own = literal.qself.is_none()
+			&& last_segment.is_some_and(|segment| {
+				return segment.ident == "Self" || segment.ident == self.struct_name;
+			});
+		if own {
+			for field in &literal.fields {
+				if let (Member::Named(name), true) = (&field.member, is_some_call(&field.expr)) {
+					let assertion =
+						generate_assertion(self.struct_name, self.none_fields, name, field.expr.span());
+					self.assertions.push(assertion);
+				}
+			}
+		}
+		visit_mut::visit_expr_struct_mut(self, literal);
+	}
+
+	fn visit_item_mut(&mut self, _: &mut Item) {
+		// `Self` is not available in nested items
+	}
+}
+
+/// Tells whether `expr` is a call of `Some`, e.g. `Some(1)` or
+/// `Option::Some(1)`.
+fn is_some_call(expr: &Expr) -> bool {
+	return match expr {
+		Expr::Call(call) => match &*call.func {
+			Expr::Path(path) => path.path.segments.last().is_some_and(|segment| segment.ident == "Some"),
+			_ => false,
+		},
+		Expr::Group(group) => is_some_call(&group.expr),
+		Expr::Paren(paren) => is_some_call(&paren.expr),
+		_ => false,
+	};
+}
+
+/// Generates a `const` item, which evaluation fails compilation if `field` is
+/// expected to be `None` by `CheckInitialState` derive. Unlike an inline
+/// `const` block, the item is evaluated by `cargo check` as well. The error
+/// points to `span`, i.e. the `Some(..)` initializer.
+/// # Parameters
+/// * `none_fields` &ndash; Path to the constant listing the fields expected to
+/// be `None`.
+fn generate_assertion(struct_name: &str, none_fields: &Path, field: &Ident, span: Span)
+	-> TokenStream {
+	let field_name = field.to_string();
+	let message = format!(
+		"Field `{}` of `{}` is initialized with `Some` in a constructor, but is expected to be \
+			`None` in initial state",
+		field_name,
+		struct_name,
+	);
+	return quote_spanned! {span=>
+		const _: () = {
+			if ::check_initial_state::__private::contains_field(#none_fields, #field_name) {
+				::std::panic!(#message);
+			}
+		};
+	};
+}
@@ -0,0 +1,88 @@
+use check_initial_state_core::{ConfigFile, EmptyCheckPolicy, RustVersion, CONFIG_FILE_NAME};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Directory with config file fixtures.
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/config_file");
+
+#[test]
+fn config_read_from_ancestor() {
+	let config = ConfigFile::find(&Path::new(FIXTURES).join("strict/nested")).unwrap().unwrap();
+	assert_eq!(config.path, Path::new(FIXTURES).join("strict/check_initial_state.toml"));
+	assert!(config.lenient);
+	assert!(config.warn_ignored_option);
+	assert!(!config.serde);
+	assert_eq!(config.empty_check, EmptyCheckPolicy::Deny);
+	assert_eq!(config.msrv, RustVersion::parse("1.40"));
+}
+
+#[test]
+fn unknown_key_reported() {
+	let error = ConfigFile::find(&Path::new(FIXTURES).join("malformed")).err().unwrap();
+	assert!(error.contains("unknown key `pedantic`"), "{}", error);
+}
+
+#[test]
+fn search_stops_at_workspace_root() {
+	// The workspace has no config file, and its parents are not searched
+	assert!(ConfigFile::find(Path::new(env!("CARGO_MANIFEST_DIR"))).unwrap().is_none());
+}
+
+#[test]
+fn comments_and_literal_strings_accepted() {
+	let config = ConfigFile::find(&Path::new(FIXTURES).join("commented")).unwrap().unwrap();
+	assert!(config.serde);
+	assert!(!config.lenient);
+	assert_eq!(config.empty_check, EmptyCheckPolicy::Warn);
+	assert_eq!(config.msrv, RustVersion::parse("1.56"));
+}
+
+#[test]
+fn unsupported_syntax_reported() {
+	let error = ConfigFile::find(&Path::new(FIXTURES).join("table")).err().unwrap();
+	assert!(error.contains("line 1: expected `key = value`"), "{}", error);
+}
+
+#[test]
+fn strict_mode_and_naming_defaults_read() {
+	let config = ConfigFile::find(&Path::new(FIXTURES).join("defaults")).unwrap().unwrap();
+	assert!(config.warn_ignored_option);
+	assert_eq!(config.empty_check, EmptyCheckPolicy::Deny);
+	assert_eq!(config.setter_hint.as_deref(), Some("with_{}"));
+	assert_eq!(config.visibility.as_deref(), Some("pub(crate)"));
+}
+
+#[test]
+fn strict_mode_conflict_reported() {
+	let error = ConfigFile::find(&Path::new(FIXTURES).join("conflicting")).err().unwrap();
+	assert!(error.contains("`strict` cannot be combined with `empty_check`"), "{}", error);
+}
+
+#[test]
+fn search_stops_at_nested_workspace_root() {
+	let root = temp_tree("nested_workspace");
+	let manifest = "[workspace.package]\nversion = \"0.1.0\"\n";
+	fs::write(root.join("workspace/Cargo.toml"), manifest).unwrap();
+	assert!(ConfigFile::find(&root.join("workspace/package")).unwrap().is_none());
+}
+
+#[test]
+fn published_package_searched_alone() {
+	let root = temp_tree("published_package");
+	let manifest =
+		"# THIS FILE IS AUTOMATICALLY GENERATED BY CARGO\n[package]\nname = \"package\"\n";
+	// Is vendored into the workspace of this repository, which the search
+	// doesn't reach
+	fs::write(root.join("workspace/package/Cargo.toml"), manifest).unwrap();
+	assert!(ConfigFile::find(&root.join("workspace/package")).unwrap().is_none());
+}
+
+/// Creates `workspace/package` directories in a fresh temporary directory
+/// named `name`, which contains a config file. Returns the temporary directory.
+fn temp_tree(name: &str) -> PathBuf {
+	let root = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+	let _ = fs::remove_dir_all(&root);
+	fs::create_dir_all(root.join("workspace/package")).unwrap();
+	fs::write(root.join(CONFIG_FILE_NAME), "lenient = true\n").unwrap();
+	root
+}
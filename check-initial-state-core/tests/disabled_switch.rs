@@ -0,0 +1,25 @@
+use check_initial_state_core::{expand, expand_check_for};
+use std::env;
+
+/// Checks that generated methods verify nothing while checks are disabled. The
+/// only test of the file, since it changes the environment of the process.
+#[test]
+fn disabled_checks_generate_no_panics() {
+	let input = syn::parse_quote! {
+		#[check_initial_state(constructor = "Self::new", mutation_test)]
+		struct Builder {
+			option: Option<u8>,
+		}
+	};
+	let check_for = quote::quote! { builder, Builder { option } };
+	assert!(expand(&input).to_string().contains("panic !"));
+	env::set_var("CHECK_INITIAL_STATE_DISABLED", "1");
+	let disabled = expand(&input).to_string();
+	let disabled_check_for = expand_check_for(check_for).to_string();
+	env::remove_var("CHECK_INITIAL_STATE_DISABLED");
+	assert!(disabled.contains("fn check_initial_state"));
+	assert!(disabled.contains("option_env"));
+	assert!(!disabled.contains("panic !"));
+	assert!(!disabled.contains("mutation_test"));
+	assert!(!disabled_check_for.contains("panic !"));
+}
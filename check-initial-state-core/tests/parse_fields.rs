@@ -0,0 +1,96 @@
+use check_initial_state_core::{expand, parse_container_options, parse_fields};
+use syn::DeriveInput;
+
+#[test]
+fn fields_keep_declaration_order_with_ignored_ones() {
+	let input: DeriveInput = syn::parse_quote! {
+		struct Builder {
+			first: Option<u8>,
+			#[ignore_field(reason = "Counts calls")]
+			counter: u32,
+			#[expect_false]
+			flag: bool,
+		}
+	};
+	let options = parse_container_options(&input).unwrap();
+	let fields = parse_fields(&input, &options).unwrap();
+	let summary = fields.iter()
+		.map(|field| (field.ident.to_string(), field.options.ignored, field.options.reason.clone()))
+		.collect::<Vec<_>>();
+	assert_eq!(summary, [
+		("first".to_owned(), false, None),
+		("counter".to_owned(), true, Some("Counts calls".to_owned())),
+		("flag".to_owned(), false, None),
+	]);
+}
+
+#[test]
+fn field_errors_are_returned_as_compile_errors() {
+	let input: DeriveInput = syn::parse_quote! {
+		struct Builder {
+			#[ignore_field(unknown)]
+			counter: u32,
+		}
+	};
+	let options = parse_container_options(&input).unwrap();
+	let errors = parse_fields(&input, &options).err().unwrap().to_string();
+	assert!(errors.contains("compile_error"));
+	assert!(errors.contains("Unknown `ignore_field` option"));
+}
+
+#[test]
+fn expansion_implements_check() {
+	let input: DeriveInput = syn::parse_quote! {
+		struct Builder {
+			option: Option<u8>,
+		}
+	};
+	let expansion = expand(&input).to_string();
+	assert!(expansion.contains("fn check_initial_state"));
+	assert!(!expansion.contains("compile_error"));
+}
+
+#[test]
+fn expansion_refers_to_overridden_crate() {
+	let input: DeriveInput = syn::parse_quote! {
+		#[check_initial_state(crate = "::my_facade::check", constructor = "Self::new")]
+		struct Builder {
+			option: Option<u8>,
+		}
+	};
+	let expansion = expand(&input).to_string();
+	assert!(expansion.contains("impl :: my_facade :: check :: CheckInitialState for Builder"));
+	assert!(expansion.contains("Self :: check_initial_state (self)"));
+	assert!(!expansion.contains(":: check_initial_state ::"));
+	assert!(!expansion.contains("compile_error"));
+}
+
+#[test]
+fn expansion_moves_panic_to_cold_function() {
+	let input: DeriveInput = syn::parse_quote! {
+		struct Builder {
+			option: Option<u8>,
+		}
+	};
+	let expansion = expand(&input).to_string();
+	assert!(expansion.contains("# [cold] # [inline (never)]"));
+	assert!(expansion.contains("Self :: __check_initial_state_failed (self , context , \"option\""));
+	assert!(expansion.contains("# [inline] fn check_initial_state_with_context"));
+}
+
+#[test]
+fn constructor_test_names_unique() {
+	let input: DeriveInput = syn::parse_quote! {
+		#[check_initial_state(constructors("Self::new", "new_2", "new", "Self::new", "new_1"))]
+		struct Builder {
+			option: Option<u8>,
+		}
+	};
+	let expansion = expand(&input).to_string();
+	let names = ["new", "new_1", "new_2", "new_3", "new_1_1"];
+	for name in names.iter() {
+		let test = format!("fn constructor_{} ()", name);
+		assert_eq!(expansion.matches(&test).count(), 1, "{}", test);
+	}
+	assert_eq!(expansion.matches("fn constructor_").count(), names.len());
+}
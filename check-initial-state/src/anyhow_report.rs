@@ -0,0 +1,53 @@
+//! [anyhow](https://crates.io/crates/anyhow) integration.
+//!
+//! [`InitialStateError`](crate::InitialStateError) implements
+//! `std::error::Error`, so `?` already converts it into `anyhow::Error`. This
+//! module additionally provides a report, which lists each field that is not
+//! in initial state as a separate cause.
+use crate::CheckInitialState;
+use anyhow::Error;
+
+/// Runs the check of [`CheckInitialState`] in functions returning
+/// `anyhow::Result`. Is implemented for all types implementing
+/// [`CheckInitialState`].
+/// ```
+/// use check_initial_state::anyhow_report::CheckInitialStateAnyhow;
+/// use check_initial_state::{CheckInitialState, Violation};
+///
+/// #[derive(CheckInitialState)]
+/// struct Builder {
+///     option: Option<i32>,
+/// }
+///
+/// let error = Builder { option: Some(10) }.check_initial_state_anyhow().unwrap_err();
+/// assert_eq!(error.to_string(), "`Builder` is not in initial state");
+/// let cause = error.chain().nth(1).unwrap();
+/// assert_eq!(cause.to_string(), "Field `option` has Some value instead of None");
+/// assert_eq!(error.downcast_ref::<Violation>().unwrap().field(), "option");
+/// ```
+pub trait CheckInitialStateAnyhow {
+	/// Checks all fields, which are not annotated with `ignore_field`, to be in
+	/// initial state.
+	/// # Errors
+	/// Any of checked fields is not in initial state. The error names the
+	/// struct, and its chain of causes consists of
+	/// [`Violation`](crate::Violation)s in the order of declaration, so
+	/// `{:?}` formatting prints each of them on a separate line.
+	fn check_initial_state_anyhow(&self) -> anyhow::Result<()>;
+}
+
+impl<T: CheckInitialState + ?Sized> CheckInitialStateAnyhow for T {
+	fn check_initial_state_anyhow(&self) -> anyhow::Result<()> {
+		let error = match self.try_check_initial_state() {
+			Ok(()) => return Ok(()),
+			Err(error) => error,
+		};
+		let mut violations = error.violations().iter().rev();
+		// Violations are never empty
+		let mut report = Error::msg(violations.next().unwrap().clone());
+		for violation in violations {
+			report = report.context(violation.clone());
+		}
+		return Err(report.context(format!("`{}` is not in initial state", error.struct_name())));
+	}
+}
@@ -0,0 +1,53 @@
+//! [eyre](https://crates.io/crates/eyre) integration.
+//!
+//! [`InitialStateError`](crate::InitialStateError) implements
+//! `std::error::Error`, so `?` already converts it into `eyre::Report`. This
+//! module additionally provides a report, which lists each field that is not
+//! in initial state as a separate cause.
+use crate::CheckInitialState;
+use eyre::Report;
+
+/// Runs the check of [`CheckInitialState`] in functions returning
+/// `eyre::Result`. Is implemented for all types implementing
+/// [`CheckInitialState`].
+/// ```
+/// use check_initial_state::eyre_report::CheckInitialStateEyre;
+/// use check_initial_state::{CheckInitialState, Violation};
+///
+/// #[derive(CheckInitialState)]
+/// struct Builder {
+///     option: Option<i32>,
+/// }
+///
+/// let error = Builder { option: Some(10) }.check_initial_state_eyre().unwrap_err();
+/// assert_eq!(error.to_string(), "`Builder` is not in initial state");
+/// let cause = error.chain().nth(1).unwrap();
+/// assert_eq!(cause.to_string(), "Field `option` has Some value instead of None");
+/// assert_eq!(error.downcast_ref::<Violation>().unwrap().field(), "option");
+/// ```
+pub trait CheckInitialStateEyre {
+	/// Checks all fields, which are not annotated with `ignore_field`, to be in
+	/// initial state.
+	/// # Errors
+	/// Any of checked fields is not in initial state. The error names the
+	/// struct, and its chain of causes consists of
+	/// [`Violation`](crate::Violation)s in the order of declaration, so
+	/// `{:?}` formatting prints each of them on a separate line.
+	fn check_initial_state_eyre(&self) -> eyre::Result<()>;
+}
+
+impl<T: CheckInitialState + ?Sized> CheckInitialStateEyre for T {
+	fn check_initial_state_eyre(&self) -> eyre::Result<()> {
+		let error = match self.try_check_initial_state() {
+			Ok(()) => return Ok(()),
+			Err(error) => error,
+		};
+		let mut violations = error.violations().iter().rev();
+		// Violations are never empty
+		let mut report = Report::msg(violations.next().unwrap().clone());
+		for violation in violations {
+			report = report.wrap_err(violation.clone());
+		}
+		return Err(report.wrap_err(format!("`{}` is not in initial state", error.struct_name())));
+	}
+}
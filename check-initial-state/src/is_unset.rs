@@ -0,0 +1,45 @@
+//! Implementations of [`IsUnset`] for common types other than `Option`, so
+//! fields of such types are checked without annotations.
+use crate::IsUnset;
+
+#[cfg(feature = "std-types")]
+impl IsUnset for std::path::PathBuf {
+	fn is_unset(&self) -> bool {
+		return self.as_os_str().is_empty();
+	}
+}
+
+#[cfg(feature = "std-types")]
+impl IsUnset for std::ffi::OsString {
+	fn is_unset(&self) -> bool {
+		return self.is_empty();
+	}
+}
+
+#[cfg(feature = "std-types")]
+impl IsUnset for std::time::Duration {
+	fn is_unset(&self) -> bool {
+		return self.is_zero();
+	}
+}
+
+#[cfg(feature = "uuid")]
+impl IsUnset for uuid::Uuid {
+	fn is_unset(&self) -> bool {
+		return self.is_nil();
+	}
+}
+
+#[cfg(feature = "bytes")]
+impl IsUnset for bytes::Bytes {
+	fn is_unset(&self) -> bool {
+		return self.is_empty();
+	}
+}
+
+#[cfg(feature = "bytes")]
+impl IsUnset for bytes::BytesMut {
+	fn is_unset(&self) -> bool {
+		return self.is_empty();
+	}
+}
@@ -0,0 +1,728 @@
+//! Runtime counterpart of
+//! [`check-initial-state-derive`](check_initial_state_derive) procedural
+//! macro. Code generated by `CheckInitialState` derive refers to the items of
+//! this crate, so it must be a dependency of a crate which uses the derive. The
+//! derive itself is re&#x2011;exported from here, so depending on this crate
+//! only is enough.
+//!
+//! Besides the inherent `check_initial_state()` method, the derive implements
+//! [`CheckInitialState`] trait. It allows to verify builders generically, e.g.
+//! by test frameworks integrations:
+//! * [`assert_initial_state!`] macro mirrors `assert!` ergonomics, naming the
+//! checked expression in the failure message.
+//! * [`IsUnset`] trait makes a custom field type checkable without
+//! annotations, like `Option` is. `uuid` and `bytes` features implement it for
+//! `uuid::Uuid`, `bytes::Bytes` and `bytes::BytesMut`.
+//! * [`CheckInitialStateExt`] trait provides `is_initial()`,
+//! `assert_initial()` and `expect_initial()` methods for all checked types, as
+//! well as `into_initial()`, which checks an instance taken by value and
+//! returns it back.
+//! * [`InitialStateObserver`] trait receives the state of each field from the
+//! generated `check_with_observer()` method, so custom frameworks control the
+//! output and the failure policy.
+//! * `googletest` feature provides `matcher::is_in_initial_state()` matcher
+//! to be used with `googletest::verify_that!`.
+//! * `validator` feature provides `validation::ValidateInitialState` trait,
+//! which reports fields, that are not in initial state, as
+//! `validator::ValidationErrors` keyed by field names.
+//! * `registry` feature collects structs annotated with `register` option, so
+//! `verify_all()` verifies all their constructors in a single call, and
+//! `check_all_initial_states!()` turns them into a test per struct.
+//! * `usage-tracking` feature records whether each struct with `track_usage`
+//! option was checked at least once, so `report_unchecked()` lists the structs a test suite never
+//! verifies.
+//! * `wasm-bindgen` feature converts [`InitialStateError`] into
+//! `wasm_bindgen::JsValue`, so browser&#x2011;targeted crates may return it
+//! to JS test harnesses with `?`.
+//! * `anyhow` and `eyre` features provide `check_initial_state_anyhow()` and
+//! `check_initial_state_eyre()` methods, which report each field that is not
+//! in initial state as a separate cause of the error. `?` converts
+//! [`InitialStateError`] into both error types without the features.
+//! * `provenance` feature records where fields were set by generated setters,
+//! so the panic of `check_initial_state()` names the file and line which
+//! assigned the stray value.
+//! # Example
+//! ```
+//! use check_initial_state::{CheckInitialState, InitialStateError};
+//!
+//! #[derive(CheckInitialState)]
+//! struct Builder {
+//!     option: Option<i32>,
+//!     #[ignore_field]
+//!     integer: i32,
+//! }
+//!
+//! fn main() {
+//!     let builder = Builder { option: Some(10), integer: 10 };
+//!     let error: InitialStateError = builder.try_check_initial_state().unwrap_err();
+//!     assert_eq!(error.violations()[0].field(), "option");
+//! }
+//! ```
+#![allow(clippy::needless_return, clippy::doc_lazy_continuation)]
+
+#[cfg(feature = "anyhow")]
+pub mod anyhow_report;
+#[cfg(feature = "eyre")]
+pub mod eyre_report;
+#[cfg(any(feature = "std-types", feature = "uuid", feature = "bytes"))]
+mod is_unset;
+#[cfg(feature = "googletest")]
+pub mod matcher;
+#[doc(hidden)]
+#[path = "private.rs"]
+pub mod __private;
+#[cfg(feature = "provenance")]
+pub mod provenance;
+#[cfg(feature = "registry")]
+pub mod registry;
+#[cfg(feature = "usage-tracking")]
+pub mod usage;
+#[cfg(feature = "validator")]
+pub mod validation;
+#[cfg(feature = "wasm-bindgen")]
+mod wasm;
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+pub use check_initial_state_derive::{
+	check_initial_state_for, CheckAnySet, CheckInitialState, CheckInvariants, CheckPhase,
+};
+#[cfg(feature = "item-macros")]
+pub use check_initial_state_derive::{
+	check_all_builders, checked_constructor, post_build_check, verify_initial_literals,
+};
+#[cfg(feature = "registry")]
+pub use registry::verify_all;
+#[cfg(feature = "usage-tracking")]
+pub use usage::report_unchecked;
+
+/// Asserts that the given expression, which implements [`CheckInitialState`],
+/// is in initial state. Unlike calling `check_initial_state()` directly, the
+/// panic message contains the stringified expression, which helps to identify
+/// the failed one among several constructed builders. Each field which is not
+/// in initial state is also spelled out as an access of the expression, like
+/// [assert2](https://crates.io/crates/assert2) expands assertions. Like
+/// `assert!`, a custom message with format arguments may be supplied after the
+/// expression.
+/// ```should_panic
+/// use check_initial_state::{assert_initial_state, CheckInitialState};
+///
+/// #[derive(CheckInitialState)]
+/// struct Builder {
+///     option: Option<i32>,
+/// }
+///
+/// assert_initial_state!(Builder { option: None });
+/// // Panics with:
+/// // assertion failed: `Builder { option: Some(10) }` is in initial state: broken
+/// //   cause: `Builder` is not in initial state: Field `option` has Some value instead of None
+/// //   with expansion:
+/// //     Builder { option: Some(10) }.option has Some value instead of None
+/// assert_initial_state!(Builder { option: Some(10) }, "{}", "broken");
+/// ```
+#[macro_export]
+macro_rules! assert_initial_state {
+	($value:expr $(,)?) => {
+		{
+			#[allow(unused_imports)]
+			use $crate::CheckInitialState as _;
+			if let ::std::result::Result::Err(error) = (&$value).try_check_initial_state() {
+				::std::panic!(
+					"assertion failed: `{}` is in initial state\n  cause: {}{}",
+					::std::stringify!($value),
+					error,
+					$crate::__private::Expansion { expression: ::std::stringify!($value), error: &error },
+				);
+			}
+		}
+	};
+	($value:expr, $($arg:tt)+) => {
+		{
+			#[allow(unused_imports)]
+			use $crate::CheckInitialState as _;
+			if let ::std::result::Result::Err(error) = (&$value).try_check_initial_state() {
+				::std::panic!(
+					"assertion failed: `{}` is in initial state: {}\n  cause: {}{}",
+					::std::stringify!($value),
+					::std::format_args!($($arg)+),
+					error,
+					$crate::__private::Expansion { expression: ::std::stringify!($value), error: &error },
+				);
+			}
+		}
+	};
+}
+
+/// Is implemented by `CheckInitialState` derive for structs which are
+/// expected to have all `Option` fields set to `None` on creation.
+pub trait CheckInitialState {
+	/// Checks all fields, which are not annotated with `ignore_field`, to be in
+	/// initial state. Unlike [`check_initial_state()`](Self::check_initial_state),
+	/// does not stop at the first such field.
+	/// # Errors
+	/// Any of checked fields is not in initial state. The error lists all such
+	/// fields.
+	fn try_check_initial_state(&self) -> Result<(), InitialStateError>;
+
+	/// Checks all fields, which are not annotated with `ignore_field`, to be in
+	/// initial state. Is expected to be used for testing purposes.
+	/// # Panics
+	/// Any of checked fields is not in initial state. Panic message will
+	/// contain the name of the first such field.
+	fn check_initial_state(&self) {
+		if let Err(error) = self.try_check_initial_state() {
+			panic!("{}", error.violations()[0]);
+		}
+	}
+}
+
+/// Convenience assertions built on [`CheckInitialState`], which offer test
+/// authors call styles of the standard library. Is implemented for all types
+/// implementing [`CheckInitialState`], so no code is generated per struct.
+/// ```should_panic
+/// use check_initial_state::{CheckInitialState, CheckInitialStateExt};
+///
+/// #[derive(CheckInitialState)]
+/// struct Builder {
+///     option: Option<i32>,
+/// }
+///
+/// assert!(Builder { option: None }.is_initial());
+/// Builder { option: None }.assert_initial();
+/// // Panics with:
+/// // fresh builder: `Builder` is not in initial state: Field `option` has Some value
+/// // instead of None
+/// Builder { option: Some(10) }.expect_initial("fresh builder");
+/// ```
+pub trait CheckInitialStateExt: CheckInitialState {
+	/// Tells whether all checked fields are in initial state, e.g. for
+	/// `assert!` or filtering.
+	fn is_initial(&self) -> bool {
+		return self.try_check_initial_state().is_ok();
+	}
+
+	/// Asserts that all checked fields are in initial state. Unlike
+	/// [`check_initial_state()`](CheckInitialState::check_initial_state), the
+	/// panic message lists all fields which are not.
+	/// # Panics
+	/// Any of checked fields is not in initial state.
+	#[track_caller]
+	fn assert_initial(&self) {
+		if let Err(error) = self.try_check_initial_state() {
+			panic!("assertion failed: {}", error);
+		}
+	}
+
+	/// Asserts that all checked fields are in initial state like
+	/// [`assert_initial()`](Self::assert_initial), but prepends `message` to
+	/// the panic message, like `Option::expect()` does.
+	/// # Panics
+	/// Any of checked fields is not in initial state.
+	#[track_caller]
+	fn expect_initial(&self, message: &str) {
+		if let Err(error) = self.try_check_initial_state() {
+			panic!("{}: {}", message, error);
+		}
+	}
+
+	/// Checks all fields, which are not annotated with `ignore_field`, to be in
+	/// initial state, taking `self` by value. So pipelines may keep chaining
+	/// the returned instance, while nothing panics:
+	/// ```
+	/// use check_initial_state::{CheckInitialState, CheckInitialStateExt};
+	///
+	/// #[derive(CheckInitialState)]
+	/// struct Builder {
+	///     option: Option<i32>,
+	/// }
+	///
+	/// let builder = Builder { option: None }.into_initial().ok().unwrap();
+	/// let (builder, error) = Builder { option: Some(1) }.into_initial().err().unwrap();
+	/// assert_eq!(builder.option, Some(1));
+	/// assert_eq!(error.violations()[0].field(), "option");
+	/// ```
+	/// # Errors
+	/// Any of checked fields is not in initial state. The instance is returned
+	/// along with the error for inspection.
+	fn into_initial(self) -> Result<Self, (Self, InitialStateError)>
+		where Self: Sized {
+		return match self.try_check_initial_state() {
+			Ok(()) => Ok(self),
+			Err(error) => Err((self, error)),
+		};
+	}
+}
+
+impl<T: CheckInitialState + ?Sized> CheckInitialStateExt for T {}
+
+/// Tells whether a field is in initial state. Fields, which are not annotated
+/// with a check attribute, are checked through this trait, so a custom field
+/// type becomes checkable by implementing a single method:
+/// ```
+/// use check_initial_state::{CheckInitialState, IsUnset};
+///
+/// struct Endpoint {
+///     url: String,
+/// }
+///
+/// impl IsUnset for Endpoint {
+///     fn is_unset(&self) -> bool {
+///         return self.url.is_empty();
+///     }
+/// }
+///
+/// #[derive(CheckInitialState)]
+/// struct Builder {
+///     option: Option<i32>,
+///     endpoint: Endpoint,
+/// }
+///
+/// let builder = Builder { option: None, endpoint: Endpoint { url: "localhost".to_owned() } };
+/// let error = builder.try_check_initial_state().unwrap_err();
+/// assert_eq!(error.violations()[0].to_string(), "Field `endpoint` is set instead of unset");
+/// ```
+///
+/// Besides `Option`, `std-types` feature implements the trait for `PathBuf`,
+/// `OsString` and `Duration`, which are unset if empty or zero. `uuid` feature
+/// implements it for `uuid::Uuid`, which is unset if nil, while `bytes` feature
+/// does for `bytes::Bytes` and `bytes::BytesMut`, which are unset if empty.
+pub trait IsUnset {
+	/// Whether the value is in initial state, e.g. `None`.
+	fn is_unset(&self) -> bool;
+}
+
+impl<T> IsUnset for Option<T> {
+	fn is_unset(&self) -> bool {
+		return self.is_none();
+	}
+}
+
+impl<T: IsUnset + ?Sized> IsUnset for &T {
+	fn is_unset(&self) -> bool {
+		return T::is_unset(self);
+	}
+}
+
+impl<T: IsUnset + ?Sized> IsUnset for &mut T {
+	fn is_unset(&self) -> bool {
+		return T::is_unset(self);
+	}
+}
+
+/// Receives the state of each checked field from `check_with_observer()`
+/// method generated by `CheckInitialState` derive. So custom test frameworks
+/// fully control the output and whether a violation fails the test. Nothing
+/// panics in the generated method itself:
+/// ```
+/// use check_initial_state::{CheckInitialState, InitialStateObserver, Violation};
+///
+/// #[derive(CheckInitialState)]
+/// struct Builder {
+///     option: Option<i32>,
+///     #[expect_false]
+///     started: bool,
+/// }
+///
+/// #[derive(Default)]
+/// struct Summary {
+///     lines: Vec<String>,
+/// }
+///
+/// impl InitialStateObserver for Summary {
+///     fn on_field(&mut self, name: &'static str, is_set: bool) {
+///         self.lines.push(format!("{}: {}", name, if is_set { "set" } else { "unset" }));
+///     }
+///
+///     fn on_violation(&mut self, violation: &Violation) {
+///         self.lines.push(format!("violation: {}", violation));
+///     }
+///
+///     fn on_complete(&mut self, struct_name: &'static str) {
+///         self.lines.push(format!("{} checked", struct_name));
+///     }
+/// }
+///
+/// let mut summary = Summary::default();
+/// Builder { option: Some(1), started: false }.check_with_observer(&mut summary);
+/// assert_eq!(
+///     summary.lines,
+///     [
+///         "option: set",
+///         "violation: Field `option` has Some value instead of None",
+///         "started: unset",
+///         "Builder checked",
+///     ],
+/// );
+/// ```
+pub trait InitialStateObserver {
+	/// Is called for each field, which is not annotated with `ignore_field`, in
+	/// the order of declaration.
+	/// # Parameters
+	/// * `name` &ndash; Name of the field as it is written in the struct, or its
+	/// `report_name` if specified.
+	/// * `is_set` &ndash; Whether the field is set, e.g. an `Option` field is
+	/// `Some`.
+	fn on_field(&mut self, name: &'static str, is_set: bool);
+
+	/// Is called right after [`on_field()`](Self::on_field) if the field is not
+	/// in initial state. Does nothing by default.
+	fn on_violation(&mut self, violation: &Violation) {
+		let _ = violation;
+	}
+
+	/// Is called once all fields are observed. Does nothing by default.
+	/// # Parameters
+	/// * `struct_name` &ndash; Name of the checked struct.
+	fn on_complete(&mut self, struct_name: &'static str) {
+		let _ = struct_name;
+	}
+}
+
+/// Describes a checked field which is not in initial state.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Violation {
+	/// Name of the field as it is written in the struct.
+	ident: &'static str,
+	/// Name of the field as it is written in the struct, or its `report_name`.
+	field: &'static str,
+	/// Describes why the field is not in initial state, e.g. `has Some value
+	/// instead of None`.
+	reason: &'static str,
+}
+
+impl Violation {
+	/// Is expected to be called by the code generated by `CheckInitialState`
+	/// derive.
+	/// # Parameters
+	/// * `field` &ndash; Name of the field as it is written in the struct, or
+	/// its `report_name` if specified.
+	/// * `reason` &ndash; Describes why the field is not in initial state.
+	// Is inlined, so `no_panic` attribute sees it never panics
+	#[inline]
+	pub fn new(field: &'static str, reason: &'static str) -> Self {
+		return Self { ident: field, field, reason };
+	}
+
+	/// Is expected to be called by the code generated by `CheckInitialState`
+	/// derive for fields which have `report_name`.
+	/// # Parameters
+	/// * `ident` &ndash; Name of the field as it is written in the struct.
+	/// * `field` &ndash; `report_name` of the field.
+	/// * `reason` &ndash; Describes why the field is not in initial state.
+	// Is inlined, so `no_panic` attribute sees it never panics
+	#[inline]
+	pub fn renamed(ident: &'static str, field: &'static str, reason: &'static str) -> Self {
+		return Self { ident, field, reason };
+	}
+
+	/// Name of the field which is not in initial state as it is written in the
+	/// struct, regardless of its `report_name`.
+	pub fn ident(&self) -> &'static str {
+		return self.ident;
+	}
+
+	/// Name of the field which is not in initial state. Is `report_name` of the
+	/// field if specified.
+	pub fn field(&self) -> &'static str {
+		return self.field;
+	}
+
+	/// Describes why the field is not in initial state.
+	pub fn reason(&self) -> &'static str {
+		return self.reason;
+	}
+}
+
+impl Display for Violation {
+	fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+		return write!(formatter, "Field `{}` {}", self.field, self.reason);
+	}
+}
+
+/// Is returned by [`CheckInitialState::try_check_initial_state()`] when some
+/// of checked fields are not in initial state.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct InitialStateError {
+	/// Name of the checked struct.
+	struct_name: &'static str,
+	/// Is never empty.
+	violations: Vec<Violation>,
+}
+
+impl InitialStateError {
+	/// Is expected to be called by the code generated by `CheckInitialState`
+	/// derive.
+	/// # Parameters
+	/// * `struct_name` &ndash; Name of the checked struct.
+	/// * `violations` &ndash; Fields which are not in initial state. Must not
+	/// be empty.
+	pub fn new(struct_name: &'static str, violations: Vec<Violation>) -> Self {
+		assert!(!violations.is_empty(), "`InitialStateError` requires at least one violation");
+		return Self { struct_name, violations };
+	}
+
+	/// Name of the struct which is not in initial state.
+	pub fn struct_name(&self) -> &'static str {
+		return self.struct_name;
+	}
+
+	/// Fields which are not in initial state, in the order of declaration.
+	pub fn violations(&self) -> &[Violation] {
+		return &self.violations;
+	}
+}
+
+impl Display for InitialStateError {
+	fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+		write!(formatter, "`{}` is not in initial state: ", self.struct_name)?;
+		for (index, violation) in self.violations.iter().enumerate() {
+			if index > 0 {
+				formatter.write_str("; ")?;
+			}
+			Display::fmt(violation, formatter)?;
+		}
+		return Ok(());
+	}
+}
+
+impl Error for InitialStateError {}
+
+/// Is the payload of panics of `check_initial_state()` generated for structs
+/// with `panic_payload` option. Tests catching the panic with
+/// [`std::panic::catch_unwind()`] may downcast the payload to assert on the
+/// violating field, instead of matching substrings of the message.
+/// ```
+/// use check_initial_state::{CheckInitialState, InitialStatePanic};
+/// use std::panic;
+///
+/// #[derive(CheckInitialState)]
+/// #[check_initial_state(panic_payload)]
+/// struct Builder {
+///     option: Option<i32>,
+/// }
+///
+/// let payload = panic::catch_unwind(|| Builder { option: Some(10) }.check_initial_state())
+///     .unwrap_err();
+/// let payload = payload.downcast_ref::<InitialStatePanic>().unwrap();
+/// assert_eq!(payload.struct_name(), "Builder");
+/// assert_eq!(payload.violation().field(), "option");
+/// ```
+/// Since the payload is not a string, the default panic hook doesn't print the
+/// message, and `#[should_panic(expected = "...")]` cannot match it.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct InitialStatePanic {
+	/// Name of the checked struct.
+	struct_name: &'static str,
+	/// The first field which is not in initial state.
+	violation: Violation,
+}
+
+impl InitialStatePanic {
+	/// Is expected to be called by the code generated by `CheckInitialState`
+	/// derive.
+	/// # Parameters
+	/// * `struct_name` &ndash; Name of the checked struct.
+	/// * `field` &ndash; Name of the field which is not in initial state.
+	/// * `reason` &ndash; Describes why the field is not in initial state.
+	pub fn new(struct_name: &'static str, field: &'static str, reason: &'static str) -> Self {
+		return Self { struct_name, violation: Violation::new(field, reason) };
+	}
+
+	/// Name of the struct which is not in initial state.
+	pub fn struct_name(&self) -> &'static str {
+		return self.struct_name;
+	}
+
+	/// The first field which is not in initial state.
+	pub fn violation(&self) -> &Violation {
+		return &self.violation;
+	}
+}
+
+impl Display for InitialStatePanic {
+	fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+		return write!(formatter, "`{}` is not in initial state: {}", self.struct_name, self.violation);
+	}
+}
+
+/// Describes a field of a struct deriving `CheckInitialState`. `fields()`
+/// associated `const fn` generated by the derive returns the metadata of all
+/// fields in the order of declaration. So coverage may be audited at build
+/// time by `const` evaluation:
+/// ```
+/// use check_initial_state::CheckInitialState;
+///
+/// #[derive(CheckInitialState)]
+/// struct Builder {
+///     #[field_group = "network"]
+///     host: Option<String>,
+///     #[ignore_field(reason = "Is set by the scheduler")]
+///     attempt: u32,
+/// }
+///
+/// const _: () = {
+///     let fields = Builder::fields();
+///     let mut index = 0;
+///     while index < fields.len() {
+///         assert!(!fields[index].ignored() || fields[index].reason().is_some());
+///         index += 1;
+///     }
+/// };
+///
+/// assert_eq!(Builder::fields()[0].group(), Some("network"));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FieldMeta {
+	/// Name of the field as it is written in the struct.
+	name: &'static str,
+	/// Whether the field is annotated with `ignore_field`, or is ignored
+	/// otherwise, e.g. by `ignore_types` option.
+	ignored: bool,
+	/// Why the field is ignored.
+	reason: Option<&'static str>,
+	/// Group specified with `field_group` attribute.
+	group: Option<&'static str>,
+}
+
+impl FieldMeta {
+	/// Is expected to be called by the code generated by `CheckInitialState`
+	/// derive.
+	/// # Parameters
+	/// * `name` &ndash; Name of the field as it is written in the struct.
+	/// * `ignored` &ndash; Whether the field is not checked.
+	/// * `reason` &ndash; Why the field is ignored.
+	/// * `group` &ndash; Group specified with `field_group` attribute.
+	pub const fn new(
+		name: &'static str,
+		ignored: bool,
+		reason: Option<&'static str>,
+		group: Option<&'static str>,
+	) -> Self {
+		return Self { name, ignored, reason, group };
+	}
+
+	/// Name of the field as it is written in the struct.
+	pub const fn name(&self) -> &'static str {
+		return self.name;
+	}
+
+	/// Whether the field is not checked, e.g. it is annotated with
+	/// `ignore_field`.
+	pub const fn ignored(&self) -> bool {
+		return self.ignored;
+	}
+
+	/// Why the field is ignored, if the reason is known, e.g. specified with
+	/// `#[ignore_field(reason = "...")]`. Is `None` for checked fields.
+	pub const fn reason(&self) -> Option<&'static str> {
+		return self.reason;
+	}
+
+	/// Group specified with `#[field_group = "..."]`, if any.
+	pub const fn group(&self) -> Option<&'static str> {
+		return self.group;
+	}
+}
+
+/// Is returned by `FromStr` implementation of the field enum generated by
+/// `field_enum` option, when the parsed name is not a name of a checked field.
+/// ```
+/// use check_initial_state::CheckInitialState;
+///
+/// #[derive(CheckInitialState)]
+/// #[check_initial_state(field_enum)]
+/// struct Builder {
+///     option: Option<i32>,
+/// }
+///
+/// assert_eq!("option".parse::<BuilderField>(), Ok(BuilderField::Option));
+/// let error = "other".parse::<BuilderField>().unwrap_err();
+/// assert_eq!(error.to_string(), "`Builder` has no checked field `other`");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct UnknownFieldError {
+	/// Name of the struct which field enum is parsed.
+	struct_name: &'static str,
+	/// The parsed name.
+	name: String,
+}
+
+impl UnknownFieldError {
+	/// Is expected to be called by the code generated by `CheckInitialState`
+	/// derive.
+	/// # Parameters
+	/// * `struct_name` &ndash; Name of the struct which field enum is parsed.
+	/// * `name` &ndash; The parsed name.
+	pub fn new(struct_name: &'static str, name: &str) -> Self {
+		return Self { struct_name, name: name.to_owned() };
+	}
+
+	/// Name of the struct which field enum is parsed.
+	pub fn struct_name(&self) -> &'static str {
+		return self.struct_name;
+	}
+
+	/// The parsed name, which is not a name of a checked field.
+	pub fn name(&self) -> &str {
+		return &self.name;
+	}
+}
+
+impl Display for UnknownFieldError {
+	fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+		return write!(formatter, "`{}` has no checked field `{}`", self.struct_name, self.name);
+	}
+}
+
+impl Error for UnknownFieldError {}
+
+/// Is returned by a finalizer annotated with `#[post_build_check(map_err)]`,
+/// e.g. `build()`, when some of checked fields of the builder are not set. Is
+/// converted into the error type of the finalizer with `From`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MissingFieldsError {
+	/// Name of the builder as [`std::any::type_name()`] reports it.
+	builder: &'static str,
+	/// Is never empty.
+	fields: Vec<&'static str>,
+}
+
+impl MissingFieldsError {
+	/// Is expected to be called by the code generated by `post_build_check`
+	/// attribute.
+	/// # Parameters
+	/// * `builder` &ndash; Name of the builder.
+	/// * `fields` &ndash; Fields which are not set. Must not be empty.
+	pub fn new(builder: &'static str, fields: Vec<&'static str>) -> Self {
+		assert!(!fields.is_empty(), "`MissingFieldsError` requires at least one field");
+		return Self { builder, fields };
+	}
+
+	/// Name of the builder as [`std::any::type_name()`] reports it, e.g.
+	/// `my_crate::ServerBuilder`.
+	pub fn builder(&self) -> &'static str {
+		return self.builder;
+	}
+
+	/// Fields which are not set, in the order of declaration. Are
+	/// `report_name` of the fields if specified.
+	pub fn fields(&self) -> &[&'static str] {
+		return &self.fields;
+	}
+}
+
+impl Display for MissingFieldsError {
+	fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+		write!(formatter, "`{}` is not completely built, unset fields: ", self.builder)?;
+		for (index, field) in self.fields.iter().enumerate() {
+			if index > 0 {
+				formatter.write_str(", ")?;
+			}
+			write!(formatter, "`{}`", field)?;
+		}
+		return Ok(());
+	}
+}
+
+impl Error for MissingFieldsError {}
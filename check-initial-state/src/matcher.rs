@@ -0,0 +1,65 @@
+//! [googletest](https://crates.io/crates/googletest) integration.
+use crate::CheckInitialState;
+use googletest::description::Description;
+use googletest::matcher::{Matcher, MatcherBase, MatcherResult};
+use std::fmt::Debug;
+
+/// Matches a value which is in initial state according to
+/// [`CheckInitialState`]. On mismatch, all fields which are not in initial
+/// state are listed.
+/// ```
+/// use check_initial_state::matcher::is_in_initial_state;
+/// use check_initial_state::CheckInitialState;
+/// use googletest::prelude::*;
+///
+/// #[derive(CheckInitialState, Debug)]
+/// struct Builder {
+///     option: Option<i32>,
+/// }
+///
+/// # fn main() -> googletest::Result<()> {
+/// verify_that!(Builder { option: None }, is_in_initial_state())?;
+/// verify_that!(Builder { option: Some(10) }, not(is_in_initial_state()))?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn is_in_initial_state() -> InitialStateMatcher {
+	return InitialStateMatcher { _private: () };
+}
+
+/// Is created by [`is_in_initial_state()`].
+pub struct InitialStateMatcher {
+	/// Prevents construction outside of this module.
+	_private: (),
+}
+
+impl MatcherBase for InitialStateMatcher {}
+
+impl<'a, T> Matcher<&'a T> for InitialStateMatcher
+	where T: CheckInitialState + Debug + ?Sized {
+	fn matches(&self, actual: &'a T) -> MatcherResult {
+		return actual.try_check_initial_state().is_ok().into();
+	}
+
+	fn describe(&self, matcher_result: MatcherResult) -> Description {
+		return match matcher_result {
+			MatcherResult::Match => "is in initial state".into(),
+			MatcherResult::NoMatch => "isn't in initial state".into(),
+		};
+	}
+
+	fn explain_match(&self, actual: &'a T) -> Description {
+		return match actual.try_check_initial_state() {
+			Ok(()) => "which is in initial state".into(),
+			Err(error) => {
+				let violations = error.violations().iter()
+					.map(|violation| violation.to_string())
+					.collect::<Description>()
+					.bullet_list();
+				let header =
+					format!("which is `{}` with fields not in initial state:", error.struct_name());
+				Description::new().text(header).nested(violations)
+			},
+		};
+	}
+}
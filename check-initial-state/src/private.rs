@@ -0,0 +1,371 @@
+//! Items used by the code generated by `CheckInitialState` derive. Not a public
+//! API.
+//!
+//! `lenient` mode checks fields without knowing whether they are `Option`
+//! using autoref specialization: a method call on `&Probe` resolves to
+//! `OptionProbe` implementation if the probed field is `Option`, since it
+//! requires no autoref. Otherwise the call falls back to `OtherProbe`
+//! implementation for `&&Probe`, which treats the field as being in initial
+//! state.
+
+/// Wraps a reference to a field which is checked in `lenient` mode.
+pub struct Probe<'a, T>(pub &'a T);
+
+/// Checks an `Option` field.
+pub trait OptionProbe {
+	/// Whether the field is `Some`.
+	fn is_set(&self) -> bool;
+}
+
+impl<T> OptionProbe for Probe<'_, Option<T>> {
+	fn is_set(&self) -> bool {
+		return self.0.is_some();
+	}
+}
+
+/// Skips a non&#x2011;`Option` field.
+pub trait OtherProbe {
+	/// Always `false`.
+	fn is_set(&self) -> bool;
+}
+
+impl<T> OtherProbe for &Probe<'_, T> {
+	fn is_set(&self) -> bool {
+		return false;
+	}
+}
+
+/// Checks a field in `auto_unset` mode. Is implemented for types implementing
+/// [`crate::IsUnset`], and for common standard types, which are unset if they
+/// are `false`, zero or empty.
+pub trait AutoUnset {
+	/// Whether the field is in initial state.
+	fn is_auto_unset(&self) -> bool;
+}
+
+impl<T: crate::IsUnset + ?Sized> AutoUnset for T {
+	fn is_auto_unset(&self) -> bool {
+		return self.is_unset();
+	}
+}
+
+impl AutoUnset for bool {
+	fn is_auto_unset(&self) -> bool {
+		return !*self;
+	}
+}
+
+macro_rules! impl_auto_unset_number {
+	($($number:ty),*) => {
+		$(
+			impl AutoUnset for $number {
+				fn is_auto_unset(&self) -> bool {
+					return Zero::is_zero(self);
+				}
+			}
+		)*
+	};
+}
+
+impl_auto_unset_number!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+/// Implements [`AutoUnset`] for collections, which are unset if empty. Type
+/// parameters of a collection are listed in brackets before it.
+macro_rules! impl_auto_unset_collection {
+	($([$($parameter:ident),*] $collection:ty;)*) => {
+		$(
+			impl<$($parameter),*> AutoUnset for $collection {
+				fn is_auto_unset(&self) -> bool {
+					return self.is_empty();
+				}
+			}
+		)*
+	};
+}
+
+impl_auto_unset_collection!(
+	[] String;
+	[T] Vec<T>;
+	[T] std::collections::VecDeque<T>;
+	[T] std::collections::LinkedList<T>;
+	[T] std::collections::BinaryHeap<T>;
+	[K, V, S] std::collections::HashMap<K, V, S>;
+	[T, S] std::collections::HashSet<T, S>;
+	[K, V] std::collections::BTreeMap<K, V>;
+	[T] std::collections::BTreeSet<T>;
+);
+
+/// Checks a numeric field annotated with `expect_zero`. Unlike comparison with
+/// `0 as _`, doesn't depend on type inference, which is ambiguous if other
+/// crates implement `PartialEq` between numbers and their types.
+pub trait Zero {
+	/// Whether the value is zero.
+	fn is_zero(&self) -> bool;
+}
+
+macro_rules! impl_zero {
+	($($number:ty),*) => {
+		$(
+			impl Zero for $number {
+				fn is_zero(&self) -> bool {
+					return *self == 0 as $number;
+				}
+			}
+		)*
+	};
+}
+
+impl_zero!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+/// Wraps a reference to a field, which value is printed in a state table if
+/// the field implements `Debug`.
+#[cfg(feature = "table")]
+pub struct DebugProbe<'a, T>(pub &'a T);
+
+/// Prints a field implementing `Debug`.
+#[cfg(feature = "table")]
+pub trait DebugValue {
+	/// The field formatted with `Debug`, which is truncated to `max_len`
+	/// characters followed by an ellipsis if longer.
+	fn debug_value(&self, max_len: usize) -> Option<String>;
+}
+
+#[cfg(feature = "table")]
+impl<T: std::fmt::Debug> DebugValue for DebugProbe<'_, T> {
+	fn debug_value(&self, max_len: usize) -> Option<String> {
+		let mut value = format!("{:?}", self.0);
+		if let Some((end, _)) = value.char_indices().nth(max_len) {
+			value.truncate(end);
+			value.push('\u{2026}');
+		}
+		return Some(value);
+	}
+}
+
+/// Skips a field which doesn't implement `Debug`.
+#[cfg(feature = "table")]
+pub trait OtherDebugValue {
+	/// Always `None`.
+	fn debug_value(&self, max_len: usize) -> Option<String>;
+}
+
+#[cfg(feature = "table")]
+impl<T> OtherDebugValue for &DebugProbe<'_, T> {
+	fn debug_value(&self, _max_len: usize) -> Option<String> {
+		return None;
+	}
+}
+
+/// Table of all checked fields of an instance, which is appended to panic
+/// messages with `table` feature, or rendered by `Display` implementation of
+/// `derive_display` option. Columns are aligned, and fields which are not in
+/// initial state are marked with `*`.
+pub struct StateTable {
+	/// Name of the checked struct.
+	struct_name: &'static str,
+	/// Rows in the order of fields declaration.
+	rows: Vec<StateRow>,
+}
+
+/// Row of [`StateTable`] describing a single field.
+struct StateRow {
+	/// Name of the field.
+	field: &'static str,
+	/// Current state of the field, e.g. `Some` or `false`.
+	state: &'static str,
+	/// Whether the field is not in initial state.
+	violating: bool,
+	/// The field formatted with `Debug`, if values are printed and the field
+	/// implements `Debug`.
+	value: Option<String>,
+}
+
+impl StateTable {
+	/// Creates an empty table of `struct_name` fields.
+	pub fn new(struct_name: &'static str) -> Self {
+		return Self { struct_name, rows: Vec::new() };
+	}
+
+	/// Adds a row for a field.
+	/// # Parameters
+	/// * `field` &ndash; Name of the field.
+	/// * `state` &ndash; Current state of the field, e.g. `Some` or `false`.
+	/// * `violating` &ndash; Whether the field is not in initial state.
+	/// * `value` &ndash; The field formatted with `Debug`, if values are
+	/// printed and the field implements `Debug`.
+	pub fn row(
+		&mut self,
+		field: &'static str,
+		state: &'static str,
+		violating: bool,
+		value: Option<String>,
+	) {
+		self.rows.push(StateRow { field, state, violating, value });
+	}
+}
+
+impl std::fmt::Display for StateTable {
+	fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let field_width = self.rows.iter().map(|row| row.field.chars().count()).max().unwrap_or(0);
+		let state_width = self.rows.iter().map(|row| row.state.chars().count()).max().unwrap_or(0);
+		write!(formatter, "Fields of `{}`:", self.struct_name)?;
+		for row in self.rows.iter() {
+			let marker = if row.violating { '*' } else { ' ' };
+			write!(formatter, "\n  {} {:<2$} | ", marker, row.field, field_width)?;
+			match &row.value {
+				Some(value) => write!(formatter, "{:<1$} | {2}", row.state, state_width, value)?,
+				None => formatter.write_str(row.state)?,
+			}
+		}
+		return Ok(());
+	}
+}
+
+/// Tells whether `names` contains `name`. Is evaluated in `const` items
+/// generated by `verify_initial_literals` and `inherit_checks` attributes,
+/// where `==` of strings is not available.
+pub const fn contains_field(names: &[&str], name: &str) -> bool {
+	let name = name.as_bytes();
+	let mut index = 0;
+	while index < names.len() {
+		let candidate = names[index].as_bytes();
+		if candidate.len() == name.len() {
+			let mut byte = 0;
+			while byte < name.len() && candidate[byte] == name[byte] {
+				byte += 1;
+			}
+			if byte == name.len() {
+				return true;
+			}
+		}
+		index += 1;
+	}
+	return false;
+}
+
+/// Is appended to the panic message of [`crate::assert_initial_state!`] to
+/// spell out each field which is not in initial state as an access of the
+/// asserted expression, e.g. `make_builder(cfg).option`, like `assert2` crate
+/// expands assertions.
+pub struct Expansion<'a> {
+	/// Stringified asserted expression.
+	pub expression: &'static str,
+	/// Error of the failed check.
+	pub error: &'a crate::InitialStateError,
+}
+
+impl std::fmt::Display for Expansion<'_> {
+	fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		// A prefix operator would apply to the field access otherwise
+		let parenthesized = self.expression.starts_with(['&', '*', '!', '-']);
+		formatter.write_str("\n  with expansion:")?;
+		for violation in self.error.violations() {
+			if parenthesized {
+				write!(formatter, "\n    ({}).{} ", self.expression, violation.ident())?;
+			} else {
+				write!(formatter, "\n    {}.{} ", self.expression, violation.ident())?;
+			}
+			formatter.write_str(violation.reason())?;
+		}
+		return Ok(());
+	}
+}
+
+/// Is appended to a panic message to name the location where the violating
+/// field was set, if it is recorded by [`crate::provenance::Provenance`].
+#[cfg(feature = "provenance")]
+pub struct LocationNote(pub Option<&'static std::panic::Location<'static>>);
+
+#[cfg(feature = "provenance")]
+impl std::fmt::Display for LocationNote {
+	fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		return match self.0 {
+			Some(location) => write!(formatter, ", set at {}", location),
+			None => Ok(()),
+		};
+	}
+}
+
+/// Writes the wrapped text as the contents of a JSON string, escaping quotes,
+/// backslashes and control characters. Is used by `initial_state_report_json()`,
+/// since reasons of `expect_matches` checks and of `smart-default` defaults
+/// contain text of the attributes.
+pub struct JsonString<'a>(pub &'a str);
+
+impl std::fmt::Display for JsonString<'_> {
+	fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		for character in self.0.chars() {
+			match character {
+				'"' => formatter.write_str("\\\"")?,
+				'\\' => formatter.write_str("\\\\")?,
+				'\n' => formatter.write_str("\\n")?,
+				'\r' => formatter.write_str("\\r")?,
+				'\t' => formatter.write_str("\\t")?,
+				character if character.is_control() => {
+					write!(formatter, "\\u{:04x}", u32::from(character))?;
+				},
+				character => std::fmt::Write::write_char(formatter, character)?,
+			}
+		}
+		return Ok(());
+	}
+}
+
+/// Registers structs deriving `CheckInitialState` with constructors in
+/// `registry` module, and all of them in `usage` module.
+#[cfg(any(feature = "registry", feature = "usage-tracking"))]
+pub use linkme;
+
+/// Expands to the registration of a struct generated for `register` option.
+/// Reports a compile error instead if `registry` feature of this crate is
+/// disabled, since the registration refers to items it provides.
+#[cfg(feature = "registry")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __check_initial_state_registered {
+	($($tokens:tt)*) => {
+		$($tokens)*
+	};
+}
+
+/// Expands to the registration of a struct generated for `register` option.
+/// Reports a compile error instead if `registry` feature of this crate is
+/// disabled, since the registration refers to items it provides.
+#[cfg(not(feature = "registry"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __check_initial_state_registered {
+	($($tokens:tt)*) => {
+		::std::compile_error!(
+			"`register` option requires `registry` feature of `check-initial-state`"
+		);
+	};
+}
+
+pub use crate::__check_initial_state_registered as registered;
+
+/// Expands to the tracking static of a struct generated for `track_usage`
+/// option, or to marking it checked. Expands to nothing if `usage-tracking`
+/// feature of this crate is disabled, so structs are tracked in test builds
+/// only if the feature is enabled in `dev-dependencies`.
+#[cfg(feature = "usage-tracking")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __check_initial_state_usage_tracked {
+	($($tokens:tt)*) => {
+		$($tokens)*
+	};
+}
+
+/// Expands to the tracking static of a struct generated for `track_usage`
+/// option, or to marking it checked. Expands to nothing if `usage-tracking`
+/// feature of this crate is disabled, so structs are tracked in test builds
+/// only if the feature is enabled in `dev-dependencies`.
+#[cfg(not(feature = "usage-tracking"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __check_initial_state_usage_tracked {
+	($($tokens:tt)*) => {};
+}
+
+pub use crate::__check_initial_state_usage_tracked as usage_tracked;
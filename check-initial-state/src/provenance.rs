@@ -0,0 +1,83 @@
+//! Records where fields of a builder were set, so the panic of
+//! `check_initial_state()` names the file and line which assigned the stray
+//! value, not just the field holding it.
+//!
+//! A struct opts in by declaring a field of [`Provenance`] type annotated with
+//! `provenance` attribute. The derive then generates a `set_<field>_tracked()`
+//! method per checked field, which assigns the field and records the caller
+//! location:
+//! ```
+//! use check_initial_state::provenance::Provenance;
+//! use check_initial_state::CheckInitialState;
+//!
+//! #[derive(CheckInitialState, Default)]
+//! struct Builder {
+//!     option: Option<i32>,
+//!     #[provenance]
+//!     provenance: Provenance,
+//! }
+//!
+//! impl Builder {
+//!     #[track_caller]
+//!     fn option(&mut self, option: i32) {
+//!         self.set_option_tracked(Some(option));
+//!     }
+//! }
+//!
+//! let mut builder = Builder::default();
+//! builder.option(10);
+//! assert!(builder.provenance.location("option").is_some());
+//! ```
+
+use std::hash::{Hash, Hasher};
+use std::panic::Location;
+
+/// Locations where fields of a struct were last set by generated
+/// `set_<field>_tracked()` methods. Is not a part of the struct state, so all
+/// instances are equal and hash the same, and a clone keeps the locations.
+#[derive(Clone, Debug, Default)]
+pub struct Provenance {
+	/// Pairs of a field name and the location where it was last set.
+	locations: Vec<(&'static str, &'static Location<'static>)>,
+}
+
+impl Provenance {
+	/// Creates provenance with no locations recorded.
+	pub fn new() -> Self {
+		return Self::default();
+	}
+
+	/// Records `location` as the one where `field` was set, replacing the
+	/// previously recorded one.
+	/// # Parameters
+	/// * `field` &ndash; Name of the field as it is written in the struct, or
+	/// its `report_name` if specified.
+	pub fn record(&mut self, field: &'static str, location: &'static Location<'static>) {
+		match self.locations.iter_mut().find(|(name, _)| *name == field) {
+			Some((_, recorded)) => *recorded = location,
+			None => self.locations.push((field, location)),
+		}
+	}
+
+	/// Location where `field` was last set, if recorded.
+	pub fn location(&self, field: &str) -> Option<&'static Location<'static>> {
+		return self.locations.iter().find(|(name, _)| *name == field).map(|(_, location)| *location);
+	}
+
+	/// Forgets all recorded locations, e.g. when a builder is reset.
+	pub fn clear(&mut self) {
+		self.locations.clear();
+	}
+}
+
+impl PartialEq for Provenance {
+	fn eq(&self, _: &Self) -> bool {
+		return true;
+	}
+}
+
+impl Eq for Provenance {}
+
+impl Hash for Provenance {
+	fn hash<H: Hasher>(&self, _: &mut H) {}
+}
@@ -0,0 +1,180 @@
+//! Registry of structs deriving `CheckInitialState` with `register` option.
+//! Allows a single test to verify every constructor of the crate with
+//! [`verify_all()`], or a test per struct with [`check_all_initial_states!`](crate::check_all_initial_states).
+use crate::InitialStateError;
+use std::env;
+use std::io::{self, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::process;
+
+/// Struct deriving `CheckInitialState`, which is registered at link time along
+/// with the hook verifying its constructors. Is created by the code generated
+/// for a struct with `register` option.
+pub struct RegisteredCheck {
+	/// Name of the registered struct.
+	struct_name: &'static str,
+	/// Constructs an instance with each constructor and checks it.
+	verify: fn() -> Result<(), InitialStateError>,
+}
+
+impl RegisteredCheck {
+	/// Is expected to be called by the code generated by `CheckInitialState`
+	/// derive.
+	/// # Parameters
+	/// * `struct_name` &ndash; Name of the registered struct.
+	/// * `verify` &ndash; Constructs an instance with each constructor and
+	/// checks it, returning the first error.
+	pub const fn new(struct_name: &'static str, verify: fn() -> Result<(), InitialStateError>)
+		-> Self {
+		return Self { struct_name, verify };
+	}
+
+	/// Name of the registered struct.
+	pub fn struct_name(&self) -> &'static str {
+		return self.struct_name;
+	}
+
+	/// Constructs an instance with each constructor of the registered struct
+	/// and checks it.
+	/// # Errors
+	/// An instance is not in initial state.
+	pub fn verify(&self) -> Result<(), InitialStateError> {
+		return (self.verify)();
+	}
+}
+
+/// All registered structs of the binary, in unspecified order. Each struct
+/// contributes an entry from the code generated by `CheckInitialState` derive,
+/// and the linker collects the entries, so no registration call is needed.
+#[linkme::distributed_slice]
+pub static REGISTERED_CHECKS: [RegisteredCheck];
+
+/// Iterates over all registered structs of the binary, in unspecified order.
+pub fn registered_checks() -> impl Iterator<Item = &'static RegisteredCheck> {
+	return REGISTERED_CHECKS.iter();
+}
+
+/// Constructs an instance with each constructor of every registered struct,
+/// and checks it to be in initial state. Is intended to be called in a single
+/// test, which covers all constructors of the crate.
+/// ```
+/// use check_initial_state::CheckInitialState;
+///
+/// #[derive(CheckInitialState)]
+/// #[check_initial_state(constructor = "Self::new", register)]
+/// struct Builder {
+///     option: Option<i32>,
+/// }
+///
+/// impl Builder {
+///     fn new() -> Self {
+///         Self { option: None }
+///     }
+/// }
+///
+/// check_initial_state::verify_all();
+/// ```
+/// # Panics
+/// Any constructed instance is not in initial state. Panic message lists all
+/// such structs.
+#[track_caller]
+pub fn verify_all() {
+	let errors = registered_checks()
+		.filter_map(|check| check.verify().err())
+		.map(|error| error.to_string())
+		.collect::<Vec<_>>();
+	if !errors.is_empty() {
+		panic!("{}", errors.join("\n"));
+	}
+}
+
+/// Expands into `main()` of a test target, which runs a test per registered
+/// struct named after it, so the test runner reports failures per struct rather
+/// than as a single test. The target must disable the default harness in
+/// `Cargo.toml`:
+/// ```toml
+/// [[test]]
+/// name = "initial_states"
+/// harness = false
+/// ```
+/// Then `tests/initial_states.rs` consists of the macro call only:
+/// ```
+/// check_initial_state::check_all_initial_states!();
+/// ```
+/// Like the default harness, the first argument not starting with `-` filters
+/// the tests by a substring of the struct name, e.g.
+/// `cargo test --test initial_states Server`.
+#[macro_export]
+macro_rules! check_all_initial_states {
+	() => {
+		fn main() {
+			$crate::registry::run_harness();
+		}
+	};
+}
+
+/// Runs a test per registered struct, printing the results in the format of
+/// the default test harness, and exits the process with failure status if any
+/// test fails. Is expected to be called by [`check_all_initial_states!`](crate::check_all_initial_states).
+pub fn run_harness() {
+	let filter = env::args().skip(1).find(|arg| !arg.starts_with('-'));
+	let stdout = io::stdout();
+	let passed = run_tests(&REGISTERED_CHECKS, filter.as_deref(), &mut stdout.lock())
+		.expect("Failed to write test results");
+	if !passed {
+		process::exit(101);
+	}
+}
+
+/// Runs a test per struct of `checks`, which name contains `filter`, and
+/// writes the results into `output` in the format of the default test harness.
+/// A constructor panic fails the test of its struct only. Returns whether all
+/// the run tests passed.
+/// # Parameters
+/// * `checks` &ndash; Structs to test.
+/// * `filter` &ndash; Substring of the names of structs to test. All structs
+/// are tested if `None`.
+/// * `output` &ndash; Destination of the results.
+/// # Errors
+/// Writing into `output` fails.
+pub fn run_tests(checks: &[RegisteredCheck], filter: Option<&str>, output: &mut dyn Write)
+	-> io::Result<bool> {
+	let selected = checks.iter()
+		.filter(|check| filter.is_none_or(|filter| check.struct_name().contains(filter)))
+		.collect::<Vec<_>>();
+	writeln!(output, "\nrunning {} tests", selected.len())?;
+	let mut failures = Vec::new();
+	for check in selected.iter() {
+		let result = match panic::catch_unwind(AssertUnwindSafe(|| check.verify())) {
+			Ok(Ok(())) => Ok(()),
+			Ok(Err(error)) => Err(error.to_string()),
+			Err(_) => Err("constructor panicked".to_owned()),
+		};
+		match result {
+			Ok(()) => writeln!(output, "test {} ... ok", check.struct_name())?,
+			Err(message) => {
+				writeln!(output, "test {} ... FAILED", check.struct_name())?;
+				failures.push((check.struct_name(), message));
+			},
+		}
+	}
+	if !failures.is_empty() {
+		writeln!(output, "\nfailures:\n")?;
+		for (struct_name, message) in failures.iter() {
+			writeln!(output, "---- {} ----\n{}\n", struct_name, message)?;
+		}
+		writeln!(output, "failures:")?;
+		for (struct_name, _) in failures.iter() {
+			writeln!(output, "    {}", struct_name)?;
+		}
+	}
+	writeln!(
+		output,
+		"\ntest result: {}. {} passed; {} failed; 0 ignored; 0 measured; {} filtered out\n",
+		if failures.is_empty() { "ok" } else { "FAILED" },
+		selected.len() - failures.len(),
+		failures.len(),
+		checks.len() - selected.len(),
+	)?;
+	return Ok(failures.is_empty());
+}
@@ -0,0 +1,84 @@
+//! Tracks which structs deriving `CheckInitialState` with `track_usage` option
+//! were actually checked during the process. Is intended for test builds only,
+//! e.g. by enabling `usage-tracking` feature in `dev-dependencies`, so a final
+//! test may flag builders which derive the macro but are never verified:
+//! ```
+//! use check_initial_state::CheckInitialState;
+//!
+//! #[derive(CheckInitialState)]
+//! #[check_initial_state(track_usage)]
+//! struct Checked {
+//!     option: Option<i32>,
+//! }
+//!
+//! #[derive(CheckInitialState)]
+//! #[check_initial_state(track_usage)]
+//! struct Forgotten {
+//!     option: Option<i32>,
+//! }
+//!
+//! Checked { option: None }.check_initial_state();
+//! assert!(check_initial_state::report_unchecked().contains(&"Forgotten"));
+//! assert!(!check_initial_state::report_unchecked().contains(&"Checked"));
+//! ```
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Struct deriving `CheckInitialState`, which is registered at link time along
+/// with the flag telling whether it was checked. Is created by the code
+/// generated for each struct with `track_usage` option.
+pub struct TrackedStruct {
+	/// Name of the tracked struct.
+	struct_name: &'static str,
+	/// Whether `check_initial_state()` or `try_check_initial_state()` of the
+	/// struct was called at least once.
+	checked: AtomicBool,
+}
+
+impl TrackedStruct {
+	/// Is expected to be called by the code generated by `CheckInitialState`
+	/// derive.
+	/// # Parameters
+	/// * `struct_name` &ndash; Name of the tracked struct.
+	pub const fn new(struct_name: &'static str) -> Self {
+		return Self { struct_name, checked: AtomicBool::new(false) };
+	}
+
+	/// Name of the tracked struct.
+	pub fn struct_name(&self) -> &'static str {
+		return self.struct_name;
+	}
+
+	/// Tells whether the struct was checked at least once.
+	pub fn is_checked(&self) -> bool {
+		return self.checked.load(Ordering::Relaxed);
+	}
+
+	/// Records that the struct was checked. Is expected to be called by the
+	/// generated checks.
+	pub fn mark_checked(&self) {
+		self.checked.store(true, Ordering::Relaxed);
+	}
+}
+
+/// All tracked structs of the binary, in unspecified order. Each struct
+/// contributes an entry from the code generated by `CheckInitialState` derive,
+/// and the linker collects the entries, so no registration call is needed.
+#[linkme::distributed_slice]
+pub static TRACKED_STRUCTS: [TrackedStruct];
+
+/// Iterates over all tracked structs of the binary, in unspecified order.
+pub fn tracked_structs() -> impl Iterator<Item = &'static TrackedStruct> {
+	return TRACKED_STRUCTS.iter();
+}
+
+/// Names of the tracked structs, which were not checked so far, sorted
+/// alphabetically. Is intended to be called by a test running after all the
+/// others, since tests checking the structs may not have run yet.
+pub fn report_unchecked() -> Vec<&'static str> {
+	let mut names = tracked_structs()
+		.filter(|tracked| !tracked.is_checked())
+		.map(TrackedStruct::struct_name)
+		.collect::<Vec<_>>();
+	names.sort_unstable();
+	return names;
+}
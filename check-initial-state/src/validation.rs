@@ -0,0 +1,53 @@
+//! [validator](https://crates.io/crates/validator) integration.
+use crate::{CheckInitialState, InitialStateError};
+use std::borrow::Cow;
+use validator::{ValidationError, ValidationErrors};
+
+/// Code of [`ValidationError`] reported for each field which is not in
+/// initial state.
+pub const INITIAL_STATE_CODE: &str = "initial_state";
+
+/// Runs the check of [`CheckInitialState`] as a part of a validation pipeline.
+/// Is implemented for all types implementing [`CheckInitialState`].
+/// ```
+/// use check_initial_state::validation::{ValidateInitialState, INITIAL_STATE_CODE};
+/// use check_initial_state::CheckInitialState;
+///
+/// #[derive(CheckInitialState)]
+/// struct Builder {
+///     option: Option<i32>,
+/// }
+///
+/// let errors = Builder { option: Some(10) }.validate_initial_state().unwrap_err();
+/// let field_errors = errors.field_errors();
+/// assert_eq!(field_errors["option"][0].code, INITIAL_STATE_CODE);
+/// ```
+pub trait ValidateInitialState {
+	/// Checks all fields, which are not annotated with `ignore_field`, to be in
+	/// initial state.
+	/// # Errors
+	/// Any of checked fields is not in initial state. The errors are keyed by
+	/// the names of such fields.
+	fn validate_initial_state(&self) -> Result<(), ValidationErrors>;
+}
+
+impl<T: CheckInitialState + ?Sized> ValidateInitialState for T {
+	fn validate_initial_state(&self) -> Result<(), ValidationErrors> {
+		return self.try_check_initial_state().map_err(ValidationErrors::from);
+	}
+}
+
+/// Keys a [`ValidationError`] by the name of each field which is not in initial
+/// state. The error has [`INITIAL_STATE_CODE`] code and the violation
+/// description as the message.
+impl From<InitialStateError> for ValidationErrors {
+	fn from(error: InitialStateError) -> Self {
+		let mut errors = ValidationErrors::new();
+		for violation in error.violations() {
+			let field_error = ValidationError::new(INITIAL_STATE_CODE)
+				.with_message(Cow::Owned(violation.to_string()));
+			errors.add(violation.field(), field_error);
+		}
+		return errors;
+	}
+}
@@ -0,0 +1,18 @@
+//! [wasm-bindgen](https://crates.io/crates/wasm-bindgen) integration.
+use crate::InitialStateError;
+use wasm_bindgen::{JsError, JsValue};
+
+/// Converts the error into JS `Error`, which message is the error description.
+/// So browser test harnesses report which fields are not in initial state:
+/// ```ignore
+/// #[wasm_bindgen]
+/// pub fn build() -> Result<(), JsValue> {
+///     Builder::new().try_check_initial_state()?;
+///     Ok(())
+/// }
+/// ```
+impl From<InitialStateError> for JsValue {
+	fn from(error: InitialStateError) -> Self {
+		return JsError::new(&error.to_string()).into();
+	}
+}
@@ -0,0 +1,46 @@
+#![cfg(feature = "anyhow")]
+
+use check_initial_state::anyhow_report::CheckInitialStateAnyhow;
+use check_initial_state::{CheckInitialState, InitialStateError, Violation};
+
+/// Checks that a struct in initial state passes the check.
+#[test]
+fn check_passed() {
+	assert!(Builder { option: None, enabled: false }.check_initial_state_anyhow().is_ok());
+}
+
+/// Checks that the error names the struct and lists violations as causes in
+/// the order of declaration.
+#[test]
+fn violations_reported_as_causes() {
+	let error =
+		Builder { option: Some(10), enabled: true }.check_initial_state_anyhow().unwrap_err();
+	assert_eq!(
+		error.chain().map(ToString::to_string).collect::<Vec<_>>(),
+		[
+			"`Builder` is not in initial state",
+			"Field `option` has Some value instead of None",
+			"Field `enabled` is true instead of false",
+		],
+	);
+	let violation = Violation::new("option", "has Some value instead of None");
+	assert_eq!(error.downcast_ref::<Violation>(), Some(&violation));
+}
+
+/// Checks that the error of the fallible check is propagated with `?`.
+#[test]
+fn error_propagated() {
+	fn check(builder: &Builder) -> anyhow::Result<()> {
+		builder.try_check_initial_state()?;
+		Ok(())
+	}
+	let error = check(&Builder { option: None, enabled: true }).unwrap_err();
+	assert_eq!(error.downcast_ref::<InitialStateError>().unwrap().struct_name(), "Builder");
+}
+
+#[derive(CheckInitialState)]
+struct Builder {
+	option: Option<i32>,
+	#[expect_false]
+	enabled: bool,
+}
@@ -0,0 +1,85 @@
+use check_initial_state::{assert_initial_state, CheckInitialState};
+
+/// Checks that nothing happens when the asserted expression is in initial
+/// state, including the case of a reference.
+#[test]
+fn assert_initial_state_ok() {
+	let builder = Builder::new();
+	assert_initial_state!(builder);
+	assert_initial_state!(&builder);
+	assert_initial_state!(Builder::new(), "custom message {}", 10);
+}
+
+/// Checks that the failure message contains the stringified expression.
+#[test]
+#[should_panic(expected = "assertion failed: `make_builder(Some(10))` is in initial state\n  \
+	cause: `Builder` is not in initial state: Field `option` has Some value instead of None")]
+fn assert_initial_state_captures_expression() {
+	assert_initial_state!(make_builder(Some(10)));
+}
+
+/// Checks that the failure message contains the custom message.
+#[test]
+#[should_panic(expected = "assertion failed: `builder` is in initial state: scenario 2\n")]
+fn assert_initial_state_with_message() {
+	let builder = make_builder(Some(10));
+	assert_initial_state!(builder, "scenario {}", 2);
+}
+
+/// Checks that each violating field is spelled out as an access of the
+/// expression.
+#[test]
+#[should_panic(expected = "\n  with expansion:\n    make_builder(Some(10)).option has Some value \
+	instead of None")]
+fn assert_initial_state_expands_fields() {
+	assert_initial_state!(make_builder(Some(10)), "scenario {}", 3);
+}
+
+/// Checks that all violating fields are expanded, and an expression with a
+/// prefix operator is parenthesized.
+#[test]
+#[should_panic(expected = "\n  with expansion:\n    (&make_builder(Some(10)).started()).option has \
+	Some value instead of None\n    (&make_builder(Some(10)).started()).started is true instead of \
+	false")]
+fn assert_initial_state_expands_all_fields() {
+	assert_initial_state!(&make_builder(Some(10)).started());
+}
+
+/// Checks that a field with `report_name` is expanded as an access of the field
+/// itself, while the cause names it by its `report_name`.
+#[test]
+#[should_panic(expected = "Field `Renamed option` has Some value instead of None\n  \
+	with expansion:\n    renamed.option has Some value instead of None")]
+fn assert_initial_state_expands_renamed_field() {
+	let renamed = Renamed { option: Some(10) };
+	assert_initial_state!(renamed);
+}
+
+fn make_builder(option: Option<i32>) -> Builder {
+	Builder { option, started: false, _integer: 10 }
+}
+
+#[derive(CheckInitialState)]
+struct Builder {
+	option: Option<i32>,
+	#[expect_false]
+	started: bool,
+	#[ignore_field]
+	_integer: i32,
+}
+
+impl Builder {
+	fn new() -> Self {
+		Self { option: None, started: false, _integer: 10 }
+	}
+
+	fn started(self) -> Self {
+		Self { started: true, ..self }
+	}
+}
+
+#[derive(CheckInitialState)]
+struct Renamed {
+	#[report_name = "Renamed option"]
+	option: Option<i32>,
+}
@@ -0,0 +1,73 @@
+use check_initial_state::{CheckInitialState, CheckInitialStateExt};
+
+/// Checks that a struct in initial state passes all assertions.
+#[test]
+fn initial_state_accepted() {
+	let builder = Builder { option: None, option2: None };
+	assert!(builder.is_initial());
+	builder.assert_initial();
+	builder.expect_initial("fresh builder");
+}
+
+/// Checks that a struct not in initial state is told apart.
+#[test]
+fn not_initial_state_told() {
+	assert!(!Builder { option: Some(1), option2: None }.is_initial());
+}
+
+/// Checks that the assertion lists all violating fields.
+#[test]
+#[should_panic(
+	expected = "assertion failed: `Builder` is not in initial state: Field `option` has Some value \
+		instead of None; Field `option2` has Some value instead of None"
+)]
+fn assertion_lists_violations() {
+	Builder { option: Some(1), option2: Some(2) }.assert_initial();
+}
+
+/// Checks that the message is prepended to the panic message.
+#[test]
+#[should_panic(
+	expected = "fresh builder: `Builder` is not in initial state: Field `option2` has Some value \
+		instead of None"
+)]
+fn expectation_message_prepended() {
+	Builder { option: None, option2: Some(2) }.expect_initial("fresh builder");
+}
+
+/// Checks that an instance in initial state is returned back by value.
+#[test]
+fn instance_returned() {
+	let builder = Builder { option: None, option2: None }.into_initial().ok().unwrap();
+	assert_eq!(builder.option, None);
+}
+
+/// Checks that an instance which is not in initial state is returned along
+/// with the error.
+#[test]
+fn instance_returned_with_error() {
+	let (builder, error) =
+		Builder { option: Some(1), option2: Some(2) }.into_initial().err().unwrap();
+	assert_eq!(builder.option2, Some(2));
+	let fields = error.violations().iter().map(|violation| violation.field()).collect::<Vec<_>>();
+	assert_eq!(fields, ["option", "option2"]);
+}
+
+/// Checks that the methods are available through trait objects and generics.
+#[test]
+fn unsized_types_supported() {
+	fn all_initial<T: CheckInitialStateExt + ?Sized>(values: &[&T]) -> bool {
+		values.iter().all(|value| value.is_initial())
+	}
+
+	let builder = Builder { option: None, option2: None };
+	let checked: &dyn CheckInitialState = &builder;
+	checked.assert_initial();
+	assert!(all_initial::<dyn CheckInitialState>(&[checked]));
+}
+
+#[derive(CheckInitialState)]
+struct Builder {
+	option: Option<i32>,
+	option2: Option<i32>,
+}
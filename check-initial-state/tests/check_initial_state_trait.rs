@@ -0,0 +1,57 @@
+use check_initial_state::{CheckInitialState, InitialStateError, Violation};
+
+/// Checks the fallible check of a struct in initial state.
+#[test]
+fn try_check_initial_state_ok() {
+	assert_eq!(builder::Builder::new().try_check_initial_state(), Ok(()));
+}
+
+/// Checks that the fallible check reports all violations in declaration order.
+#[test]
+fn try_check_initial_state_err() {
+	let mut builder = builder::Builder::new();
+	builder.option = Some(10);
+	builder.option2 = Some("value".to_owned());
+	let error: InitialStateError = builder.try_check_initial_state().unwrap_err();
+	assert_eq!(error.struct_name(), "Builder");
+	assert_eq!(
+		error.violations(),
+		&[
+			Violation::new("option", "has Some value instead of None"),
+			Violation::new("option2", "has Some value instead of None"),
+		],
+	);
+	assert_eq!(
+		error.to_string(),
+		"`Builder` is not in initial state: Field `option` has Some value instead of None; \
+			Field `option2` has Some value instead of None",
+	);
+}
+
+/// Checks that the panicking check is reachable through the trait from other
+/// modules, where the inherent method is private.
+#[test]
+#[should_panic(expected = "Field `option2` has Some value instead of None")]
+fn check_initial_state_through_trait() {
+	let mut builder = builder::Builder::new();
+	builder.option2 = Some("value".to_owned());
+	builder.check_initial_state();
+}
+
+mod builder {
+	use check_initial_state::CheckInitialState;
+
+	#[derive(CheckInitialState)]
+	pub struct Builder {
+		pub option: Option<i32>,
+		pub option2: Option<String>,
+		#[ignore_field]
+		pub _integer: i32,
+	}
+
+	impl Builder {
+		pub fn new() -> Self {
+			Self { option: None, option2: None, _integer: 10 }
+		}
+	}
+}
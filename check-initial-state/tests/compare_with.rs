@@ -0,0 +1,61 @@
+use check_initial_state::{CheckInitialState, Violation};
+
+/// Checks that the fallible check reports differences from the reference
+/// instance in both directions.
+#[test]
+fn compare_with_violations() {
+	let error = Builder { option: Some(10), preset: None }.try_check_initial_state().unwrap_err();
+	assert_eq!(
+		error.violations(),
+		&[
+			Violation::new("option", "has Some value instead of None"),
+			Violation::new("preset", "has None value instead of Some"),
+		],
+	);
+}
+
+/// Checks that the trait method reports the same diff as the inherent one.
+#[test]
+#[should_panic(expected = "Differences from the reference instance (reference => actual):\n  \
+	`preset`: Some => None")]
+fn compare_with_diff_through_trait() {
+	CheckInitialState::check_initial_state(&Builder { option: None, preset: None });
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(compare_with = "Self::with_preset(5)")]
+struct Builder {
+	option: Option<i32>,
+	preset: Option<i32>,
+}
+
+impl Builder {
+	fn with_preset(preset: i32) -> Self {
+		Self { option: None, preset: Some(preset) }
+	}
+}
+
+/// Checks that boolean fields are compared with the reference instance.
+#[test]
+#[should_panic(expected = "Field `enabled` is false instead of true\n\
+	Differences from the reference instance (reference => actual):\n  \
+	`enabled`: true => false\n  \
+	`dirty`: false => true")]
+fn compare_with_boolean_fields() {
+	Flags { enabled: false, dirty: true }.check_initial_state();
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(compare_with = "new")]
+struct Flags {
+	#[expect_false]
+	enabled: bool,
+	#[expect_false]
+	dirty: bool,
+}
+
+impl Flags {
+	fn new() -> Self {
+		Self { enabled: true, dirty: false }
+	}
+}
@@ -0,0 +1,27 @@
+#![cfg(feature = "googletest")]
+
+use check_initial_state::matcher::is_in_initial_state;
+use check_initial_state::CheckInitialState;
+use googletest::prelude::*;
+
+/// Checks that a struct in initial state is matched.
+#[test]
+fn matches_initial_state() -> Result<()> {
+	verify_that!(Builder { option: None, _integer: 10 }, is_in_initial_state())
+}
+
+/// Checks that the failure explains which fields are not in initial state.
+#[test]
+fn explains_violations() {
+	let result = verify_that!(Builder { option: Some(10), _integer: 10 }, is_in_initial_state());
+	let message = result.unwrap_err().description;
+	assert!(message.contains("Expected: is in initial state"), "{}", message);
+	assert!(message.contains("Field `option` has Some value instead of None"), "{}", message);
+}
+
+#[derive(CheckInitialState, Debug)]
+struct Builder {
+	option: Option<i32>,
+	#[ignore_field]
+	_integer: i32,
+}
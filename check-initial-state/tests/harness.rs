@@ -0,0 +1,28 @@
+use check_initial_state::CheckInitialState;
+
+#[derive(CheckInitialState)]
+#[check_initial_state(constructor = "Self::new", register)]
+struct ServerBuilder {
+	port: Option<u16>,
+}
+
+impl ServerBuilder {
+	fn new() -> Self {
+		Self { port: None }
+	}
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(constructor = "Self::new", register)]
+struct ClientBuilder {
+	#[expect_false]
+	verbose: bool,
+}
+
+impl ClientBuilder {
+	fn new() -> Self {
+		Self { verbose: false }
+	}
+}
+
+check_initial_state::check_all_initial_states!();
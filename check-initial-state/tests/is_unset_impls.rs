@@ -0,0 +1,49 @@
+/// Checks that standard types are checked without annotations.
+#[cfg(feature = "std-types")]
+#[test]
+fn standard_types() {
+	use check_initial_state::CheckInitialState;
+	use std::ffi::OsString;
+	use std::path::PathBuf;
+	use std::time::Duration;
+
+	#[derive(CheckInitialState)]
+	struct Builder {
+		path: PathBuf,
+		name: OsString,
+		timeout: Duration,
+	}
+
+	let builder = Builder { path: PathBuf::new(), name: OsString::new(), timeout: Duration::ZERO };
+	assert!(builder.try_check_initial_state().is_ok());
+	let builder = Builder {
+		path: PathBuf::from("/tmp"),
+		name: OsString::from("name"),
+		timeout: Duration::from_millis(1),
+	};
+	let error = builder.try_check_initial_state().unwrap_err();
+	let fields = error.violations().iter().map(|violation| violation.field()).collect::<Vec<_>>();
+	assert_eq!(fields, ["path", "name", "timeout"]);
+}
+
+/// Checks that a nil UUID is unset.
+#[cfg(feature = "uuid")]
+#[test]
+fn uuid() {
+	use check_initial_state::IsUnset;
+
+	assert!(uuid::Uuid::nil().is_unset());
+	assert!(!uuid::Uuid::from_u128(1).is_unset());
+}
+
+/// Checks that empty buffers are unset.
+#[cfg(feature = "bytes")]
+#[test]
+fn bytes() {
+	use check_initial_state::IsUnset;
+
+	assert!(bytes::Bytes::new().is_unset());
+	assert!(!bytes::Bytes::from_static(b"data").is_unset());
+	assert!(bytes::BytesMut::new().is_unset());
+	assert!(!bytes::BytesMut::from(&b"data"[..]).is_unset());
+}
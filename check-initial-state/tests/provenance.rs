@@ -0,0 +1,60 @@
+#![cfg(feature = "provenance")]
+
+use check_initial_state::provenance::Provenance;
+use check_initial_state::CheckInitialState;
+use std::panic::{self, Location, UnwindSafe};
+
+/// Checks that the panic names the location where the violating field was set.
+#[test]
+fn location_reported() {
+	let mut builder = Builder::default();
+	let line = Location::caller().line() + 1;
+	builder.set_option_tracked(Some(1));
+	let message = catch_message(move || builder.check_initial_state());
+	let expected =
+		format!("Field `option` has Some value instead of None, set at {}:{}:", file!(), line);
+	assert!(message.starts_with(&expected), "{}", message);
+}
+
+/// Checks that the location of the last assignment is reported, while fields
+/// assigned directly have no location.
+#[test]
+fn last_location_reported() {
+	let mut builder = Builder::default();
+	builder.set_enabled_tracked(true);
+	builder.set_enabled_tracked(false);
+	builder.set_enabled_tracked(true);
+	let line = Location::caller().line() - 1;
+	assert_eq!(builder.provenance.location("Enabled").unwrap().line(), line);
+	builder.option = Some(1);
+	let message = catch_message(move || builder.check_initial_state());
+	assert_eq!(message.lines().next(), Some("Field `option` has Some value instead of None"));
+}
+
+/// Checks that provenance affects neither the check nor equality.
+#[test]
+fn provenance_not_checked() {
+	let mut builder = Builder::default();
+	builder.set_option_tracked(None);
+	builder.check_initial_state();
+	assert!(builder == Builder::default());
+	builder.provenance.clear();
+	assert_eq!(builder.provenance.location("option"), None);
+}
+
+/// Runs `check` and returns its panic message. The state table, which `table`
+/// feature appends, follows the first line.
+fn catch_message(check: impl FnOnce() + UnwindSafe) -> String {
+	let payload = panic::catch_unwind(check).unwrap_err();
+	*payload.downcast::<String>().unwrap()
+}
+
+#[derive(CheckInitialState, Default, PartialEq)]
+struct Builder {
+	option: Option<i32>,
+	#[expect_false]
+	#[report_name = "Enabled"]
+	enabled: bool,
+	#[provenance]
+	provenance: Provenance,
+}
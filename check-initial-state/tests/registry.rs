@@ -0,0 +1,104 @@
+#![cfg(feature = "registry")]
+
+use check_initial_state::registry::{registered_checks, run_tests, RegisteredCheck};
+use check_initial_state::{verify_all, CheckInitialState, InitialStateError, Violation};
+
+/// Checks that only structs with `register` option are registered.
+#[test]
+fn structs_with_register_option_registered() {
+	let mut names = registered_checks().map(RegisteredCheck::struct_name).collect::<Vec<_>>();
+	names.sort_unstable();
+	assert_eq!(names, ["Dirty", "Fresh"]);
+}
+
+/// Checks that each constructor of a registered struct is verified.
+#[test]
+fn registered_constructors_verified() {
+	let fresh = registered_checks().find(|check| check.struct_name() == "Fresh").unwrap();
+	assert!(fresh.verify().is_ok());
+}
+
+/// Checks that all registered structs are verified at once.
+#[test]
+#[should_panic(expected = "`Dirty` is not in initial state: Field `option` has Some value")]
+fn verify_all_reports_dirty_struct() {
+	verify_all();
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(constructors("Self::new", "Self::default"), register)]
+struct Fresh {
+	option: Option<i32>,
+}
+
+impl Fresh {
+	fn new() -> Self {
+		Self { option: None }
+	}
+}
+
+impl Default for Fresh {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+// Stands for a struct which constructor doesn't produce initial state. Is
+// registered directly, since the constructor test generated by the derive would
+// fail otherwise
+#[check_initial_state::__private::linkme::distributed_slice(
+	check_initial_state::registry::REGISTERED_CHECKS
+)]
+#[linkme(crate = check_initial_state::__private::linkme)]
+static DIRTY: RegisteredCheck = RegisteredCheck::new("Dirty", verify_dirty);
+
+fn verify_dirty() -> Result<(), InitialStateError> {
+	let violation = Violation::new("option", "has Some value instead of None");
+	Err(InitialStateError::new("Dirty", vec![violation]))
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(constructor = "Self::new")]
+struct Unregistered {
+	option: Option<i32>,
+}
+
+impl Unregistered {
+	fn new() -> Self {
+		Self { option: None }
+	}
+}
+
+/// Checks that each struct is run as a separate test, and a constructor panic
+/// fails the test of its struct only.
+#[test]
+fn run_tests_reports_each_struct() {
+	let checks = [
+		RegisteredCheck::new("Clean", || Ok(())),
+		RegisteredCheck::new("Panicking", || panic!("Constructor failed")),
+		RegisteredCheck::new("Dirty", verify_dirty),
+	];
+	let mut output = Vec::new();
+	let passed = run_tests(&checks, None, &mut output).unwrap();
+	assert!(!passed);
+	let output = String::from_utf8(output).unwrap();
+	assert!(output.contains("running 3 tests\n"));
+	assert!(output.contains("test Clean ... ok\n"));
+	assert!(output.contains("test Panicking ... FAILED\n"));
+	assert!(output.contains("---- Dirty ----\n`Dirty` is not in initial state"));
+	assert!(output.contains("test result: FAILED. 1 passed; 2 failed;"));
+}
+
+/// Checks that only the structs which names contain the filter are run.
+#[test]
+fn run_tests_filters_structs() {
+	let checks = [
+		RegisteredCheck::new("ServerBuilder", || Ok(())),
+		RegisteredCheck::new("Dirty", verify_dirty),
+	];
+	let mut output = Vec::new();
+	let passed = run_tests(&checks, Some("Server"), &mut output).unwrap();
+	assert!(passed);
+	let output = String::from_utf8(output).unwrap();
+	assert!(output.contains("test result: ok. 1 passed; 0 failed; 0 ignored; 0 measured; 1 filtered"));
+}
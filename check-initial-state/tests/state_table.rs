@@ -0,0 +1,143 @@
+#![cfg(feature = "table")]
+
+use check_initial_state::CheckInitialState;
+use std::panic::{self, UnwindSafe};
+
+/// Checks that the table lists all checked fields and marks the violating one.
+#[test]
+fn table_appended() {
+	let message = catch_message(|| {
+		Builder { option: None, enabled: true, _ignored: Some(1) }.check_initial_state()
+	});
+	assert_eq!(
+		message,
+		"Field `enabled` is true instead of false\n\
+			Fields of `Builder`:\n    \
+			option  | None\n  \
+			* enabled | true",
+	);
+}
+
+/// Checks that values of fields implementing `Debug` are printed with
+/// `table_values` option.
+#[test]
+fn values_printed() {
+	let message =
+		catch_message(|| Valued { option: Some(7), opaque: Some(Opaque) }.check_initial_state());
+	assert_eq!(
+		message,
+		"Field `option` has Some value instead of None\n\
+			Fields of `Valued`:\n  \
+			* option | Some | Some(7)\n  \
+			* opaque | Some",
+	);
+}
+
+/// Checks that values longer than the default limit are truncated.
+#[test]
+fn long_value_truncated_by_default() {
+	let text = "\u{e9}".repeat(150);
+	let message = catch_message(|| Texted { text: Some(text.clone()) }.check_initial_state());
+	let truncated = format!("{:?}", Some(text)).chars().take(100).collect::<String>();
+	assert!(message.ends_with(&format!("| {}\u{2026}", truncated)), "{}", message);
+}
+
+/// Checks that values are truncated to `max_value_len` characters.
+#[test]
+fn value_truncated_to_max_len() {
+	let message =
+		catch_message(|| Limited { items: Some(vec![1, 2, 3, 4]) }.check_initial_state());
+	assert!(message.ends_with("* items | Some | Some([1, \u{2026}"), "{}", message);
+}
+
+/// Checks that a value of exactly `max_value_len` characters is not truncated.
+#[test]
+fn value_of_max_len_kept() {
+	let message = catch_message(|| Limited { items: Some(vec![1]) }.check_initial_state());
+	assert!(message.ends_with("* items | Some | Some([1])"), "{}", message);
+}
+
+/// Checks that values of redacted fields are never printed.
+#[test]
+fn redacted_value_hidden() {
+	let message = catch_message(|| {
+		Credentials { token: Some("s3cr3t".to_owned()), user: Some("admin".to_owned()) }
+			.check_initial_state()
+	});
+	assert!(!message.contains("s3cr3t"), "{}", message);
+	assert!(message.ends_with(
+		"* token | Some | <redacted>\n  \
+			* user  | Some | Some(\"admin\")"
+	));
+}
+
+/// Checks that nothing panics for an instance in initial state.
+#[test]
+fn initial_state_not_panics() {
+	Builder { option: None, enabled: false, _ignored: Some(1) }.check_initial_state();
+}
+
+/// Checks that the table of a foreign struct is appended as well.
+#[test]
+fn remote_table_appended() {
+	let message = catch_message(|| BuilderDef::check_initial_state(&Foreign { option: Some(1) }));
+	assert_eq!(
+		message,
+		"Field `option` has Some value instead of None\nFields of `Foreign`:\n  * option | Some",
+	);
+}
+
+/// Runs `check` and returns its panic message.
+fn catch_message(check: impl FnOnce() + UnwindSafe) -> String {
+	let payload = panic::catch_unwind(check).unwrap_err();
+	*payload.downcast::<String>().unwrap()
+}
+
+#[derive(CheckInitialState)]
+struct Builder {
+	option: Option<i32>,
+	#[expect_false]
+	enabled: bool,
+	#[ignore_field]
+	_ignored: Option<i32>,
+}
+
+struct Opaque;
+
+#[derive(CheckInitialState)]
+#[check_initial_state(table_values)]
+struct Valued {
+	option: Option<i32>,
+	opaque: Option<Opaque>,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(table_values)]
+struct Texted {
+	text: Option<String>,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(table_values, max_value_len = 9)]
+struct Limited {
+	items: Option<Vec<i32>>,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(table_values)]
+struct Credentials {
+	#[redact]
+	token: Option<String>,
+	user: Option<String>,
+}
+
+struct Foreign {
+	option: Option<i32>,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(remote = "Foreign")]
+#[allow(dead_code)]
+struct BuilderDef {
+	option: Option<i32>,
+}
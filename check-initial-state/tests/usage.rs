@@ -0,0 +1,53 @@
+#![cfg(feature = "usage-tracking")]
+
+use check_initial_state::usage::{tracked_structs, TrackedStruct};
+use check_initial_state::{report_unchecked, CheckInitialState};
+
+/// Checks that every struct with `track_usage` option is tracked, and a checked
+/// struct is not reported.
+#[test]
+fn unchecked_structs_reported() {
+	let mut names = tracked_structs().map(TrackedStruct::struct_name).collect::<Vec<_>>();
+	names.sort_unstable();
+	assert_eq!(names, ["Checked", "Forgotten", "Remote", "Tried"]);
+	Checked { option: None }.check_initial_state();
+	assert!(Tried { option: Some(10) }.try_check_initial_state().is_err());
+	assert_eq!(report_unchecked(), ["Forgotten", "Remote"]);
+	Mirror::check_initial_state(&Remote { option: None });
+	assert_eq!(report_unchecked(), ["Forgotten"]);
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(track_usage)]
+struct Checked {
+	option: Option<i32>,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(track_usage)]
+struct Tried {
+	option: Option<i32>,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(track_usage)]
+struct Forgotten {
+	_option: Option<i32>,
+}
+
+struct Remote {
+	option: Option<i32>,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(remote = "Remote", track_usage)]
+#[allow(dead_code)]
+struct Mirror {
+	option: Option<i32>,
+}
+
+#[derive(CheckInitialState)]
+#[allow(dead_code)]
+struct Untracked {
+	option: Option<i32>,
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "validator")]
+
+use check_initial_state::validation::{ValidateInitialState, INITIAL_STATE_CODE};
+use check_initial_state::CheckInitialState;
+use validator::ValidationErrors;
+
+/// Checks that a struct in initial state passes validation.
+#[test]
+fn validation_passed() {
+	assert!(Builder { option: None, option2: None }.validate_initial_state().is_ok());
+}
+
+/// Checks that validation errors are keyed by the fields which are not in
+/// initial state.
+#[test]
+fn validation_errors_keyed_by_field() {
+	let errors =
+		Builder { option: Some(10), option2: Some(10) }.validate_initial_state().unwrap_err();
+	let field_errors = errors.field_errors();
+	assert_eq!(field_errors.len(), 2);
+	let option_error = &field_errors["option2"][0];
+	assert_eq!(option_error.code, INITIAL_STATE_CODE);
+	assert_eq!(
+		option_error.message.as_deref(),
+		Some("Field `option2` has Some value instead of None"),
+	);
+}
+
+/// Checks that the error of the fallible check converts into validation
+/// errors, so it may be propagated with `?`.
+#[test]
+fn error_converts_into_validation_errors() {
+	let error = Builder { option: Some(10), option2: None }.try_check_initial_state().unwrap_err();
+	let errors = ValidationErrors::from(error);
+	assert!(ValidationErrors::has_error(&Err(errors), "option"));
+}
+
+#[derive(CheckInitialState)]
+struct Builder {
+	option: Option<i32>,
+	option2: Option<i32>,
+}
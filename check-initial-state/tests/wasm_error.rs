@@ -0,0 +1,12 @@
+#![cfg(feature = "wasm-bindgen")]
+
+use check_initial_state::InitialStateError;
+use wasm_bindgen::JsValue;
+
+/// Checks that the error converts into `JsValue`. JS values cannot be created
+/// outside of wasm, so the conversion itself is not called.
+#[test]
+fn error_converts_into_js_value() {
+	fn converts_into_js_value<T: Into<JsValue>>() {}
+	converts_into_js_value::<InitialStateError>();
+}
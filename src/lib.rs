@@ -11,7 +11,27 @@
 //! There may be a need to ignore some fields of a builder struct, so they can
 //! have any value initially. Such fields should be annotated with
 //! `ignore_field` attribute. The same requirement applies to fields which are
-//! not `Option` at all, if such are present.
+//! not `Option` at all, if such are present, unless the struct is annotated with
+//! `#[check_initial_state(lenient)]`, which skips such fields silently.
+//!
+//! `bool` flags don't have to be ignored: annotate them with `expect_false` or
+//! `expect_true` attribute to check them against the expected initial value.
+//! Likewise, numeric counters may be annotated with `expect_zero`, while any
+//! field may declare its expected initial pattern with `expect_matches`, e.g.
+//! `#[expect_matches(State::Idle)]`.
+//! Recurring non&#x2011;`Option` types may be checked across the whole struct by
+//! mapping them to emptiness methods, e.g.
+//! `#[check_initial_state(empty_via(Vec = "is_empty"))]`.
+//! Non&#x2011;annotated fields are checked through `IsUnset` trait of the
+//! runtime crate, which is implemented for `Option`. So a custom field type
+//! becomes checkable without annotations by implementing `is_unset()` method.
+//!
+//! Code generated by the macro refers to
+//! `check-initial-state` runtime crate, so it must be a
+//! dependency as well, regardless of enabled features. Frameworks re&#x2011;exporting
+//! it may point the macro to their path with `crate` option. The runtime crate
+//! re&#x2011;exports the macro along with `CheckInitialState` trait which the
+//! macro implements.
 //! # Example
 //! ```
 //! # use check_initial_state_derive::CheckInitialState;
@@ -43,15 +63,10 @@
 //!     Builder::new().check_initial_state()
 //! }
 //! ```
-use proc_macro::TokenStream;
-use quote::quote;
-use syn::punctuated::Punctuated;
-use syn::token::Comma;
-use syn::{parse_macro_input, Data, DataStruct, DeriveInput, Error, Field, Fields};
+#![allow(clippy::needless_return, clippy::doc_lazy_continuation)]
 
-/// Fields which are annotated with this attribute are ignored by
-/// `CheckInitialState` proc macro check.
-const IGNORE_FIELD_ATTRIBUTE: &'static str = "ignore_field";
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
 
 /// Creates `check_initial_state()` method which is intended to check all
 /// `Option` fields to have `None` at the time of the mentioned method call. If
@@ -62,139 +77,1489 @@ const IGNORE_FIELD_ATTRIBUTE: &'static str = "ignore_field";
 /// * Proc macros cannot definitely determine fields types. Fields which are not
 /// `Option` must be explicitly annotated with `ignore_field` attribute. The
 /// same approach may be used to exclude some `Option` fields from the check.
-/// * This procedural macro expects to call `std::option::Option::is_some()` on
-/// each non&#x2011;annotated field of the provided struct. Since
+/// * This procedural macro expects to call `IsUnset::is_unset()` on each
+/// non&#x2011;annotated field of the provided struct. Since
 /// `proc_macro_derive` receives nothing but a struct in the form of a token
 /// tree, a user of this macro is going to observe error, similar to
 /// ```text
-/// error[E0308]: mismatched types
-///   --> $DIR/struct_with_other_fields_not_annotated.rs:10:10
+/// error[E0277]: the trait bound `i32: IsUnset` is not satisfied
+///   --> tests/compile_fail/struct_with_other_fields_not_annotated.rs:13:11
 ///    |
-/// 10 | #[derive(CheckInitialState)]
-///    |          ^^^^^^^^^^^^^^^^^ expected enum `std::option::Option`, found `i32`
-///    |
-///    = note: expected reference `&std::option::Option<_>`
-///               found reference `&i32`
-///    = note: this error originates in a derive macro (in Nightly builds, run with -Z macro-backtrace for more info)
+/// 13 |     integer: i32,
+///    |              ^^^ the trait `IsUnset` is not implemented for `i32`
 /// ```
 /// , if a given non&#x2011;annotated with `ignore_field` field is not an
-/// `Option`.
-#[proc_macro_derive(CheckInitialState, attributes(ignore_field))]
+/// `Option`. The error points at the type of the offending field, so it is
+/// easy to spot in a large struct.
+/// * Ignoring an `Option` field is usually worth an explanation, which may be
+/// given with `#[ignore_field(reason = "...")]`. `warn_ignored_option` option,
+/// i.e. `#[check_initial_state(warn_ignored_option)]`, makes the compiler warn
+/// about each `Option` field ignored without a reason. The warning is reported
+/// as a usage of a deprecated item, so `#![deny(deprecated)]` turns it into an
+/// error.
+/// * A struct with all fields ignored gets a vacuous check. `warn_empty_check`
+/// and `deny_empty_check` options report such struct with a warning and a
+/// compile error respectively.
+/// * All problems of `check_initial_state` and field attributes are reported
+/// at once rather than the first one only. A misspelled option name gets a
+/// suggestion of the closest known option.
+/// * Checked fields of a `#[repr(packed)]` struct are copied before being
+/// checked, since references to them may be unaligned. So such fields must
+/// be `Copy`.
+/// * The struct may be generated by `macro_rules!`. Field types, constructors
+/// and attributes passed as macro fragments, e.g. `$ty:ty`, are treated the
+/// same as if they were written in place.
+/// * Generated impls keep generics and where clauses of the struct as they are
+/// and add no bounds on generic parameters, e.g. `Debug` or `PartialEq`, in any
+/// mode. So enabling an option or a feature doesn't restrict the parameters.
+/// * Generated impls are marked `#[automatically_derived]` and allow
+/// `dead_code` and `clippy::panic` lints, so crates with strict lint policies
+/// aren't warned about generated methods they don't use or about their panics.
+///
+/// # Constructors verification
+/// The struct may be annotated with `check_initial_state` attribute listing
+/// its constructors:
+/// ```
+/// # use check_initial_state_derive::CheckInitialState;
+/// #
+/// #[derive(CheckInitialState)]
+/// #[check_initial_state(constructors("Self::new", "Self::with_capacity(10)"))]
+/// struct Builder {
+///     option: Option<i32>,
+///     #[ignore_field]
+///     capacity: usize,
+/// }
+///
+/// impl Builder {
+///     fn new() -> Self {
+///         Self::with_capacity(0)
+///     }
+///
+///     fn with_capacity(capacity: usize) -> Self {
+///         Self { option: None, capacity }
+///     }
+/// }
+/// ```
+/// A `#[test]` is generated for each of the listed constructors, calling
+/// `check_initial_state()` on the constructed instance. So every construction
+/// path is covered without writing the tests manually. A constructor is either
+/// a path to a function taking no arguments or a call expression. A bare
+/// function name, e.g. `"new"`, denotes an associated function of the struct.
+/// Single constructor may also be specified as
+/// `#[check_initial_state(constructor = "Self::new")]`. Expressions of
+/// `constructor`, `compare_with` and `ignore_field(default)` options may be
+/// written without quotes as well, e.g.
+/// `#[check_initial_state(constructor = Self::new)]` or
+/// `#[ignore_field(default = 10)]`.
+///
+/// For `Clone` structs `check_clone` option may be added, e.g.
+/// `#[check_initial_state(constructor = "Self::new", check_clone)]`. Then an
+/// additional test is generated for each constructor, checking that a clone of
+/// the freshly constructed instance is in initial state too. This catches
+/// `Clone` implementations which eagerly populate some fields.
+///
+/// `mutation_test` option generates a test for each constructor, which takes
+/// the freshly constructed instance, sets each checked field one at a time and
+/// expects `check_initial_state()` to panic with that field's name. This
+/// verifies the check actually covers every field. A field is set to
+/// `Some(Default::default())` directly, unless setters naming convention is
+/// given, e.g. `#[check_initial_state(constructor = "new", mutation_test,
+/// setter = "set_{}")]`. Then the setter is called with `Default::default()`
+/// argument. Without setters, fields which payload doesn't implement
+/// `Default`, e.g. `Option<Box<dyn Handler>>`, are skipped rather than
+/// requiring the bound.
+///
+/// `try_constructors` option makes the invariant a part of the construction
+/// API itself. A fallible associated function is generated for each
+/// constructor, which is a path to a function, e.g. `try_new()` for
+/// `Self::new`. It returns the constructed instance if it is in initial state,
+/// and `InitialStateError` otherwise. Constructors written as call expressions
+/// are skipped. The functions have the visibility of the struct:
+/// ```
+/// # use check_initial_state_derive::CheckInitialState;
+/// #
+/// #[derive(CheckInitialState)]
+/// #[check_initial_state(constructor = "new", try_constructors)]
+/// struct Builder {
+///     option: Option<i32>,
+/// }
+///
+/// impl Builder {
+///     fn new() -> Self {
+///         Self { option: None }
+///     }
+/// }
+///
+/// let builder: Builder = Builder::try_new().unwrap();
+/// ```
+///
+/// `register` option registers the struct at link time via
+/// [linkme](https://crates.io/crates/linkme), e.g.
+/// `#[check_initial_state(constructor = "new", register)]`. Then
+/// `check_initial_state::verify_all()`, which requires `registry` feature of
+/// the runtime crate, constructs an instance with each constructor of every
+/// registered struct and checks it, so a single test covers the whole crate.
+/// Alternatively, `check_all_initial_states!()` expands into a test harness
+/// running a test per registered struct.
+///
+/// `track_usage` option registers the struct the same way, and its
+/// `check_initial_state()` and `try_check_initial_state()` record that it was
+/// checked. Then `check_initial_state::report_unchecked()` lists the structs
+/// which were never checked during the process, so a final test may flag
+/// builders that derive the macro but are never verified. The option does
+/// nothing unless `usage-tracking` feature of `check-initial-state` crate is
+/// enabled, which is intended to be done in `dev-dependencies` only.
+///
+/// # Boolean fields
+/// Boolean flags may participate in the check as well, without wrapping them
+/// into `Option<bool>`. A field annotated with `expect_false` is expected to be
+/// `false` initially, while the one annotated with `expect_true` &ndash; to be
+/// `true`:
+/// ```
+/// # use check_initial_state_derive::CheckInitialState;
+/// #
+/// #[derive(CheckInitialState)]
+/// struct Builder {
+///     option: Option<i32>,
+///     // Panic message is "Field `dirty` is true instead of false"
+///     #[expect_false]
+///     dirty: bool,
+///     #[expect_true]
+///     enabled: bool,
+/// }
+/// ```
+///
+/// # Numeric fields
+/// Counters and accumulators annotated with `expect_zero` are expected to be
+/// `0` initially. The attribute applies to any integer or float type:
+/// ```
+/// # use check_initial_state_derive::CheckInitialState;
+/// #
+/// #[derive(CheckInitialState)]
+/// struct Builder {
+///     // Panic message is "Field `retries` is non-zero instead of zero"
+///     #[expect_zero]
+///     retries: u32,
+///     #[expect_zero]
+///     total: f64,
+/// }
+/// ```
+///
+/// # Patterns
+/// Any field may declare its initial state as a pattern with `expect_matches`
+/// attribute. The field is checked with `matches!` macro, and the panic
+/// message contains the pattern. Since no value can be derived from a
+/// pattern, such fields are skipped by `mutation_test` and are not supported
+/// by `initial_const` option:
+/// ```
+/// # use check_initial_state_derive::CheckInitialState;
+/// #
+/// enum State {
+///     Idle,
+///     Running(u32),
+/// }
+///
+/// #[derive(CheckInitialState)]
+/// struct Builder {
+///     // Panic message is "Field `state` doesn't match `State::Idle`"
+///     #[expect_matches(State::Idle)]
+///     state: State,
+///     #[expect_matches(None | Some(0))]
+///     limit: Option<u32>,
+/// }
+/// ```
+///
+/// # Emptiness methods
+/// Recurring non&#x2011;`Option` types, e.g. collections, may be checked
+/// across the whole struct without per&#x2011;field attributes. `empty_via`
+/// option maps a type, matched by its name regardless of generic arguments, to
+/// a method which returns `true` for an empty value. Fields annotated with a
+/// check attribute, e.g. `expect_matches`, are not affected by the mapping.
+/// Like patterns, such fields are skipped by `mutation_test` and are not
+/// supported by `initial_const` option:
+/// ```
+/// # use check_initial_state_derive::CheckInitialState;
+/// #
+/// #[derive(CheckInitialState)]
+/// #[check_initial_state(empty_via(Vec = "is_empty", String = "is_empty"))]
+/// struct Builder {
+///     // Panic message is "Field `items` is not empty according to `is_empty()`"
+///     items: Vec<u8>,
+///     name: String,
+/// }
+/// ```
+///
+/// # Option&#x2011;like types
+/// A field of a project&#x2011;specific type, which behaves like `Option` and
+/// exposes `is_some()` method, e.g. a newtype, may be annotated with
+/// `option_alias` attribute instead of `ignore_field`. The field is expected to
+/// be `None` according to its `is_some()` method, and is reported like an
+/// `Option` field. Since no value of such type can be derived, the field is
+/// skipped by `mutation_test` and is not supported by `initial_const` option:
+/// ```
+/// # use check_initial_state_derive::CheckInitialState;
+/// #
+/// struct MaybeId(Option<u64>);
+///
+/// impl MaybeId {
+///     fn is_some(&self) -> bool {
+///         self.0.is_some()
+///     }
+/// }
+///
+/// #[derive(CheckInitialState)]
+/// struct Builder {
+///     // Panic message is "Field `id` has Some value instead of None"
+///     #[option_alias]
+///     id: MaybeId,
+/// }
+/// ```
+///
+/// # Report names
+/// A field may be named differently in panic messages and reports with
+/// `report_name` attribute, while the code keeps its own naming. The name
+/// replaces the field name in all generated output, including
+/// `Violation::field()` and metrics labels, while `FIELD_NAMES` constant keeps
+/// the names as they are written:
+/// ```
+/// # use check_initial_state_derive::CheckInitialState;
+/// #
+/// #[derive(CheckInitialState)]
+/// struct Builder {
+///     // Panic message is "Field `Override timestamp` has Some value instead of None"
+///     #[report_name = "Override timestamp"]
+///     cfg_ovr_ts: Option<u64>,
+/// }
+/// ```
+///
+/// # Ignoring per build configuration
+/// `ignore_field` also accepts a boolean, e.g. `#[ignore_field = false]`, which
+/// keeps the field checked. Combined with `cfg_attr` this flips a field between
+/// checked and ignored depending on the build configuration. If a field has
+/// several `ignore_field` attributes, the last one wins:
+/// ```
+/// # use check_initial_state_derive::CheckInitialState;
+/// #
+/// #[derive(CheckInitialState)]
+/// struct Builder {
+///     // Ignored in test builds only
+///     #[cfg_attr(test, ignore_field)]
+///     cached: Option<i32>,
+///     // Checked if `strict` feature is enabled
+///     #[ignore_field]
+///     #[cfg_attr(feature = "strict", ignore_field = false)]
+///     preset: Option<i32>,
+/// }
+/// ```
+///
+/// # Ignoring by type
+/// Cross&#x2011;cutting infrastructure fields, e.g. markers, timestamps or
+/// metrics handles, may be skipped across the whole struct without
+/// per&#x2011;field annotations. `ignore_types` option lists types, matched by
+/// their names regardless of generic arguments like in `empty_via` option.
+/// Fields annotated with a check attribute, `ignore_field` or `delegate_check`
+/// are not affected:
+/// ```
+/// # use check_initial_state_derive::CheckInitialState;
+/// # use std::marker::PhantomData;
+/// # use std::time::Instant;
+/// #
+/// #[derive(CheckInitialState)]
+/// #[check_initial_state(ignore_types(PhantomData, Instant))]
+/// struct Builder<T> {
+///     option: Option<T>,
+///     marker: PhantomData<T>,
+///     created: Instant,
+/// }
+/// ```
+///
+/// # Attributes of other derives
+/// Attributes of this crate are matched by their whole path. So helper
+/// attributes of other derives on the same fields, e.g. `#[serde(...)]` or
+/// `#[builder(...)]`, never affect the checks unless an option requests it,
+/// and a foreign attribute, which merely ends with the same name, e.g.
+/// `#[rustfmt::ignore_field]`, is not taken for `ignore_field`.
+///
+/// # serde attributes
+/// A struct, which doubles as a deserialization target, already tells with
+/// `serde` attributes which fields are not deserialized or are defaulted.
+/// `serde` option, i.e. `#[check_initial_state(serde)]`, makes such fields
+/// ignored without duplicating the annotations:
+/// * Fields annotated with `#[serde(skip)]` or `#[serde(skip_deserializing)]`
+/// are ignored.
+/// * Non&#x2011;`Option` fields annotated with `#[serde(default)]`, or all
+/// non&#x2011;`Option` fields if the struct itself is annotated so, are
+/// ignored. `Option` fields are defaulted to `None`, so they are still
+/// checked.
+///
+/// A field annotated with `ignore_field` or a check attribute is treated
+/// according to its annotation regardless of `serde` attributes:
+/// ```
+/// # use check_initial_state_derive::CheckInitialState;
+/// # use serde::Deserialize;
+/// #
+/// #[derive(CheckInitialState, Deserialize)]
+/// #[check_initial_state(serde)]
+/// struct Config {
+///     timeout: Option<u32>,
+///     // Ignored
+///     #[serde(skip)]
+///     cache: Option<u32>,
+///     // Ignored
+///     #[serde(default)]
+///     retries: u32,
+/// }
+/// ```
+///
+/// # smart&#x2011;default attributes
+/// A field annotated with `default` attribute of
+/// [smart-default](https://crates.io/crates/smart-default) crate, e.g.
+/// `#[default(3)]`, is expected to equal the specified value, which is
+/// converted to the field type the way `smart-default` does. So the field
+/// needs no second annotation. `#[default]` and `#[default(None)]` keep the
+/// usual check, while a check attribute takes precedence over the default.
+/// Such fields are skipped by `mutation_test` and are not supported by
+/// `initial_const` option:
+/// ```
+/// # use check_initial_state_derive::CheckInitialState;
+/// # use smart_default::SmartDefault;
+/// #
+/// #[derive(CheckInitialState, SmartDefault)]
+/// struct Config {
+///     // Panic message is "Field `retries` doesn't equal `3`"
+///     #[default(3)]
+///     retries: u32,
+///     #[default = "localhost"]
+///     host: String,
+///     #[default(None)]
+///     timeout: Option<u32>,
+/// }
+/// ```
+///
+/// # Lenient mode
+/// `lenient` option, i.e. `#[check_initial_state(lenient)]`, makes
+/// non&#x2011;annotated fields, which are not `Option`, skipped by the check
+/// instead of failing compilation. So rapidly evolving structs don't require
+/// `ignore_field` on every new non&#x2011;`Option` field. Whether a field is
+/// `Option` is determined by its concrete type, so a field of a generic type
+/// parameter is always skipped. `mutation_test` mutates only `Option` fields,
+/// which payload implements `Default`. The mode cannot be combined with
+/// `setter` and `initial_const` options:
+/// ```
+/// # use check_initial_state_derive::CheckInitialState;
+/// #
+/// #[derive(CheckInitialState)]
+/// #[check_initial_state(lenient)]
+/// struct Builder {
+///     option: Option<i32>,
+///     // Skipped without `ignore_field` annotation
+///     integer: i32,
+/// }
+/// ```
+///
+/// # Automatic checks
+/// `auto_unset` option, i.e. `#[check_initial_state(auto_unset)]`, makes
+/// non&#x2011;annotated fields, which are not `Option`, checked if their type
+/// implements `IsUnset` trait of the runtime crate, or is `bool`, a number,
+/// `String`, `OsString` or a standard collection. Such fields are expected to
+/// be `false`, zero or empty respectively. So only truly opaque types require
+/// `ignore_field`, while others fail compilation as usual. The mode takes
+/// precedence over `lenient` one for such fields:
+/// ```
+/// # use check_initial_state_derive::CheckInitialState;
+/// # use std::collections::HashMap;
+/// #
+/// #[derive(CheckInitialState)]
+/// #[check_initial_state(auto_unset)]
+/// struct Builder {
+///     option: Option<i32>,
+///     started: bool,
+///     retries: u32,
+///     headers: HashMap<String, String>,
+/// }
+///
+/// let builder = Builder { option: None, started: false, retries: 1, headers: HashMap::new() };
+/// let mut report = Vec::new();
+/// builder.write_initial_state_report(&mut report).unwrap();
+/// assert_eq!(report, b"Field `retries` is set instead of unset\n");
+/// ```
+///
+/// # Comparison with a reference instance
+/// By default checked fields are expected to be `None`. With
+/// `#[check_initial_state(compare_with = "Self::new")]` initial state is
+/// defined by the real constructor instead. Generated checks construct a fresh
+/// reference instance with the given constructor, and compare whether each
+/// checked field is `Some` against the reference one. Payload values are not
+/// compared. So `Self::new` may initialize some fields with `Some` on purpose,
+/// while other instances are expected to follow. Panic message of
+/// `check_initial_state()` lists all fields which differ from the reference
+/// instance:
+/// ```text
+/// Field `option` has Some value instead of None
+/// Differences from the reference instance (reference => actual):
+///   `option`: None => Some
+///   `preset`: Some => None
+/// ```
+///
+/// # Generic bounds
+/// Generated impls keep the where clause of the struct. If generated code
+/// relies on bounds the struct doesn't declare, e.g. the `compare_with`
+/// constructor requires `T: Default`, they may be added to the generated impls
+/// with `#[check_initial_state(bound = "T: Default")]`, like
+/// `#[serde(bound = "...")]` does. Then the generated methods are available for
+/// the types satisfying the bounds only.
+///
+/// Fields of associated type projections, e.g. `Option<<T as Config>::Output>`
+/// or `Option<T::Output>`, are checked like any other `Option` fields, and the
+/// projected types need no bounds either. `FIELD_TYPES` renders them as they
+/// are written.
+///
+/// # Initial constant
+/// `initial_const` option, i.e. `#[check_initial_state(initial_const)]`,
+/// generates `INITIAL` associated constant. All checked fields of the constant
+/// are in initial state, e.g. `None`, while fields annotated with `ignore_field` must specify their
+/// const&#x2011;constructible initial values:
+/// ```
+/// # use check_initial_state_derive::CheckInitialState;
+/// #
+/// #[derive(CheckInitialState)]
+/// #[check_initial_state(initial_const)]
+/// struct Builder {
+///     option: Option<i32>,
+///     #[ignore_field(default = "10")]
+///     integer: i32,
+/// }
+///
+/// static TEMPLATE: Builder = Builder::INITIAL;
+/// ```
+///
+/// # Builder function
+/// `builder_fn` option generates `builder()` associated function, which
+/// creates an instance in initial state the same way. Since the macro fully
+/// controls the body, the constructor cannot drift from the checked
+/// invariant. Unlike `INITIAL`, default values of ignored fields need not be
+/// constant, and fields with `smart-default` defaults get their default
+/// values. The function has the visibility of the struct, and may be renamed,
+/// e.g. `builder_fn = "new_builder"`:
+/// ```
+/// # use check_initial_state_derive::CheckInitialState;
+/// #
+/// #[derive(CheckInitialState)]
+/// #[check_initial_state(builder_fn)]
+/// struct Builder {
+///     option: Option<i32>,
+///     #[expect_zero]
+///     retries: u32,
+///     #[ignore_field(default = "String::from(\"localhost\")")]
+///     host: String,
+/// }
+///
+/// let builder = Builder::builder();
+/// builder.check_initial_state();
+/// assert_eq!(builder.host, "localhost");
+/// ```
+/// `derive_default` option implements `Default` the same way. So ignored
+/// fields of types without `Default`, e.g. `std::time::Instant`, need nothing
+/// but `#[ignore_field(default = Instant::now())]`.
+///
+/// # Introspection
+/// `FIELD_NAMES` and `FIELD_TYPES` associated constants are generated as well.
+/// They list names and types, as written in source, of checked fields. So
+/// tooling may audit payload types a builder carries without parsing the
+/// source.
+///
+/// # Field metadata
+/// `fields()` associated `const fn` returns `FieldMeta` of each field, checked
+/// or ignored, in the order of declaration. It holds the name of the field,
+/// whether the field is ignored and why, and the group specified with
+/// `#[field_group = "..."]` attribute. The attribute does not affect checks.
+/// Since the function is `const`, coverage may be audited at build time:
+/// ```
+/// # use check_initial_state_derive::CheckInitialState;
+/// #
+/// #[derive(CheckInitialState)]
+/// struct Builder {
+///     #[field_group = "network"]
+///     host: Option<String>,
+///     #[ignore_field(reason = "Is set by the scheduler")]
+///     attempt: u32,
+/// }
+///
+/// const _: () = assert!(Builder::fields().len() == 2);
+/// const _: () = assert!(Builder::fields()[1].ignored());
+/// assert_eq!(Builder::fields()[0].name(), "host");
+/// assert_eq!(Builder::fields()[0].group(), Some("network"));
+/// assert_eq!(Builder::fields()[1].reason(), Some("Is set by the scheduler"));
+/// ```
+///
+/// # Error bits
+/// `error_bits` option, i.e. `#[check_initial_state(error_bits)]`, generates
+/// `initial_state_errors()` method for firmware, where panics are not
+/// acceptable. The method neither panics nor allocates, and returns `u32` with
+/// a bit set for each checked field which is not in initial state.
+/// `INITIAL_STATE_ERROR_BITS` associated constant maps each bit to the name of
+/// its field. Up to 32 checked fields are supported:
+/// ```
+/// # use check_initial_state_derive::CheckInitialState;
+/// #
+/// #[derive(CheckInitialState)]
+/// #[check_initial_state(error_bits)]
+/// struct Config {
+///     baud_rate: Option<u32>,
+///     #[expect_false]
+///     started: bool,
+/// }
+///
+/// let config = Config { baud_rate: Some(9600), started: false };
+/// assert_eq!(config.initial_state_errors(), 0b01);
+/// assert_eq!(Config::INITIAL_STATE_ERROR_BITS, [(0b01, "baud_rate"), (0b10, "started")]);
+/// ```
+///
+/// # Lazy violations
+/// `violations_iter` option, i.e. `#[check_initial_state(violations_iter)]`,
+/// generates `violations()` method for hot loops, e.g. fuzzers repeatedly
+/// verifying builder state. It returns an iterator, which lazily yields names
+/// of checked fields which are not in initial state without allocating. So
+/// stopping at the first yielded name skips checks of the following fields:
+/// ```
+/// # use check_initial_state_derive::CheckInitialState;
+/// #
+/// #[derive(CheckInitialState)]
+/// #[check_initial_state(violations_iter)]
+/// struct Builder {
+///     option: Option<i32>,
+///     #[expect_false]
+///     started: bool,
+/// }
+///
+/// let builder = Builder { option: Some(1), started: true };
+/// assert_eq!(builder.violations().next(), Some("option"));
+/// assert_eq!(builder.violations().collect::<Vec<_>>(), ["option", "started"]);
+/// ```
+///
+/// # Field enum
+/// `field_enum` option, i.e. `#[check_initial_state(field_enum)]`, generates
+/// an enum named after the struct with `Field` suffix, which has a variant for
+/// each checked field in upper camel case. `try_check_initial_state_fields()`
+/// method reports fields which are not in initial state as its variants, in
+/// the order of declaration. So tests may match violations exhaustively, and
+/// the compiler flags them once a field is renamed. `name()` method of the
+/// enum returns the field name used in messages. The enum implements `Display`
+/// and `FromStr` with field names as they are written in the struct, so
+/// violations may be filtered by names read from configuration. The enum has
+/// the visibility of the struct:
+/// ```
+/// # use check_initial_state_derive::CheckInitialState;
+/// #
+/// #[derive(CheckInitialState)]
+/// #[check_initial_state(field_enum)]
+/// struct Builder {
+///     option: Option<i32>,
+///     #[expect_false]
+///     started: bool,
+/// }
+///
+/// let builder = Builder { option: None, started: true };
+/// assert_eq!(builder.try_check_initial_state_fields(), Err(vec![BuilderField::Started]));
+/// assert_eq!(BuilderField::Started.name(), "started");
+/// assert_eq!("started".parse(), Ok(BuilderField::Started));
+/// assert_eq!(BuilderField::Option.to_string(), "option");
+/// ```
+///
+/// # State comparison
+/// `same_state_as()` method compares whether checked fields of two instances
+/// are set, ignoring their values, and panics on the first field which is set
+/// in one instance only. So a test may assert that a round&#x2011;trip or a
+/// reset leaves the builder in an equivalent state:
+/// ```should_panic
+/// # use check_initial_state_derive::CheckInitialState;
+/// #
+/// #[derive(CheckInitialState, Default)]
+/// struct Builder {
+///     option: Option<i32>,
+/// }
+///
+/// Builder { option: Some(1) }.same_state_as(&Builder { option: Some(2) });
+/// // Panics with "Field `option` is Some in this instance, but None in the
+/// // other one"
+/// Builder { option: Some(1) }.same_state_as(&Builder::default());
+/// ```
+///
+/// # Field state visitor
+/// `for_each_field_state()` method calls a closure with the name of each
+/// checked field and whether the field is set, in the order of declaration.
+/// So custom reports may be built without allocations in the derive itself:
+/// ```
+/// # use check_initial_state_derive::CheckInitialState;
+/// #
+/// #[derive(CheckInitialState)]
+/// struct Builder {
+///     option: Option<i32>,
+///     #[expect_false]
+///     started: bool,
+/// }
+///
+/// let mut set_count = 0;
+/// Builder { option: Some(1), started: false }.for_each_field_state(|_, set| {
+///     set_count += usize::from(set);
+/// });
+/// assert_eq!(set_count, 1);
+/// ```
+///
+/// `check_with_observer()` method reports the same states to
+/// `InitialStateObserver` trait of the runtime crate, followed by a violation
+/// for each field which is not in initial state, and completion of the check.
+/// Nothing panics, so custom test frameworks control the output and whether
+/// violations fail.
+///
+/// # rstest fixture
+/// With `rstest` feature enabled, `fixture` option generates an
+/// [rstest](https://crates.io/crates/rstest) fixture, named after the struct in
+/// snake case. The fixture returns an instance constructed by the first of
+/// listed constructors and verified with `check_initial_state()`. So
+/// parameterized tests may depend on a guaranteed clean builder. The crate
+/// using the fixture must depend on `rstest`:
+/// ```ignore
+/// #[derive(CheckInitialState)]
+/// #[check_initial_state(constructor = "new", fixture)]
+/// struct HttpBuilder {
+///     timeout: Option<u32>,
+/// }
+///
+/// #[rstest]
+/// fn sets_timeout(mut http_builder: HttpBuilder) {
+///     http_builder.timeout = Some(10);
+/// }
+/// ```
+///
+/// # bon builders
+/// With `bon` feature enabled, `bon` option of a struct deriving
+/// [bon](https://crates.io/crates/bon)'s `Builder` generates a test, which
+/// creates the builder with its start function, e.g. `builder()`, and verifies
+/// its state. Named members are expected to be unset, while custom fields,
+/// i.e. members annotated with `#[builder(field)]`, are checked by the same
+/// attributes as the fields of the struct. Fields annotated with
+/// `ignore_field` are skipped. Structs with type parameters or start function
+/// arguments are not supported:
+/// ```ignore
+/// #[derive(Builder, CheckInitialState)]
+/// #[check_initial_state(bon)]
+/// struct Request {
+///     #[builder(field)]
+///     #[expect_zero]
+///     retries: u32,
+///     timeout: Option<u32>,
+/// }
+/// ```
+///
+/// # Panic&#x2011;free checks
+/// With `no-panic` feature enabled, `no_panic` option generates
+/// `check_initial_state_no_panic()` method for code bases which forbid panics
+/// in any linked code. It returns the first field which is not in initial
+/// state as `Violation` of the runtime crate instead of panicking. The method
+/// is annotated with [no_panic](https://crates.io/crates/no-panic)'s
+/// attribute, so linking fails if it may panic, e.g. because `compare_with`
+/// constructor or `PartialEq` of `expect_value` may. Since `no_panic` relies
+/// on optimizations, the attribute is applied in builds without debug
+/// assertions only. The crate using the option must depend on `no-panic`:
+/// ```ignore
+/// #[derive(CheckInitialState)]
+/// #[check_initial_state(no_panic)]
+/// struct Builder {
+///     option: Option<i32>,
+/// }
+///
+/// let violation = Builder { option: Some(1) }.check_initial_state_no_panic().unwrap_err();
+/// assert_eq!(violation.field(), "option");
+/// ```
+///
+/// # buildstructor builders
+/// The builder of [buildstructor](https://crates.io/crates/buildstructor)
+/// keeps its state private to the generated module, so it cannot be inspected
+/// directly. Instead, `buildstructor` option adds a constructor, which calls
+/// the constructor through the builder without setting anything, i.e.
+/// `Self::builder().build()`. So constructor tests, mutation tests and other
+/// options relying on constructors verify what a fresh builder produces. All
+/// parameters of the constructor must be optional, e.g. `Option` or
+/// collections, otherwise the builder cannot be finished. Names of the entry
+/// function and the exit method may be overridden like in `builder` attribute
+/// of `buildstructor`, e.g. `buildstructor(entry = "request", exit = "send")`:
+/// ```ignore
+/// #[derive(CheckInitialState)]
+/// #[check_initial_state(buildstructor, empty_via(Vec = is_empty))]
+/// struct Request {
+///     timeout: Option<u32>,
+///     headers: Vec<String>,
+/// }
+///
+/// #[buildstructor::buildstructor]
+/// impl Request {
+///     #[builder]
+///     fn new(timeout: Option<u32>, headers: Vec<String>) -> Self {
+///         return Self { timeout, headers };
+///     }
+/// }
+/// ```
+///
+/// # Kani proofs
+/// With `kani` feature enabled, `kani_proof` option generates a
+/// [Kani](https://github.com/model-checking/kani) proof harness for each of
+/// listed constructors. The harness proves that the constructed instance
+/// passes `check_initial_state()` for all possible executions, e.g. whatever
+/// values nondeterministic inputs of the constructor take. Harnesses are
+/// compiled by `cargo kani` only, which sets `kani` cfg. So the crate using the
+/// option should declare the cfg in its `Cargo.toml` to avoid
+/// `unexpected_cfgs` warnings:
+/// ```toml
+/// [lints.rust]
+/// unexpected_cfgs = { level = "warn", check-cfg = ["cfg(kani)"] }
+/// ```
+///
+/// # Foreign structs
+/// A struct of another crate cannot be annotated with the derive. Like `serde`
+/// remote derive, a local mirror of it may be annotated with
+/// `#[check_initial_state(remote = "other_crate::Builder")]` instead. Fields of
+/// the mirror define which fields of the foreign struct are checked and how,
+/// so they must be accessible. `CheckInitialState` trait cannot be implemented
+/// for a foreign type, so `check_initial_state()` and
+/// `try_check_initial_state()` are generated as associated functions of the
+/// mirror, which take a reference to the foreign struct. Options which
+/// construct `Self`, e.g. `constructor` or `initial_const`, cannot be combined
+/// with `remote`.
+/// ```
+/// # use check_initial_state_derive::CheckInitialState;
+/// mod other_crate {
+///     pub struct Builder {
+///         pub option: Option<i32>,
+///         pub enabled: bool,
+///     }
+/// }
+///
+/// #[derive(CheckInitialState)]
+/// #[check_initial_state(remote = "other_crate::Builder")]
+/// #[allow(dead_code)]
+/// struct BuilderDef {
+///     option: Option<i32>,
+///     #[expect_false]
+///     enabled: bool,
+/// }
+///
+/// # fn main() {
+/// let builder = other_crate::Builder { option: None, enabled: false };
+/// BuilderDef::check_initial_state(&builder);
+/// # }
+/// ```
+///
+/// # Delegated checks
+/// A facade, which owns the real builder internally, may forward its check to
+/// the builder by annotating the field with `delegate_check` attribute. The
+/// field type must implement `CheckInitialState` trait, while other fields are
+/// not checked. Panic messages and reports name fields of the delegated field.
+/// Constructors are verified as usual, while options concerning individual
+/// fields, e.g. `mutation_test` or `error_bits`, cannot be applied:
+/// ```
+/// # use check_initial_state_derive::CheckInitialState;
+/// #
+/// #[derive(CheckInitialState, Default)]
+/// struct Builder {
+///     option: Option<i32>,
+/// }
+///
+/// #[derive(CheckInitialState)]
+/// struct Facade {
+///     #[delegate_check]
+///     builder: Builder,
+///     name: String,
+/// }
+/// ```
+///
+/// # Transparent newtypes
+/// The check of a `#[repr(transparent)]` newtype is delegated to the wrapped
+/// field without annotations, if it is the only field, which is neither
+/// ignored nor `PhantomData`, and has no field attributes. So annotations of
+/// the wrapped type needn't be duplicated. A wrapped `Option` is checked as
+/// usual:
+/// ```
+/// # use check_initial_state_derive::CheckInitialState;
+/// #
+/// #[derive(CheckInitialState, Default)]
+/// struct Builder {
+///     option: Option<i32>,
+///     #[expect_false]
+///     enabled: bool,
+/// }
+///
+/// #[derive(CheckInitialState)]
+/// #[check_initial_state(constructor = Self(Builder::default()))]
+/// #[repr(transparent)]
+/// struct Wrapper(Builder);
+/// ```
+///
+/// # Inherited checks
+/// A builder composed of fields of other builders, e.g. embedded by a macro or
+/// code generation, may name them with `inherit_checks` attribute. Fields
+/// checked by the named structs must then be checked by the composed one as
+/// well, so ignoring an embedded field or dropping it fails compilation. The
+/// named structs must derive `CheckInitialState` without delegating their
+/// checks, and the verification requires Rust 1.57 or newer:
+/// ```
+/// # use check_initial_state_derive::CheckInitialState;
+/// #
+/// #[derive(CheckInitialState)]
+/// struct Network {
+///     host: Option<String>,
+///     port: Option<u16>,
+/// }
+///
+/// #[derive(CheckInitialState)]
+/// #[inherit_checks(Network)]
+/// struct Builder {
+///     host: Option<String>,
+///     // Ignoring the field fails compilation with
+///     // "Field `port` is checked by `Network`, but is ignored in `Builder`"
+///     port: Option<u16>,
+///     user: Option<String>,
+/// }
+/// #
+/// # fn main() {}
+/// ```
+///
+/// # Runtime crate path
+/// Generated code refers to the runtime crate as `::check_initial_state`.
+/// Frameworks, which re&#x2011;export it under their own name, may override
+/// the path with `crate` option, like `#[serde(crate = "...")]` does, so their
+/// users needn't depend on `check-initial-state` directly:
+/// ```
+/// # use check_initial_state_derive::CheckInitialState;
+/// #
+/// mod facade {
+///     pub use check_initial_state as check;
+/// }
+///
+/// #[derive(CheckInitialState)]
+/// #[check_initial_state(crate = "crate::facade::check")]
+/// struct Builder {
+///     option: Option<i32>,
+/// }
+/// #
+/// # fn main() {}
+/// ```
+///
+/// # Visibility of generated items
+/// Associated functions and constants, free functions of `remote` mode and the
+/// enum of `field_enum` option have the visibility of the struct. `visibility`
+/// option overrides it, e.g. to keep test helpers of a public struct private to
+/// the crate:
+/// ```
+/// # use check_initial_state_derive::CheckInitialState;
+/// #
+/// #[derive(CheckInitialState)]
+/// #[check_initial_state(builder_fn, visibility = "pub(crate)")]
+/// pub struct Builder {
+///     option: Option<i32>,
+/// }
+///
+/// Builder::builder().check_initial_state();
+/// ```
+///
+/// # Workspace defaults
+/// Defaults of some container options may be defined once for a package or a
+/// whole workspace in `check_initial_state.toml`. The file is looked up in the
+/// package directory and then in its ancestors up to the workspace root, i.e.
+/// the directory whose `Cargo.toml` declares `[workspace]` table. Only the
+/// package directory is searched if the package is not a member of a
+/// workspace, e.g. if it is published to a registry. Options of
+/// `check_initial_state` attribute are applied on top of the defaults. All
+/// keys are optional:
+/// ```toml
+/// # Makes `lenient` option the default
+/// lenient = true
+/// # Makes `warn_ignored_option` option the default
+/// warn_ignored_option = true
+/// # Makes `serde` option the default
+/// serde = true
+/// # How to treat structs with all fields ignored: "allow", "warn" or "deny"
+/// empty_check = "deny"
+/// # Default of `msrv` option, which takes precedence over `rust-version`
+/// msrv = "1.40"
+/// # Default of `setter_hint` option, i.e. the naming convention of setters
+/// setter_hint = "with_{}"
+/// # Default of `visibility` option
+/// visibility = "pub(crate)"
+/// ```
+/// `strict = true` enables strict mode, which makes both `warn_ignored_option`
+/// and `deny_empty_check` options the default. So it cannot be combined with
+/// `warn_ignored_option` and `empty_check` keys.
+///
+/// The file is parsed without a TOML library, so it supports the subset of
+/// TOML the keys need. Each line is either blank, a comment starting with `#`,
+/// or `key = value` optionally followed by a comment. The key is bare, while
+/// the value is `true`, `false`, a basic string without escapes, e.g.
+/// `"deny"`, or a literal string, e.g. `'deny'`. Tables, dotted or quoted keys,
+/// arrays, numbers, escapes and multi&#x2011;line strings are rejected. A
+/// malformed file fails each derive with a compile error naming the line. The
+/// generated code includes the file, so it is re&#x2011;expanded whenever the
+/// file changes.
+///
+/// # Disabling checks
+/// Setting `CHECK_INITIAL_STATE_DISABLED` environment variable to any
+/// non&#x2011;empty value except `0` at compile time, e.g.
+/// `CHECK_INITIAL_STATE_DISABLED=1 cargo build --release`, disables all checks
+/// without touching the source. The generated methods are kept, so the code
+/// calling them still compiles, but they verify nothing: `check_initial_state()`
+/// never panics, while reports contain no violations. Constructor tests and
+/// Kani proofs are not generated. Cargo rebuilds the crate using the derive
+/// whenever the variable changes.
+///
+/// # Minimal Rust version
+/// Generated `check_initial_state()` is annotated with `#[track_caller]`, so
+/// the panic points to its caller. This and other conveniences of the
+/// generated code require newer compilers, so alternative code is generated
+/// for older ones. The minimal Rust version is taken from `rust-version` of the
+/// package, and may be overridden with `msrv` option, e.g.
+/// `#[check_initial_state(msrv = "1.40")]`. The newest code is generated if
+/// neither is specified.
+///
+/// # Compile time
+/// Derives parse nothing but the struct and its attributes, so they don't need
+/// `full` features of `syn`. The features are required by
+/// `check_all_builders`, `checked_constructor`, `post_build_check` and
+/// `verify_initial_literals` attribute macros only, which parse whole items.
+/// These macros are provided by `item-macros` feature, which is disabled by
+/// default, so `syn` is built with the reduced feature set unless another
+/// dependency enables it. Then constructors and other expressions of the
+/// options are parsed by `syn` in a reduced form as well: paths, calls, method
+/// calls, literals and operators are supported, while e.g. closures and blocks
+/// are not. Crates using the attribute macros enable the feature, which used
+/// to be enabled by default:
+/// ```toml
+/// [dependencies]
+/// check-initial-state = { version = "...", features = ["item-macros"] }
+/// ```
+///
+/// # Code coverage
+/// Generated methods and tests are mostly panic arms, which are reached only if
+/// a check fails. With `coverage` feature enabled, they are annotated with
+/// `#[cfg_attr(coverage_nightly, coverage(off))]`, so they don't pollute
+/// coverage reports. `cargo llvm-cov` sets `coverage_nightly` cfg on a nightly
+/// toolchain, while the crate using the derive has to enable the unstable
+/// attribute itself:
+/// ```ignore
+/// #![cfg_attr(coverage_nightly, feature(coverage_attribute))]
+/// ```
+/// and declare the cfg in its `Cargo.toml` to avoid `unexpected_cfgs` warnings:
+/// ```toml
+/// [lints.rust]
+/// unexpected_cfgs = { level = "warn", check-cfg = ["cfg(coverage_nightly)"] }
+/// ```
+///
+/// # Performance
+/// `check_initial_state()` is `#[inline]`, since it merely scans the checked
+/// fields. The panic message, with the context, the state table and the setter
+/// hint, is built by a separate `#[cold]` function which is never inlined. So
+/// checks called on hot paths, e.g. via `debug_assert!`&#x2011;like wrappers,
+/// don't bloat their callers.
+///
+/// # Debugging
+/// Setting `CHECK_INITIAL_STATE_DEBUG` environment variable makes the macro
+/// dump the code generated for each struct at compile time: to stderr if the
+/// value is `1`, or to `<Struct>.rs` files in the directory the value points
+/// to otherwise, e.g. `CHECK_INITIAL_STATE_DEBUG=target/expansions`. Cargo
+/// doesn't track the variable, so the crate has to be touched to be
+/// re&#x2011;expanded.
+///
+/// # Reports
+/// `write_initial_state_report()` method is generated along with
+/// `check_initial_state()`. It writes a line for each checked field which is
+/// not in initial state to an arbitrary `std::io::Write` sink, e.g. a log file,
+/// instead of panicking.
+///
+/// Panic messages, report lines and `defmt` logs may be tagged for CI log
+/// scrapers with `message_prefix` and `message_suffix` options, e.g.
+/// `#[check_initial_state(message_prefix = "[builder-hygiene] ")]`.
+///
+/// `setter_hint` option, e.g. `#[check_initial_state(setter_hint = "set_{}")]`,
+/// specifies setters naming convention with `{}` standing for a field name.
+/// Then the panic message of `check_initial_state()` suggests the setter which
+/// probably assigned a stray value, e.g. "Field `endpoint` has Some value
+/// instead of None, was it set via `set_endpoint(...)`?". The convention of
+/// `setter` option is used if `setter_hint` is not specified. Nothing is
+/// suggested for fields which are expected to be set, or in `compare_with`
+/// mode.
+///
+/// `check_initial_state_with_context()` method checks fields like
+/// `check_initial_state()`, but prepends a caller&#x2011;supplied context to
+/// the panic message. So shared test helpers may label which scenario
+/// constructed the offending builder, e.g.
+/// `builder.check_initial_state_with_context("retry scenario")` panics with
+/// "retry scenario: Field `option` has Some value instead of None". Nothing is
+/// prepended if the context is empty. The context is not a part of
+/// `panic_payload` payload.
+///
+/// With `panic_payload` option, i.e. `#[check_initial_state(panic_payload)]`,
+/// `check_initial_state()` panics with `check_initial_state::InitialStatePanic`
+/// payload naming the struct and its first field which is not in initial state.
+/// Tests catching the panic with `std::panic::catch_unwind()` may downcast the
+/// payload instead of matching the message. Since the payload is not a string,
+/// `#[should_panic(expected = "...")]` cannot match it.
+///
+/// With `table` feature enabled, the panic message of `check_initial_state()`
+/// is followed by an aligned table of all checked fields with their states, so
+/// a single failure shows the whole state of the instance. Fields which are
+/// not in initial state are marked with `*`:
+/// ```text
+/// Field `enabled` is true instead of false
+/// Fields of `Builder`:
+///     option  | None
+///   * enabled | true
+/// ```
+/// With `#[check_initial_state(table_values)]`, the table also prints values of
+/// fields implementing `Debug`. Values longer than 100 characters are truncated
+/// with an ellipsis, so huge payloads don't flood test logs. The limit may be
+/// changed with `max_value_len` option, e.g.
+/// `#[check_initial_state(table_values, max_value_len = 40)]`. Fields holding
+/// secrets, e.g. tokens or passwords, may be annotated with `#[redact]`, so
+/// `<redacted>` is printed instead of their values. The crate using the derive
+/// must depend on `check-initial-state` with `table` feature. The table is not
+/// appended to the payload of `panic_payload` option.
+///
+/// `derive_display` option, i.e. `#[check_initial_state(derive_display)]`,
+/// implements `Display` rendering the same table regardless of `table`
+/// feature. So a failing test may simply print the builder with
+/// `println!("{builder}")` to see its whole construction state. Values are
+/// rendered with `table_values` option only. The crate using the derive must
+/// depend on `check-initial-state`.
+///
+/// With `provenance` feature enabled, a field of
+/// `check_initial_state::provenance::Provenance` type may be annotated with
+/// `#[provenance]`. Such field is not checked, while `set_<field>_tracked()`
+/// method is generated for each checked field. The method assigns the field
+/// and records the caller location, so the panic message of
+/// `check_initial_state()` names the file and line which set the stray value,
+/// e.g. "Field `option` has Some value instead of None, set at
+/// src/builder.rs:10:14". Setters of the struct may call the method, being
+/// annotated with `#[track_caller]` to record their own callers. The crate
+/// using the derive must depend on `check-initial-state` with `provenance`
+/// feature.
+///
+/// With `json` feature enabled, `initial_state_report_json()` method is
+/// generated in addition. It returns all checked fields which are not in
+/// initial state in machine&#x2011;readable form:
+/// ```json
+/// {"struct":"Builder","violations":[{"field":"option","reason":"has Some value instead of None"}]}
+/// ```
+///
+/// With `defmt` feature enabled, `initial_state_report_defmt()` method is
+/// generated for embedded targets. It logs each checked field which is not in
+/// initial state with [defmt](https://crates.io/crates/defmt) `error!` instead
+/// of panicking, and returns whether all checked fields are in initial state.
+/// Field names are interned as a part of format strings. The crate using the
+/// derive must depend on `defmt`.
+///
+/// With `metrics` feature enabled, `check_initial_state()` and
+/// `try_check_initial_state()` report how often they run via
+/// [metrics](https://crates.io/crates/metrics) facade, e.g. to soak tests.
+/// `check_initial_state.checks` counter is incremented per call, while
+/// `check_initial_state.violations` counter is incremented per field which is
+/// not in initial state. Counters are labeled with `struct` name, and the latter
+/// with `field` name as well. The crate using the derive must depend on
+/// `metrics`.
+#[proc_macro_derive(
+	CheckInitialState,
+	attributes(
+		ignore_field,
+		check_initial_state,
+		expect_false,
+		expect_true,
+		expect_zero,
+		expect_matches,
+		option_alias,
+		report_name,
+		redact,
+		delegate_check,
+		provenance,
+		field_group,
+		inherit_checks,
+	),
+)]
 pub fn check_initial_state_derive(input: TokenStream) -> TokenStream {
 	let ast = parse_macro_input!(input as DeriveInput);
-	let idents = {
-		let data = match extract_struct(&ast) {
-			Ok(data) => data,
-			Err(err) => return err,
-		};
-		let fields = match fetch_fields(&data, &ast) {
-			Ok(fields) => fields,
-			Err(err) => return err,
-		};
-		fields.iter()
-			// Filter out fields with `ignore_field` attribute
-			.filter(|field| {
-				return field.attrs.iter()
-					.filter(|attribute| attribute.path.segments.first().is_some())
-					.find(|attribute| {
-						return attribute.path.segments.first().unwrap()
-							.ident.to_string() == IGNORE_FIELD_ATTRIBUTE;
-					})
-					.is_none();
-			})
-			.map(|field| {
-				return match field.ident.as_ref() {
-					Some(field) => field,
-					None => {
-						panic!(
-							"Unexpected implementation error occurred. Reason: Field `{:?}` is \
-								expected to have name while it does not",
-							field,
-						);
-					},
-				};
-			})
-	};
-	let struct_name = &ast.ident;
-	let (leading_generics, trailing_generics, where_clause) = &ast.generics.split_for_impl();
-	let impl_header = if where_clause.is_some() {
-		let where_clause = where_clause.unwrap();
-		quote! { impl #leading_generics #struct_name #trailing_generics #where_clause }
-	} else {
-		quote! { impl #leading_generics #struct_name #trailing_generics }
-	};
-	let field_checks = idents.map(|ident| {
-		let error_message = format!("Field `{}` has Some value instead of None", ident);
-		return quote! {
-			if ::std::option::Option::is_some(&self.#ident) {
-				panic!(#error_message);
-			};
-		};
-	});
-	let result = quote! {
-		#impl_header {
-			/// Checks all `Option` fields to have `None` at the time of this
-			/// method call. Is expected to be used for testing purposes.
-			/// # Panics
-			/// Any of `self` fields, which are not annotated with
-			/// `ignore_field`, are `Some`. Panic message will contain the name
-			/// of an `Option` field which has some value.
-			fn check_initial_state(&self) {
-				#(#field_checks)*
-			}
-		}
-	};
-	return result.into();
+	return check_initial_state_core::expand(&ast).into();
 }
 
-/// Fetches struct from `input`. Parsing error is returned in case of data
-/// structure for this procedural macro is other than a struct with named
-/// fields.
-fn extract_struct(input: &DeriveInput) -> Result<&DataStruct, TokenStream> {
-	return match &input.data {
-		Data::Struct(data_struct) => Ok(data_struct),
-		_ => {
-			let message = Error::new_spanned(
-				&input,
-				"`CheckInitialState` procedural macro is allowed for structs with named fields \
-					only",
-			)
-				.to_compile_error()
-				.into();
-			Err(message)
-		},
-	};
+/// Creates `check_any_set()` method, which is the complement of
+/// `check_initial_state()`. It panics if all checked fields are in initial
+/// state, e.g. all `Option` fields are `None`. So tests may verify that a
+/// setter actually recorded something into a builder. Fields are selected and
+/// checked by the same attributes and `check_initial_state` options as
+/// `CheckInitialState` derive uses, so both derives may be applied to the same
+/// struct. Neither `remote` option nor `delegate_check` attribute is
+/// supported.
+///
+/// `check_exactly_n_set(n)` method is created as well. It panics unless
+/// exactly `n` checked fields are out of initial state, listing such fields.
+/// So step&#x2011;by&#x2011;step tests may verify incremental population of a
+/// builder.
+/// ```
+/// use check_initial_state_derive::{CheckAnySet, CheckInitialState};
+///
+/// #[derive(CheckAnySet, CheckInitialState, Default)]
+/// struct Builder {
+///     option: Option<i32>,
+///     #[expect_false]
+///     enabled: bool,
+/// }
+///
+/// impl Builder {
+///     fn enable(&mut self) {
+///         self.enabled = true;
+///     }
+/// }
+///
+/// let mut builder = Builder::default();
+/// builder.check_initial_state();
+/// builder.enable();
+/// builder.check_any_set();
+/// builder.check_exactly_n_set(1);
+/// ```
+/// # Panics
+/// `check_any_set()` panics if all checked fields are in initial state. Panic
+/// message lists the checked fields. `check_exactly_n_set(n)` panics if other
+/// number of checked fields is out of initial state.
+#[proc_macro_derive(
+	CheckAnySet,
+	attributes(
+		ignore_field,
+		check_initial_state,
+		expect_false,
+		expect_true,
+		expect_zero,
+		expect_matches,
+		option_alias,
+		report_name,
+		redact,
+		delegate_check,
+		provenance,
+		field_group,
+	),
+)]
+pub fn check_any_set_derive(input: TokenStream) -> TokenStream {
+	let ast = parse_macro_input!(input as DeriveInput);
+	return check_initial_state_core::expand_check_any_set(&ast).into();
+}
+
+/// Creates `check_phase(n)` method, which encodes the intended construction
+/// order of a builder as an invariant verified at runtime. Fields are grouped
+/// into phases with `phase` attribute, e.g. `#[phase = 1]`. The method checks
+/// all fields of phases earlier than `n` to be out of initial state, e.g.
+/// `Some`, and all fields of later phases to be in initial state, e.g. `None`.
+/// Fields of phase `n` itself may be in either state, since they are being
+/// populated. Fields without `phase` attribute are not checked. Fields are
+/// checked by the same attributes and `check_initial_state` options as
+/// `CheckInitialState` derive uses. Neither `remote` option nor
+/// `delegate_check` attribute is supported.
+/// ```
+/// use check_initial_state_derive::CheckPhase;
+///
+/// #[derive(CheckPhase, Default)]
+/// struct RequestBuilder {
+///     #[phase = 1]
+///     url: Option<String>,
+///     #[phase = 2]
+///     #[expect_false]
+///     authorized: bool,
+///     #[phase = 3]
+///     body: Option<Vec<u8>>,
+/// }
+///
+/// let mut builder = RequestBuilder::default();
+/// builder.check_phase(1);
+/// builder.url = Some("https://example.com".to_owned());
+/// builder.check_phase(2);
+/// builder.authorized = true;
+/// builder.check_phase(3);
+/// ```
+/// # Panics
+/// `check_phase(n)` panics if a field of an earlier phase is in initial state,
+/// or a field of a later phase is not. Panic message contains the name and the
+/// phase of such field.
+#[proc_macro_derive(
+	CheckPhase,
+	attributes(
+		phase,
+		ignore_field,
+		check_initial_state,
+		expect_false,
+		expect_true,
+		expect_zero,
+		expect_matches,
+		option_alias,
+		report_name,
+		redact,
+		delegate_check,
+		provenance,
+		field_group,
+	),
+)]
+pub fn check_phase_derive(input: TokenStream) -> TokenStream {
+	let ast = parse_macro_input!(input as DeriveInput);
+	return check_initial_state_core::expand_check_phase(&ast).into();
+}
+
+/// Creates `check_invariants()` method, which verifies relationships between
+/// fields rather than the state of each field. Invariants are declared as
+/// boolean expressions referring to the instance as `self`, with `invariant`
+/// attributes of the struct, e.g.
+/// `#[invariant(self.start.is_none() || self.end.is_none())]`. The method
+/// checks them in the order of declaration. `check_initial_state` options,
+/// which tag panic messages, add bounds or disable checks, are applied the same
+/// way as `CheckInitialState` derive applies them, while `remote` option is
+/// not supported.
+/// ```
+/// use check_initial_state_derive::{CheckInitialState, CheckInvariants};
+///
+/// #[derive(CheckInitialState, CheckInvariants, Default)]
+/// #[invariant(self.start.is_none() || self.end.is_none())]
+/// #[invariant(self.retries <= 5)]
+/// struct RangeBuilder {
+///     start: Option<u32>,
+///     end: Option<u32>,
+///     #[ignore_field]
+///     retries: u8,
+/// }
+///
+/// let builder = RangeBuilder::default();
+/// builder.check_initial_state();
+/// builder.check_invariants();
+/// ```
+/// # Panics
+/// `check_invariants()` panics if any of the invariants doesn't hold. Panic
+/// message contains the expression of the first such invariant.
+#[proc_macro_derive(CheckInvariants, attributes(invariant, check_initial_state))]
+pub fn check_invariants_derive(input: TokenStream) -> TokenStream {
+	let ast = parse_macro_input!(input as DeriveInput);
+	return check_initial_state_core::expand_check_invariants(&ast).into();
+}
+
+/// Checks the listed fields of an instance to be in initial state, the same
+/// way `check_initial_state()` method created by `CheckInitialState` derive
+/// does. Is intended for third&#x2011;party types, which cannot be annotated
+/// with the derive. The first argument is the instance or a reference to it,
+/// while the second one is its type followed by the checked fields in braces.
+/// The fields must be accessible at the call site.
+///
+/// Listed fields are expected to be `None`, unless they are annotated with a
+/// check attribute, i.e. `expect_false`, `expect_true`, `expect_zero` or
+/// `expect_matches`. Fields annotated with `delegate_check` are checked with
+/// their own `CheckInitialState` implementation. Fields which are not listed
+/// are not checked.
+/// ```
+/// use check_initial_state::check_initial_state_for;
+///
+/// mod other_crate {
+///     pub struct Builder {
+///         pub option: Option<i32>,
+///         pub enabled: bool,
+///     }
+/// }
+///
+/// fn main() {
+///     let builder = other_crate::Builder { option: None, enabled: false };
+///     check_initial_state_for!(builder, other_crate::Builder {
+///         option,
+///         #[expect_false]
+///         enabled,
+///     });
+/// }
+/// ```
+/// # Panics
+/// Any of the listed fields is not in initial state. Panic message contains
+/// the name of such field.
+#[proc_macro]
+pub fn check_initial_state_for(input: TokenStream) -> TokenStream {
+	return check_initial_state_core::expand_check_for(input.into()).into();
 }
 
-/// Retrieves fields contained in `data_struct`. Parsing error is returned, if
-/// `data_struct` is not of expected type.
-/// # Parameters
-/// * `data_struct` &ndash; Expected to be a struct with named fields.
-/// * `input` &ndash; Is entire abstract syntax tree provided for this
-/// procedural macro. May be used to form a syntax error when `data_struct` is
-/// other than expected.
-fn fetch_fields<'a>(data_struct: &'a DataStruct, input: &DeriveInput)
-	-> Result<&'a Punctuated<Field, Comma>, TokenStream> {
-	return match &data_struct.fields {
-		Fields::Named(named_fields) => Ok(&named_fields.named),
-		Fields::Unit => {
-			let message = Error::new_spanned(
-				input,
-				"`CheckInitialState` procedural macro is no allowed for unit structs",
-			)
-				.to_compile_error()
-				.into();
-			Err(message)
-		},
-		fields => {
-			let message = Error::new_spanned(
-				fields,
-				"`CheckInitialState` procedural macro is allowed for structs with named fields \
-					only",
-			)
-				.to_compile_error()
-				.into();
-			Err(message)
-		},
-	};
+/// Applies `CheckInitialState` derive to each struct of the annotated inline
+/// module, which name matches a pattern. Removes per&#x2011;struct boilerplate
+/// in modules with many builders. The pattern is `*Builder` by default, and may
+/// be specified with `pattern` option, where each `*` stands for any sequence
+/// of characters, e.g. `#[check_all_builders(pattern = "*Config")]`. Structs
+/// which derive `CheckInitialState` already are left intact, as well as
+/// structs of nested modules. Matching structs may use all attributes of the
+/// derive.
+/// ```
+/// use check_initial_state::{check_all_builders, CheckInitialState};
+///
+/// #[check_all_builders]
+/// mod builders {
+///     pub struct ServerBuilder {
+///         pub port: Option<u16>,
+///     }
+///
+///     #[check_initial_state(lenient)]
+///     pub struct ClientBuilder {
+///         pub host: Option<String>,
+///         pub retries: u32,
+///     }
+///
+///     // Doesn't match the pattern, so is not checked
+///     pub struct Server {
+///         pub port: u16,
+///     }
+/// }
+///
+/// fn main() {
+///     // Inherent methods are private to the module, while the trait is
+///     // implemented publicly
+///     builders::ServerBuilder { port: None }.check_initial_state();
+///     builders::ClientBuilder { host: None, retries: 3 }.check_initial_state();
+/// }
+/// ```
+#[cfg(feature = "item-macros")]
+#[proc_macro_attribute]
+pub fn check_all_builders(args: TokenStream, item: TokenStream) -> TokenStream {
+	return check_initial_state_core::expand_check_all_builders(args.into(), item.into()).into();
+}
+
+/// Checks the instance returned by the annotated constructor to be in initial
+/// state, the same way `check_initial_state()` method created by
+/// `CheckInitialState` derive does. The check is performed in debug builds
+/// only, so release builds pay nothing. `async` constructors are supported as
+/// well: the check is performed once the constructed instance is ready. The
+/// function must return the constructed instance.
+/// ```
+/// use check_initial_state::{checked_constructor, CheckInitialState};
+///
+/// #[derive(CheckInitialState)]
+/// struct Builder {
+///     option: Option<i32>,
+/// }
+///
+/// impl Builder {
+///     #[checked_constructor]
+///     fn new() -> Self {
+///         Self { option: None }
+///     }
+///
+///     #[checked_constructor]
+///     async fn connect() -> Self {
+///         Self { option: None }
+///     }
+/// }
+///
+/// fn main() {
+///     Builder::new();
+/// }
+/// ```
+/// # Panics
+/// In debug builds, the returned instance is not in initial state.
+#[cfg(feature = "item-macros")]
+#[proc_macro_attribute]
+pub fn checked_constructor(args: TokenStream, item: TokenStream) -> TokenStream {
+	return check_initial_state_core::expand_checked_constructor(args.into(), item.into()).into();
+}
+
+/// Verifies the builder to be completely populated before the annotated
+/// finalizer, e.g. `build()`, proceeds. It is the counterpart of
+/// `check_initial_state()`, which checks the final state at the point of use
+/// rather than the initial one. All fields of `self`, which `CheckInitialState`
+/// derive checks, must be set, e.g. `Some`, while fields annotated with
+/// `ignore_field` are optional. The method must take `self` in any form, and
+/// must be defined in the module of the builder. Unset fields are reported
+/// with a panic listing them:
+/// ```
+/// use check_initial_state::{post_build_check, CheckInitialState};
+///
+/// #[derive(CheckInitialState, Default)]
+/// struct ServerBuilder {
+///     host: Option<String>,
+///     port: Option<u16>,
+/// }
+///
+/// impl ServerBuilder {
+///     #[post_build_check]
+///     fn build(self) -> (String, u16) {
+///         // Is verified to be set
+///         (self.host.unwrap(), self.port.unwrap())
+///     }
+/// }
+///
+/// fn main() {
+///     let builder = ServerBuilder { host: Some("localhost".to_owned()), port: Some(80) };
+///     assert_eq!(builder.build(), ("localhost".to_owned(), 80));
+/// }
+/// ```
+/// With `map_err` option, i.e. `#[post_build_check(map_err)]`,
+/// `MissingFieldsError` listing unset fields is returned instead, converted
+/// into the error type of the method with `From`, the same way `?` operator
+/// converts errors. So the method must return `Result`:
+/// ```
+/// use check_initial_state::{post_build_check, CheckInitialState, MissingFieldsError};
+///
+/// #[derive(CheckInitialState, Default)]
+/// struct ServerBuilder {
+///     host: Option<String>,
+///     port: Option<u16>,
+/// }
+///
+/// impl ServerBuilder {
+///     #[post_build_check(map_err)]
+///     fn build(self) -> Result<(String, u16), MissingFieldsError> {
+///         Ok((self.host.unwrap(), self.port.unwrap()))
+///     }
+/// }
+///
+/// fn main() {
+///     let error = ServerBuilder { port: Some(80), ..Default::default() }.build().unwrap_err();
+///     assert_eq!(error.fields(), ["host"]);
+/// }
+/// ```
+/// The method is left intact if checks are disabled with
+/// `CHECK_INITIAL_STATE_DISABLED` environment variable.
+/// # Panics
+/// Without `map_err` option, any of the checked fields is not set.
+#[cfg(feature = "item-macros")]
+#[proc_macro_attribute]
+pub fn post_build_check(args: TokenStream, item: TokenStream) -> TokenStream {
+	return check_initial_state_core::expand_post_build_check(args.into(), item.into()).into();
+}
+
+/// Analyzes constructors of the annotated `impl` block at compile time, so a
+/// debug value committed by mistake is caught before the program runs. Each
+/// associated function without a receiver is scanned for struct literals of
+/// the implemented type, e.g. `Self { .. }`. If such literal initializes a
+/// field with `Some(..)`, while `CheckInitialState` derive expects the field
+/// to be `None`, compilation fails. Fields which are ignored or annotated with
+/// a check attribute are not reported, as well as fields initialized with
+/// other expressions, e.g. a variable. Constructors which deliberately set
+/// fields may be annotated with `ignore_constructor`. The struct must derive
+/// `CheckInitialState` without `remote` option, and its crate must support
+/// Rust 1.57 at least.
+/// ```compile_fail
+/// use check_initial_state::{verify_initial_literals, CheckInitialState};
+///
+/// #[derive(CheckInitialState)]
+/// struct Builder {
+///     option: Option<i32>,
+/// }
+///
+/// #[verify_initial_literals]
+/// impl Builder {
+///     fn new() -> Self {
+///         // Fails to compile, since `option` is expected to be `None`
+///         Self { option: Some(1) }
+///     }
+///
+///     #[ignore_constructor]
+///     fn with_option(option: i32) -> Self {
+///         Self { option: Some(option) }
+///     }
+/// }
+/// ```
+#[cfg(feature = "item-macros")]
+#[proc_macro_attribute]
+pub fn verify_initial_literals(args: TokenStream, item: TokenStream) -> TokenStream {
+	return check_initial_state_core::expand_verify_initial_literals(args.into(), item.into())
+		.into();
 }
@@ -0,0 +1,98 @@
+use check_initial_state::IsUnset;
+use check_initial_state_derive::CheckInitialState;
+use std::collections::{BTreeSet, HashMap};
+
+/// Checks that standard and `IsUnset` types are checked without annotations.
+#[test]
+fn auto_unset() {
+	Builder::default().check_initial_state();
+}
+
+/// Checks that each kind of type is reported when set.
+#[test]
+fn set_fields_reported() {
+	let builder = Builder {
+		option: Some(1),
+		started: true,
+		retries: 2,
+		ratio: 0.5,
+		name: "name".to_owned(),
+		items: vec![1],
+		headers: vec![(1, 2)].into_iter().collect(),
+		tags: vec!["tag"].into_iter().collect(),
+		endpoint: Endpoint(Some(1)),
+		_opaque: Opaque,
+	};
+	let mut report = Vec::new();
+	builder.write_initial_state_report(&mut report).unwrap();
+	let expected = [
+		"Field `option` has Some value instead of None",
+		"Field `started` is set instead of unset",
+		"Field `retries` is set instead of unset",
+		"Field `ratio` is set instead of unset",
+		"Field `name` is set instead of unset",
+		"Field `items` is set instead of unset",
+		"Field `headers` is set instead of unset",
+		"Field `tags` is set instead of unset",
+		"Field `endpoint` is set instead of unset",
+	];
+	assert_eq!(String::from_utf8(report).unwrap().lines().collect::<Vec<_>>(), expected);
+}
+
+/// Checks that check attributes take precedence over the mode.
+#[test]
+#[should_panic(expected = "Field `ready` is false instead of true")]
+fn annotated_field() {
+	Annotated { ready: false, count: 0 }.check_initial_state();
+}
+
+/// Checks that the mode takes precedence over `lenient` for non-`Option`
+/// fields.
+#[test]
+#[should_panic(expected = "Field `count` is set instead of unset")]
+fn lenient_overridden() {
+	Lenient { option: None, count: 1 }.check_initial_state();
+}
+
+#[derive(Default)]
+struct Endpoint(Option<u32>);
+
+impl IsUnset for Endpoint {
+	fn is_unset(&self) -> bool {
+		self.0.is_none()
+	}
+}
+
+#[derive(Default)]
+struct Opaque;
+
+#[derive(CheckInitialState, Default)]
+#[check_initial_state(auto_unset)]
+struct Builder {
+	option: Option<i32>,
+	started: bool,
+	retries: u32,
+	ratio: f64,
+	name: String,
+	items: Vec<u8>,
+	headers: HashMap<u8, u8>,
+	tags: BTreeSet<&'static str>,
+	endpoint: Endpoint,
+	#[ignore_field]
+	_opaque: Opaque,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(auto_unset)]
+struct Annotated {
+	#[expect_true]
+	ready: bool,
+	count: usize,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(auto_unset, lenient)]
+struct Lenient {
+	option: Option<i32>,
+	count: u8,
+}
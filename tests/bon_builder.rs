@@ -0,0 +1,52 @@
+#![cfg(feature = "bon")]
+
+use bon::Builder;
+use check_initial_state_derive::CheckInitialState;
+
+/// Checks that the struct built by `bon` builder is checked as usual.
+#[test]
+#[should_panic(expected = "Field `timeout` has Some value instead of None")]
+fn built_struct_checked() {
+	Request::builder().url("url".to_owned()).timeout(10).build().check_initial_state();
+}
+
+/// Checks that a renamed start function is used, and custom fields keep their
+/// initial values, which the generated
+/// `__check_initial_state_bon_Renamed::bon_builder` test checks by their check
+/// attributes.
+#[test]
+fn custom_fields_initialized() {
+	let builder = Renamed::new_builder();
+	assert_eq!((builder.retries, builder.label), (0, Some("label")));
+	builder.name("name".to_owned()).build().check_initial_state();
+}
+
+/// Builder with named members only, which are expected to be unset.
+#[derive(Builder, CheckInitialState)]
+#[check_initial_state(bon)]
+struct Request {
+	#[ignore_field]
+	_url: String,
+	timeout: Option<u32>,
+	#[builder(default)]
+	#[expect_zero]
+	retries: u32,
+	#[builder(skip)]
+	_cache: Option<u32>,
+}
+
+/// Builder with a custom field and a renamed start function.
+#[derive(Builder, CheckInitialState)]
+#[builder(start_fn = new_builder)]
+#[check_initial_state(bon)]
+struct Renamed<'a> {
+	#[builder(field)]
+	#[expect_zero]
+	retries: u32,
+	#[builder(field = Some("label"))]
+	#[expect_matches(Some("label"))]
+	label: Option<&'a str>,
+	#[ignore_field]
+	_name: String,
+	parent: Option<&'a str>,
+}
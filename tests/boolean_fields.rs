@@ -0,0 +1,37 @@
+use check_initial_state_derive::CheckInitialState;
+
+/// Checks boolean fields which are in their expected initial state.
+#[test]
+fn boolean_fields_in_initial_state() {
+	Struct { option: None, dirty: false, enabled: true }.check_initial_state();
+}
+
+/// Checks the case when `expect_false` field is `true`.
+#[test]
+#[should_panic(expected = "Field `dirty` is true instead of false")]
+fn expect_false_violated() {
+	Struct { option: None, dirty: true, enabled: true }.check_initial_state();
+}
+
+/// Checks the case when `expect_true` field is `false`.
+#[test]
+#[should_panic(expected = "Field `enabled` is false instead of true")]
+fn expect_true_violated() {
+	Struct { option: None, dirty: false, enabled: false }.check_initial_state();
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(constructor = "new", mutation_test, initial_const)]
+struct Struct {
+	option: Option<i32>,
+	#[expect_false]
+	dirty: bool,
+	#[expect_true]
+	enabled: bool,
+}
+
+impl Struct {
+	fn new() -> Self {
+		Self::INITIAL
+	}
+}
@@ -0,0 +1,29 @@
+use check_initial_state_derive::CheckInitialState;
+
+/// Checks that the reference instance relies on the bound added by `bound`
+/// option.
+#[test]
+#[should_panic(expected = "Field `option` has Some value instead of None")]
+fn bound_enables_reference_constructor() {
+	Cache::<u8> { option: Some(1), _capacity: 0 }.check_initial_state();
+}
+
+/// Checks that an instance equal to the reference one passes the check.
+#[test]
+fn bound_satisfied() {
+	Cache::<u8>::new().check_initial_state();
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(compare_with = "Self::new()", bound = "T: Default + Copy")]
+struct Cache<T> {
+	option: Option<T>,
+	#[ignore_field]
+	_capacity: T,
+}
+
+impl<T: Default + Copy> Cache<T> {
+	fn new() -> Self {
+		Self { option: None, _capacity: T::default() }
+	}
+}
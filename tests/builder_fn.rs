@@ -0,0 +1,57 @@
+use check_initial_state_derive::CheckInitialState;
+use smart_default::SmartDefault;
+
+/// Checks that the generated function creates an instance in initial state,
+/// taking ignored fields from their defaults.
+#[test]
+fn builder_created() {
+	let builder = Builder::builder();
+	builder.check_initial_state();
+	assert_eq!(builder.host, "localhost");
+	assert_eq!(builder.created.len(), 2);
+}
+
+/// Checks that the function may be renamed, and a generic struct is supported.
+#[test]
+fn renamed_builder_created() {
+	Generic::<String>::new_builder().check_initial_state();
+}
+
+/// Checks that fields with `smart-default` defaults get their default values.
+#[test]
+fn smart_default_applied() {
+	let config = Config::builder();
+	config.check_initial_state();
+	assert_eq!(config.retries, 3);
+	assert_eq!(config.name, "config");
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(builder_fn)]
+struct Builder {
+	_option: Option<i32>,
+	#[expect_false]
+	_enabled: bool,
+	#[expect_zero]
+	_count: u32,
+	#[ignore_field(default = "String::from(\"localhost\")")]
+	host: String,
+	#[ignore_field(default = vec![1, 2])]
+	created: Vec<u8>,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(builder_fn = new_builder)]
+struct Generic<T> {
+	_option: Option<T>,
+}
+
+#[derive(CheckInitialState, SmartDefault)]
+#[check_initial_state(builder_fn = "builder")]
+struct Config {
+	#[default(3)]
+	retries: u32,
+	#[default = "config"]
+	name: String,
+	_option: Option<u8>,
+}
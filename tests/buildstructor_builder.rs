@@ -0,0 +1,45 @@
+use check_initial_state_derive::CheckInitialState;
+
+/// Checks that the struct created through the builder with default entry and
+/// exit is in initial state. The generated constructor and mutation tests
+/// verify the same.
+#[test]
+fn default_names() {
+	Request::builder().build().check_initial_state();
+}
+
+/// Checks that the struct created through the builder with renamed entry and
+/// exit is in initial state.
+#[test]
+fn renamed_names() {
+	Renamed::request().send().check_initial_state();
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(buildstructor, mutation_test, empty_via(Vec = is_empty))]
+struct Request {
+	timeout: Option<u32>,
+	headers: Vec<String>,
+}
+
+#[buildstructor::buildstructor]
+impl Request {
+	#[builder]
+	fn new(timeout: Option<u32>, headers: Vec<String>) -> Self {
+		Self { timeout, headers }
+	}
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(buildstructor(entry = "request", exit = send))]
+struct Renamed {
+	timeout: Option<u32>,
+}
+
+#[buildstructor::buildstructor]
+impl Renamed {
+	#[builder(entry = "request", exit = "send")]
+	fn new(timeout: Option<u32>) -> Self {
+		Self { timeout }
+	}
+}
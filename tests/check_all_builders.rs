@@ -0,0 +1,64 @@
+#![cfg(feature = "item-macros")]
+
+use check_initial_state::CheckInitialState;
+use check_initial_state_derive::check_all_builders;
+
+/// Checks that structs matching the default pattern get the derive.
+#[test]
+#[should_panic(expected = "Field `port` has Some value instead of None")]
+fn default_pattern_derives() {
+	builders::ServerBuilder { port: Some(80) }.check_initial_state();
+}
+
+/// Checks that derive attributes of matching structs are applied.
+#[test]
+fn derive_attributes_applied() {
+	builders::ClientBuilder { host: None, retries: 3 }.check_initial_state();
+}
+
+/// Checks that a struct deriving `CheckInitialState` already is left intact.
+#[test]
+fn existing_derive_kept() {
+	builders::ProxyBuilder { upstream: None }.check_initial_state();
+}
+
+/// Checks that a custom pattern selects the structs.
+#[test]
+#[should_panic(expected = "Field `level` has Some value instead of None")]
+fn custom_pattern_derives() {
+	CheckInitialState::check_initial_state(&configs::LogConfig { level: Some(1) });
+}
+
+#[check_all_builders]
+mod builders {
+	use check_initial_state::CheckInitialState;
+
+	pub struct ServerBuilder {
+		pub port: Option<u16>,
+	}
+
+	#[check_initial_state(lenient)]
+	pub struct ClientBuilder {
+		pub host: Option<String>,
+		pub retries: u32,
+	}
+
+	#[derive(CheckInitialState)]
+	pub struct ProxyBuilder {
+		pub upstream: Option<String>,
+	}
+
+	/// Doesn't match the pattern, so its non-`Option` field needn't be
+	/// annotated.
+	#[allow(dead_code)]
+	pub struct Server {
+		pub port: u16,
+	}
+}
+
+#[check_all_builders(pattern = "*Config")]
+mod configs {
+	pub struct LogConfig {
+		pub level: Option<u8>,
+	}
+}
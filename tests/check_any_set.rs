@@ -0,0 +1,82 @@
+use check_initial_state_derive::{CheckAnySet, CheckInitialState};
+
+/// Checks that nothing panics if a single field is recorded.
+#[test]
+fn any_set() {
+	Builder { option: Some(1), enabled: false, count: 0, _ignored: None }.check_any_set();
+	Builder { option: None, enabled: true, count: 0, _ignored: None }.check_any_set();
+	Builder { option: None, enabled: false, count: 3, _ignored: None }.check_any_set();
+}
+
+/// Checks that a builder in initial state panics listing its checked fields,
+/// while ignored fields are not taken into account.
+#[test]
+#[should_panic(
+	expected = "No checked field of `Builder` is set. Checked fields: `option`, `enabled`, `Count`"
+)]
+fn none_set() {
+	let builder = Builder { option: None, enabled: false, count: 0, _ignored: Some(1) };
+	builder.check_initial_state();
+	builder.check_any_set();
+}
+
+/// Checks that fields are compared with the reference instance in
+/// `compare_with` mode.
+#[test]
+fn compared_any_set() {
+	Compared { option: None }.check_any_set();
+}
+
+/// Checks that the panic message is tagged.
+#[test]
+#[should_panic(expected = "[check] No checked field of `Compared` is set")]
+fn compared_none_set() {
+	Compared { option: Some(1) }.check_any_set();
+}
+
+/// Checks that the count of recorded fields is verified step by step.
+#[test]
+fn exactly_n_set() {
+	let mut builder = Builder { option: None, enabled: false, count: 0, _ignored: Some(1) };
+	builder.check_exactly_n_set(0);
+	builder.option = Some(1);
+	builder.check_exactly_n_set(1);
+	builder.enabled = true;
+	builder.check_exactly_n_set(2);
+	builder.count = 1;
+	builder.check_exactly_n_set(3);
+}
+
+/// Checks that the panic message lists the fields which are set.
+#[test]
+#[should_panic(
+	expected = "Expected 1 checked fields of `Builder` to be set, found 2: `option`, `Count`"
+)]
+fn other_number_set() {
+	Builder { option: Some(1), enabled: false, count: 1, _ignored: None }.check_exactly_n_set(1);
+}
+
+/// Checks that the panic message tells that no field is set.
+#[test]
+#[should_panic(expected = "Expected 2 checked fields of `Builder` to be set, found 0: none")]
+fn none_set_of_expected() {
+	Builder { option: None, enabled: false, count: 0, _ignored: None }.check_exactly_n_set(2);
+}
+
+#[derive(CheckAnySet, CheckInitialState)]
+struct Builder {
+	option: Option<i32>,
+	#[expect_false]
+	enabled: bool,
+	#[expect_zero]
+	#[report_name = "Count"]
+	count: u8,
+	#[ignore_field]
+	_ignored: Option<i32>,
+}
+
+#[derive(CheckAnySet)]
+#[check_initial_state(compare_with = Self { option: Some(1) }, message_prefix = "[check] ")]
+struct Compared {
+	option: Option<i32>,
+}
@@ -0,0 +1,26 @@
+use check_initial_state_derive::CheckInitialState;
+
+/// Checks that `check_clone` option is accepted for `Clone` structs, while the
+/// tests checking the clones are generated by the macro itself.
+#[test]
+fn check_clone() {
+	Struct::new().clone().check_initial_state();
+}
+
+#[derive(Clone, CheckInitialState)]
+#[check_initial_state(constructors("Self::new", "Self::with_cache"), check_clone)]
+struct Struct {
+	option: Option<String>,
+	#[ignore_field]
+	_cache: Vec<u8>,
+}
+
+impl Struct {
+	fn new() -> Self {
+		Self { option: None, _cache: Vec::new() }
+	}
+
+	fn with_cache() -> Self {
+		Self { option: None, _cache: vec![1, 2, 3] }
+	}
+}
@@ -0,0 +1,60 @@
+use check_initial_state_derive::CheckInitialState;
+
+/// Checks that constructors listed in `check_initial_state` attribute are
+/// usable, while the tests for them are generated by the macro itself.
+#[test]
+fn check_constructors() {
+	Struct::new().check_initial_state();
+	Struct::with_capacity(10).check_initial_state();
+	Single::new().check_initial_state();
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(constructors("Self::new", "Self::with_capacity(10)", "new", "make_struct()"))]
+struct Struct {
+	option: Option<String>,
+	#[ignore_field]
+	_capacity: usize,
+}
+
+impl Struct {
+	fn new() -> Self {
+		Self::with_capacity(0)
+	}
+
+	fn with_capacity(capacity: usize) -> Self {
+		Self { option: None, _capacity: capacity }
+	}
+}
+
+fn make_struct() -> Struct {
+	Struct::new()
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(constructor = "Single::new")]
+struct Single {
+	option: Option<i32>,
+}
+
+impl Single {
+	fn new() -> Self {
+		Self { option: None }
+	}
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(constructor = Self::new, constructor = Self::with_value(None))]
+struct Unquoted {
+	option: Option<i32>,
+}
+
+impl Unquoted {
+	fn new() -> Self {
+		Self::with_value(None)
+	}
+
+	fn with_value(option: Option<i32>) -> Self {
+		Self { option }
+	}
+}
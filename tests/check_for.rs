@@ -0,0 +1,79 @@
+use check_initial_state_derive::check_initial_state_for;
+
+/// Checks listed fields which are in initial state.
+#[test]
+fn listed_fields_in_initial_state() {
+	let builder = foreign::Builder {
+		option: None,
+		enabled: false,
+		retries: 0,
+		state: foreign::State::Idle,
+	};
+	check_initial_state_for!(builder, foreign::Builder {
+		option,
+		#[expect_false]
+		enabled,
+		#[expect_zero]
+		retries,
+		#[expect_matches(foreign::State::Idle)]
+		state,
+	});
+}
+
+/// Checks the case when a listed `Option` field is `Some`.
+#[test]
+#[should_panic(expected = "Field `option` has Some value instead of None")]
+fn listed_option_is_some() {
+	let builder = foreign::Builder {
+		option: Some(1),
+		enabled: false,
+		retries: 0,
+		state: foreign::State::Idle,
+	};
+	check_initial_state_for!(&builder, foreign::Builder { option });
+}
+
+/// Checks the case when a listed field violates its check attribute.
+#[test]
+#[should_panic(expected = "Field `state` doesn't match `foreign::State::Idle`")]
+fn listed_field_violates_check_attribute() {
+	let builder = foreign::Builder {
+		option: None,
+		enabled: false,
+		retries: 0,
+		state: foreign::State::Busy,
+	};
+	check_initial_state_for!(builder, foreign::Builder {
+		option,
+		#[expect_matches(foreign::State::Idle)]
+		state,
+	});
+}
+
+/// Checks that fields which are not listed are not checked.
+#[test]
+fn unlisted_fields_not_checked() {
+	let builder = foreign::Builder {
+		option: None,
+		enabled: true,
+		retries: 3,
+		state: foreign::State::Busy,
+	};
+	check_initial_state_for!(builder, foreign::Builder { option });
+}
+
+/// Stands for a third-party crate, whose types cannot be annotated with the
+/// derive.
+mod foreign {
+	pub struct Builder {
+		pub option: Option<i32>,
+		pub enabled: bool,
+		pub retries: u32,
+		pub state: State,
+	}
+
+	pub enum State {
+		Idle,
+		Busy,
+	}
+}
@@ -1,5 +1,7 @@
+use check_initial_state::InitialStatePanic;
 use check_initial_state_derive::CheckInitialState;
 use std::fmt::{Debug, Display};
+use std::panic;
 
 type AnOption<T> = Option<T>;
 
@@ -17,6 +19,43 @@ fn check_generics() {
 		.check_initial_state();
 }
 
+/// Ensures that higher-ranked bounds, bounds on associated items,
+/// `Self` in where clauses and lifetime bounds are kept by all generated items.
+#[test]
+fn check_higher_ranked_bounds() {
+	Hrtb::<u8>::new().check_initial_state();
+	let errors = Hrtb::<u8> { option: Some(1), _reference: None, _other: None }
+		.initial_state_errors();
+	assert_eq!(errors, 1);
+}
+
+/// Ensures that a reference instance is constructed for a struct with
+/// higher-ranked bounds.
+#[test]
+fn compare_higher_ranked_bounds() {
+	let payload = panic::catch_unwind(|| Compared::<u8> { option: Some(1) }.check_initial_state())
+		.unwrap_err();
+	assert_eq!(payload.downcast_ref::<InitialStatePanic>().unwrap().violation().field(), "option");
+}
+
+/// Ensures that a mirror of a foreign struct with higher-ranked bounds
+/// checks it.
+#[test]
+#[should_panic(expected = "Field `option` has Some value instead of None")]
+fn remote_higher_ranked_bounds() {
+	Mirror::check_initial_state(&foreign::Foreign { option: Some(1u8) });
+}
+
+/// Ensures that comparison and reporting modes don't require generic
+/// parameters to implement `Debug` or `PartialEq`.
+#[test]
+fn no_bounds_on_generic_parameters() {
+	let mut report = Vec::new();
+	Unbounded::<Opaque> { option: Some(Opaque) }.write_initial_state_report(&mut report).unwrap();
+	assert_eq!(report, b"Field `option` has Some value instead of None\n");
+	Unbounded::<Opaque>::new().check_initial_state();
+}
+
 #[derive(CheckInitialState)]
 struct Struct<'a, 'b, T: Display + ?Sized, E>
 	where E: Debug {
@@ -29,3 +68,90 @@ struct Struct<'a, 'b, T: Display + ?Sized, E>
 	#[ignore_field]
 	_vector: Vec<Box<T>>,
 }
+
+pub trait Parse<'x> {
+	type Output;
+}
+
+impl<'x> Parse<'x> for u8 {
+	type Output = &'x str;
+}
+
+#[derive(CheckInitialState, Clone)]
+#[check_initial_state(
+	constructor = "Self::new",
+	check_clone,
+	mutation_test,
+	initial_const,
+	error_bits,
+)]
+struct Hrtb<'a, 'b: 'a, T>
+where
+	Self: Sized,
+	for<'x> T: Parse<'x> + Clone,
+	for<'x> <T as Parse<'x>>::Output: Debug,
+	T: 'b,
+{
+	option: Option<T>,
+	#[ignore_field(default = "None", reason = "Borrowed")]
+	_reference: Option<&'a u8>,
+	#[ignore_field(default = "None", reason = "Borrowed")]
+	_other: Option<&'b u8>,
+}
+
+impl<'a, 'b: 'a> Hrtb<'a, 'b, u8> {
+	fn new() -> Self {
+		Self { option: None, _reference: None, _other: None }
+	}
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(compare_with = "Self { option: None }", panic_payload)]
+struct Compared<T>
+where
+	Self: Send,
+	for<'x> T: Parse<'x>,
+{
+	option: Option<T>,
+}
+
+mod foreign {
+	pub struct Foreign<T>
+	where
+		for<'x> T: super::Parse<'x>,
+	{
+		pub option: Option<T>,
+	}
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(remote = "foreign::Foreign<T>")]
+struct Mirror<T>
+where
+	for<'x> T: Parse<'x>,
+{
+	#[allow(dead_code)]
+	option: Option<T>,
+}
+
+/// Implements no traits but `Clone`, which `check_clone` option requires.
+#[derive(Clone)]
+struct Opaque;
+
+#[derive(CheckInitialState)]
+#[check_initial_state(
+	constructor = "Unbounded::<Opaque>::new",
+	compare_with = "Self::new()",
+	check_clone,
+	error_bits,
+)]
+#[derive(Clone)]
+struct Unbounded<T> {
+	option: Option<T>,
+}
+
+impl<T> Unbounded<T> {
+	fn new() -> Self {
+		Self { option: None }
+	}
+}
@@ -0,0 +1,62 @@
+use check_initial_state_derive::{CheckInitialState, CheckInvariants};
+
+/// Checks that nothing panics while all invariants hold.
+#[test]
+fn invariants_hold() {
+	RangeBuilder::default().check_invariants();
+	RangeBuilder { start: Some(1), ..RangeBuilder::default() }.check_invariants();
+	RangeBuilder { end: Some(2), retries: 5, ..RangeBuilder::default() }.check_invariants();
+}
+
+/// Checks that the panic message contains the violated invariant as written.
+#[test]
+#[should_panic(
+	expected = "Invariant `self.start.is_none() || self.end.is_none()` of `RangeBuilder` doesn't hold"
+)]
+fn first_invariant_violated() {
+	RangeBuilder { start: Some(1), end: Some(2), retries: 0 }.check_invariants();
+}
+
+/// Checks that invariants are checked in the order of declaration.
+#[test]
+#[should_panic(expected = "Invariant `self.retries <= 5` of `RangeBuilder` doesn't hold")]
+fn second_invariant_violated() {
+	RangeBuilder { retries: 6, ..RangeBuilder::default() }.check_invariants();
+}
+
+/// Checks that `check_initial_state` options tag the message.
+#[test]
+#[should_panic(expected = "[window] Invariant `self.width > 0` of `Window` doesn't hold")]
+fn tagged_message() {
+	Window { width: 0 }.check_invariants();
+}
+
+/// Checks that invariants may refer to generic fields.
+#[test]
+#[should_panic(expected = "Invariant `!self.items.is_empty()` of `NonEmpty` doesn't hold")]
+fn generic_invariant_violated() {
+	NonEmpty::<u8> { items: Vec::new() }.check_invariants();
+}
+
+#[derive(CheckInitialState, CheckInvariants, Default)]
+#[invariant(self.start.is_none() || self.end.is_none())]
+#[invariant(self.retries <= 5)]
+struct RangeBuilder {
+	start: Option<u32>,
+	end: Option<u32>,
+	#[ignore_field]
+	retries: u8,
+}
+
+#[derive(CheckInvariants)]
+#[check_initial_state(message_prefix = "[window] ")]
+#[invariant(self.width > 0)]
+struct Window {
+	width: u32,
+}
+
+#[derive(CheckInvariants)]
+#[invariant(!self.items.is_empty())]
+struct NonEmpty<T> {
+	items: Vec<T>,
+}
@@ -0,0 +1,67 @@
+use check_initial_state_derive::CheckInitialState;
+
+/// Checks that `mutation_test` option is accepted, while the mutation tests
+/// are generated by the macro itself.
+#[test]
+fn check_mutation_test() {
+	let mut instance = WithSetters::new();
+	instance.set_option(10);
+	instance.set_option2("value".to_owned());
+	instance.set_option(0);
+	Direct::new().check_initial_state();
+	let mut raw = RawIdentifier::new();
+	raw.set_type(1);
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(constructor = "new", mutation_test, setter = "set_{}")]
+struct WithSetters {
+	option: Option<i32>,
+	option2: Option<String>,
+	#[ignore_field]
+	_integer: i32,
+}
+
+impl WithSetters {
+	fn new() -> Self {
+		Self { option: None, option2: None, _integer: 10 }
+	}
+
+	fn set_option(&mut self, value: i32) {
+		self.option = Some(value);
+	}
+
+	fn set_option2(&mut self, value: String) {
+		self.option2 = Some(value);
+	}
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(constructors("Self::new"), mutation_test)]
+struct Direct {
+	option: Option<Vec<u8>>,
+	option2: Option<bool>,
+}
+
+impl Direct {
+	fn new() -> Self {
+		Self { option: None, option2: None }
+	}
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(constructor = "new", mutation_test, setter = "set_{}")]
+struct RawIdentifier {
+	r#type: Option<u8>,
+}
+
+impl RawIdentifier {
+	fn new() -> Self {
+		Self { r#type: None }
+	}
+
+	// Setter of a raw identifier field is named after the unraw identifier
+	fn set_type(&mut self, value: u8) {
+		self.r#type = Some(value);
+	}
+}
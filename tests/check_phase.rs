@@ -0,0 +1,46 @@
+use check_initial_state_derive::CheckPhase;
+
+/// Checks that nothing panics while a builder is populated in the declared
+/// order.
+#[test]
+fn phases_in_order() {
+	let mut builder = RequestBuilder::default();
+	builder.check_phase(1);
+	builder.url = Some("https://example.com".to_owned());
+	builder.check_phase(1);
+	builder.check_phase(2);
+	builder.authorized = true;
+	builder.retries = 3;
+	builder.check_phase(2);
+	builder.check_phase(3);
+	builder.body = Some(vec![1]);
+	builder.check_phase(3);
+}
+
+/// Checks that a field of an earlier phase is expected to be set.
+#[test]
+#[should_panic(expected = "Field `url` of phase 1 is not set in phase 2")]
+fn earlier_phase_unset() {
+	RequestBuilder::default().check_phase(2);
+}
+
+/// Checks that a field of a later phase is expected to be unset.
+#[test]
+#[should_panic(expected = "Field `Authorized` of phase 2 is true instead of false in phase 1")]
+fn later_phase_set() {
+	RequestBuilder { authorized: true, ..RequestBuilder::default() }.check_phase(1);
+}
+
+#[derive(CheckPhase, Default)]
+struct RequestBuilder {
+	#[phase = 1]
+	url: Option<String>,
+	#[phase = 2]
+	#[expect_false]
+	#[report_name = "Authorized"]
+	authorized: bool,
+	#[phase = 3]
+	body: Option<Vec<u8>>,
+	// Is not checked without a phase
+	retries: u8,
+}
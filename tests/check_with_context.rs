@@ -0,0 +1,58 @@
+use check_initial_state_derive::CheckInitialState;
+
+/// Checks that nothing panics for a struct in initial state.
+#[test]
+fn context_initial_state() {
+	Builder { option: None }.check_initial_state_with_context("default scenario");
+}
+
+/// Checks that the context precedes the panic message.
+#[test]
+#[should_panic(expected = "retry scenario: Field `option` has Some value instead of None")]
+fn context_prepended() {
+	Builder { option: Some(1) }.check_initial_state_with_context("retry scenario");
+}
+
+/// Checks that the message is intact if the context is empty.
+#[test]
+fn empty_context() {
+	let message = std::panic::catch_unwind(|| {
+		Builder { option: Some(1) }.check_initial_state_with_context("");
+	})
+		.unwrap_err()
+		.downcast::<String>()
+		.unwrap();
+	assert!(message.starts_with("Field `option` has Some value instead of None"), "{}", message);
+}
+
+/// Checks that the context precedes the diff in `compare_with` mode.
+#[test]
+#[should_panic(expected = "reset scenario: Field `option` has None value instead of Some")]
+fn context_of_comparison() {
+	Compared { option: None }.check_initial_state_with_context("reset scenario");
+}
+
+/// Checks that the context of a foreign struct check precedes the message.
+#[test]
+#[should_panic(expected = "remote scenario: Field `option` has Some value instead of None")]
+fn context_of_remote() {
+	BuilderDef::check_initial_state_with_context(&Builder { option: Some(1) }, "remote scenario");
+}
+
+#[derive(CheckInitialState)]
+struct Builder {
+	option: Option<i32>,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(compare_with = Self { option: Some(1) })]
+struct Compared {
+	option: Option<i32>,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(remote = "Builder")]
+#[allow(dead_code)]
+struct BuilderDef {
+	option: Option<i32>,
+}
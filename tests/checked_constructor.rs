@@ -0,0 +1,81 @@
+#![cfg(feature = "item-macros")]
+
+use check_initial_state_derive::{checked_constructor, CheckInitialState};
+use std::future::Future;
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+/// Checks that a constructor returning initial state succeeds.
+#[test]
+fn clean_constructor() {
+	assert!(Builder::new().option.is_none());
+}
+
+/// Checks that a constructor returning other than initial state panics.
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "Field `option` has Some value instead of None")]
+fn dirty_constructor() {
+	Builder::with_option(10);
+}
+
+/// Checks that an early `return` of the constructor is checked as well.
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "Field `option` has Some value instead of None")]
+fn early_return_checked() {
+	Builder::early(true);
+}
+
+/// Checks that an `async` constructor returning initial state succeeds.
+#[test]
+fn clean_async_constructor() {
+	assert!(block_on(Builder::connect(None)).option.is_none());
+}
+
+/// Checks that an `async` constructor is checked once it is awaited.
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "Field `option` has Some value instead of None")]
+fn dirty_async_constructor() {
+	block_on(Builder::connect(Some(10)));
+}
+
+#[derive(CheckInitialState)]
+struct Builder {
+	option: Option<i32>,
+}
+
+impl Builder {
+	#[checked_constructor]
+	fn new() -> Self {
+		Self { option: None }
+	}
+
+	#[checked_constructor]
+	fn with_option(option: i32) -> Self {
+		Self { option: Some(option) }
+	}
+
+	#[checked_constructor]
+	fn early(dirty: bool) -> Self {
+		if dirty {
+			return Self { option: Some(1) };
+		}
+		Self { option: None }
+	}
+
+	#[checked_constructor]
+	async fn connect(option: Option<i32>) -> Self {
+		Self { option }
+	}
+}
+
+/// Polls `future`, which is expected to be ready at once.
+fn block_on<F: Future>(future: F) -> F::Output {
+	let mut context = Context::from_waker(Waker::noop());
+	match pin!(future).poll(&mut context) {
+		Poll::Ready(output) => output,
+		Poll::Pending => panic!("Future is not ready"),
+	}
+}
@@ -0,0 +1,51 @@
+use check_initial_state_derive::CheckInitialState;
+
+/// Checks that an instance with the same `Some` pattern as the reference one
+/// passes the check regardless of payload values.
+#[test]
+fn compare_with_same_pattern() {
+	Struct { option: None, preset: Some(20), _integer: 10 }.check_initial_state();
+}
+
+/// Checks the case when the reference instance has `None` in a field, while
+/// the checked one has `Some`.
+#[test]
+#[should_panic(expected = "Field `option` has Some value instead of None")]
+fn compare_with_unexpected_some() {
+	Struct { option: Some("value".to_owned()), preset: Some(10), _integer: 10 }
+		.check_initial_state();
+}
+
+/// Checks the case when the reference instance has `Some` in a field, while
+/// the checked one has `None`.
+#[test]
+#[should_panic(expected = "Field `preset` has None value instead of Some")]
+fn compare_with_unexpected_none() {
+	Struct { option: None, preset: None, _integer: 10 }.check_initial_state();
+}
+
+/// Checks that the panic message contains the diff of all fields which differ
+/// from the reference instance.
+#[test]
+#[should_panic(expected = "Field `option` has Some value instead of None\n\
+	Differences from the reference instance (reference => actual):\n  \
+	`option`: None => Some\n  \
+	`preset`: Some => None")]
+fn compare_with_diff() {
+	Struct { option: Some("value".to_owned()), preset: None, _integer: 10 }.check_initial_state();
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(compare_with = "new")]
+struct Struct {
+	option: Option<String>,
+	preset: Option<i32>,
+	#[ignore_field]
+	_integer: i32,
+}
+
+impl Struct {
+	fn new() -> Self {
+		Self { option: None, preset: Some(10), _integer: 0 }
+	}
+}
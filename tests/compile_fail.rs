@@ -1,5 +1,10 @@
 /// Manages tests which are expected to fail while procedural macro expansion.
 #[test]
+#[cfg_attr(
+	feature = "coverage",
+	ignore = "trybuild projects don't declare `coverage_nightly` cfg, so outputs get \
+		`unexpected_cfgs` warnings. CI runs the test with all other features",
+)]
 fn compile_fail() {
 	let t = trybuild::TestCases::new();
 	t.compile_fail("tests/compile_fail/struct_with_other_fields_not_annotated.rs");
@@ -7,4 +12,81 @@ fn compile_fail() {
 	t.compile_fail("tests/compile_fail/derive_for_union.rs");
 	t.compile_fail("tests/compile_fail/derive_for_tuple_struct.rs");
 	t.compile_fail("tests/compile_fail/derive_for_unit_struct.rs");
+	t.compile_fail("tests/compile_fail/malformed_constructors.rs");
+	t.compile_fail("tests/compile_fail/unknown_container_option.rs");
+	t.compile_fail("tests/compile_fail/check_clone_without_constructors.rs");
+	t.compile_fail("tests/compile_fail/setter_without_mutation_test.rs");
+	t.compile_fail("tests/compile_fail/initial_const_without_default.rs");
+	t.compile_fail("tests/compile_fail/expect_false_with_ignore_field.rs");
+	t.compile_fail("tests/compile_fail/several_check_attributes.rs");
+	t.compile_fail("tests/compile_fail/expect_matches_without_pattern.rs");
+	t.compile_fail("tests/compile_fail/initial_const_with_expect_matches.rs");
+	t.compile_fail("tests/compile_fail/malformed_empty_via.rs");
+	t.compile_fail("tests/compile_fail/lenient_with_initial_const.rs");
+	t.compile_fail("tests/compile_fail/ignored_option_without_reason.rs");
+	t.compile_fail("tests/compile_fail/deny_empty_check.rs");
+	t.compile_fail("tests/compile_fail/warn_empty_check.rs");
+	t.compile_fail("tests/compile_fail/ignore_field_on_struct.rs");
+	t.compile_fail("tests/compile_fail/ignore_field_on_generic.rs");
+	t.compile_fail("tests/compile_fail/malformed_msrv.rs");
+	t.compile_fail("tests/compile_fail/warn_empty_check_with_old_msrv.rs");
+	t.compile_fail("tests/compile_fail/misspelled_container_option.rs");
+	t.compile_fail("tests/compile_fail/several_attribute_problems.rs");
+	t.compile_fail("tests/compile_fail/malformed_ignore_field_flag.rs");
+	t.compile_fail("tests/compile_fail/ignored_option_from_macro_rules.rs");
+	t.compile_fail("tests/compile_fail/error_bits_overflow.rs");
+	t.compile_fail("tests/compile_fail/malformed_check_for.rs");
+	t.compile_fail("tests/compile_fail/remote_with_self_options.rs");
+	t.compile_fail("tests/compile_fail/malformed_bound.rs");
+	t.compile_fail("tests/compile_fail/malformed_report_name.rs");
+	t.compile_fail("tests/compile_fail/malformed_redact.rs");
+	t.compile_fail("tests/compile_fail/transparent_field_option.rs");
+	t.compile_fail("tests/compile_fail/malformed_delegate_check.rs");
+	t.compile_fail("tests/compile_fail/check_any_set_unsupported.rs");
+	t.compile_fail("tests/compile_fail/malformed_phase.rs");
+	t.compile_fail("tests/compile_fail/malformed_ignore_types.rs");
+	t.compile_fail("tests/compile_fail/malformed_buildstructor.rs");
+	t.compile_fail("tests/compile_fail/malformed_builder_fn.rs");
+	t.compile_fail("tests/compile_fail/malformed_field_enum.rs");
+	t.compile_fail("tests/compile_fail/malformed_field_group.rs");
+	t.compile_fail("tests/compile_fail/malformed_setter_hint.rs");
+	t.compile_fail("tests/compile_fail/malformed_setter.rs");
+	t.compile_fail("tests/compile_fail/malformed_visibility.rs");
+	t.compile_fail("tests/compile_fail/auto_unset_opaque_field.rs");
+	t.compile_fail("tests/compile_fail/malformed_inherit_checks.rs");
+	t.compile_fail("tests/compile_fail/inherit_checks_unchecked_fields.rs");
+	t.compile_fail("tests/compile_fail/malformed_invariant.rs");
+	t.compile_fail("tests/compile_fail/register_without_registry_feature.rs");
+	if cfg!(feature = "item-macros") {
+		t.compile_fail("tests/compile_fail/malformed_check_all_builders.rs");
+		t.compile_fail("tests/compile_fail/malformed_checked_constructor.rs");
+		t.compile_fail("tests/compile_fail/initial_literal_some.rs");
+		t.compile_fail("tests/compile_fail/malformed_verify_initial_literals.rs");
+		t.compile_fail("tests/compile_fail/malformed_post_build_check.rs");
+	}
+	if cfg!(feature = "rstest") {
+		t.compile_fail("tests/compile_fail/fixture_without_constructors.rs");
+	} else {
+		t.compile_fail("tests/compile_fail/fixture_without_rstest_feature.rs");
+	}
+	if cfg!(feature = "kani") {
+		t.compile_fail("tests/compile_fail/kani_proof_without_constructors.rs");
+	} else {
+		t.compile_fail("tests/compile_fail/kani_proof_without_kani_feature.rs");
+	}
+	if cfg!(feature = "provenance") {
+		t.compile_fail("tests/compile_fail/malformed_provenance.rs");
+	} else {
+		t.compile_fail("tests/compile_fail/provenance_without_feature.rs");
+	}
+	if cfg!(feature = "bon") {
+		t.compile_fail("tests/compile_fail/malformed_bon.rs");
+	} else {
+		t.compile_fail("tests/compile_fail/bon_without_feature.rs");
+	}
+	if cfg!(feature = "no-panic") {
+		t.compile_fail("tests/compile_fail/no_panic_with_remote.rs");
+	} else {
+		t.compile_fail("tests/compile_fail/no_panic_without_feature.rs");
+	}
 }
@@ -0,0 +1,12 @@
+use check_initial_state_derive::CheckInitialState;
+
+fn main() {}
+
+struct Opaque;
+
+#[derive(CheckInitialState)]
+#[check_initial_state(auto_unset)]
+struct Struct {
+	option: Option<i32>,
+	opaque: Opaque,
+}
@@ -0,0 +1,30 @@
+use check_initial_state_derive::{CheckAnySet, CheckInitialState};
+
+fn main() {}
+
+#[derive(CheckInitialState)]
+struct Builder {
+	option: Option<u8>,
+}
+
+#[derive(CheckAnySet)]
+struct Facade {
+	#[delegate_check]
+	builder: Builder,
+}
+
+#[derive(CheckAnySet)]
+struct Ignored {
+	#[ignore_field]
+	option: Option<u8>,
+}
+
+struct Foreign {
+	option: Option<u8>,
+}
+
+#[derive(CheckAnySet)]
+#[check_initial_state(remote = "Foreign")]
+struct ForeignDef {
+	option: Option<u8>,
+}
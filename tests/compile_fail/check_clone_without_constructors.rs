@@ -0,0 +1,9 @@
+use check_initial_state_derive::CheckInitialState;
+
+fn main() {}
+
+#[derive(Clone, CheckInitialState)]
+#[check_initial_state(check_clone)]
+struct Struct {
+	option: Option<i32>,
+}
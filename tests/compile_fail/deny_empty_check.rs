@@ -0,0 +1,12 @@
+use check_initial_state_derive::CheckInitialState;
+
+fn main() {}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(deny_empty_check)]
+struct Struct {
+	#[ignore_field]
+	option: Option<i32>,
+	#[ignore_field]
+	integer: i32,
+}
@@ -0,0 +1,9 @@
+use check_initial_state_derive::CheckInitialState;
+
+fn main() {}
+
+#[derive(CheckInitialState)]
+struct Struct {
+	#[expect_matches]
+	state: Option<i32>,
+}
@@ -0,0 +1,9 @@
+use check_initial_state_derive::CheckInitialState;
+
+fn main() {}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(fixture)]
+struct Struct {
+	option: Option<i32>,
+}
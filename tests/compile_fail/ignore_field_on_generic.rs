@@ -0,0 +1,8 @@
+use check_initial_state_derive::CheckInitialState;
+
+fn main() {}
+
+#[derive(CheckInitialState)]
+struct Struct<#[ignore_field] T> {
+	option: Option<T>,
+}
@@ -0,0 +1,20 @@
+#![deny(deprecated)]
+
+use check_initial_state_derive::CheckInitialState;
+
+fn main() {}
+
+macro_rules! builder {
+	($name:ident, $($field:ident: $ty:ty),*) => {
+		#[derive(CheckInitialState)]
+		#[check_initial_state(warn_ignored_option)]
+		struct $name {
+			$(
+				#[ignore_field]
+				$field: $ty,
+			)*
+		}
+	};
+}
+
+builder!(Builder, option: Option<i32>);
@@ -0,0 +1,14 @@
+#![deny(deprecated)]
+
+use check_initial_state_derive::CheckInitialState;
+
+fn main() {}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(warn_ignored_option)]
+struct Struct {
+	#[ignore_field]
+	option: Option<i32>,
+	#[ignore_field]
+	integer: i32,
+}
@@ -0,0 +1,23 @@
+use check_initial_state_derive::CheckInitialState;
+
+fn main() {}
+
+#[derive(CheckInitialState)]
+struct Base {
+	host: Option<String>,
+	port: Option<u16>,
+}
+
+#[derive(CheckInitialState)]
+#[inherit_checks(Base)]
+struct Composed {
+	host: Option<String>,
+	#[ignore_field]
+	port: Option<u16>,
+}
+
+#[derive(CheckInitialState)]
+#[inherit_checks(Base)]
+struct Partial {
+	host: Option<String>,
+}
@@ -0,0 +1,10 @@
+use check_initial_state_derive::CheckInitialState;
+
+fn main() {}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(initial_const)]
+struct Struct {
+	#[expect_matches(None | Some(0))]
+	limit: Option<i32>,
+}
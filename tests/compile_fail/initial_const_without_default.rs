@@ -0,0 +1,11 @@
+use check_initial_state_derive::CheckInitialState;
+
+fn main() {}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(initial_const)]
+struct Struct {
+	option: Option<i32>,
+	#[ignore_field]
+	integer: i32,
+}
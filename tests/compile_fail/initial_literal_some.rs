@@ -0,0 +1,26 @@
+use check_initial_state_derive::{verify_initial_literals, CheckInitialState};
+
+fn main() {
+	Builder::new();
+	Builder::generic::<u8>();
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(lenient)]
+struct Builder {
+	option: Option<i32>,
+	#[report_name = "Renamed"]
+	other: Option<i32>,
+}
+
+#[verify_initial_literals]
+impl Builder {
+	fn new() -> Self {
+		Self { option: Some(1), other: None }
+	}
+
+	fn generic<T: Default>() -> Self {
+		let _ = T::default();
+		Builder { option: None, other: Option::Some(2) }
+	}
+}
@@ -0,0 +1,10 @@
+use check_initial_state_derive::CheckInitialState;
+
+fn main() {}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(lenient, initial_const)]
+struct Struct {
+	option: Option<i32>,
+	integer: i32,
+}
@@ -0,0 +1,30 @@
+use bon::Builder;
+use check_initial_state_derive::CheckInitialState;
+
+fn main() {}
+
+#[derive(Builder, CheckInitialState)]
+#[check_initial_state(bon)]
+struct StartFnArguments {
+	#[builder(start_fn)]
+	id: u32,
+	option: Option<i32>,
+}
+
+#[derive(Builder, CheckInitialState)]
+#[check_initial_state(bon)]
+struct Generic<T> {
+	option: Option<T>,
+}
+
+#[derive(Builder, CheckInitialState)]
+#[check_initial_state(bon, compare_with = Self::reference)]
+struct Compared {
+	option: Option<i32>,
+}
+
+impl Compared {
+	fn reference() -> Self {
+		Self { option: None }
+	}
+}
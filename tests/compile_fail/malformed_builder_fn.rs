@@ -0,0 +1,38 @@
+use check_initial_state_derive::CheckInitialState;
+
+fn main() {}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(builder_fn = "not a name")]
+struct InvalidName {
+	option: Option<i32>,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(builder_fn, lenient)]
+struct Lenient {
+	option: Option<i32>,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(builder_fn)]
+struct WithoutDefault {
+	option: Option<i32>,
+	#[ignore_field]
+	count: u32,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(builder_fn)]
+struct Pattern {
+	#[expect_matches(None | Some(0))]
+	option: Option<i32>,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(derive_default)]
+struct DefaultWithoutDefault {
+	option: Option<i32>,
+	#[ignore_field]
+	count: u32,
+}
@@ -0,0 +1,21 @@
+use check_initial_state_derive::CheckInitialState;
+
+fn main() {}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(buildstructor(visibility = "pub"))]
+struct UnknownName {
+	option: Option<i32>,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(buildstructor(entry = "not a name"))]
+struct InvalidName {
+	option: Option<i32>,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(remote = "std::ops::Range", buildstructor)]
+struct Remote {
+	start: Option<i32>,
+}
@@ -0,0 +1,11 @@
+use check_initial_state_derive::check_all_builders;
+
+fn main() {}
+
+#[check_all_builders(patern = "*Config")]
+mod configs {}
+
+#[check_all_builders]
+struct ServerBuilder {
+	port: Option<u16>,
+}
@@ -0,0 +1,14 @@
+use check_initial_state_derive::check_initial_state_for;
+
+struct Builder {
+	option: Option<i32>,
+}
+
+fn main() {
+	let builder = Builder { option: None };
+	check_initial_state_for!(builder, Builder {});
+	check_initial_state_for!(builder, Builder {
+		#[expect_false(true)]
+		option,
+	});
+}
@@ -0,0 +1,28 @@
+use check_initial_state_derive::{checked_constructor, CheckInitialState};
+
+fn main() {}
+
+#[derive(CheckInitialState)]
+struct Builder {
+	option: Option<i32>,
+}
+
+impl Builder {
+	#[checked_constructor(debug)]
+	fn new() -> Self {
+		Self { option: None }
+	}
+
+	#[checked_constructor]
+	const fn empty() -> Self {
+		Self { option: None }
+	}
+
+	#[checked_constructor]
+	fn reset(&mut self) {
+		self.option = None;
+	}
+}
+
+#[checked_constructor]
+struct Other;
@@ -0,0 +1,42 @@
+use check_initial_state_derive::CheckInitialState;
+
+fn main() {}
+
+#[derive(CheckInitialState)]
+struct Builder {
+	option: Option<u8>,
+}
+
+#[derive(CheckInitialState)]
+struct Malformed {
+	#[delegate_check(builder)]
+	with_arguments: Builder,
+	#[delegate_check]
+	#[delegate_check]
+	twice: Builder,
+	#[delegate_check]
+	#[expect_false]
+	checked: bool,
+	#[delegate_check]
+	#[ignore_field]
+	ignored: Builder,
+}
+
+#[derive(CheckInitialState)]
+struct Several {
+	#[delegate_check]
+	first: Builder,
+	#[delegate_check]
+	second: Builder,
+}
+
+struct Foreign {
+	builder: Builder,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(remote = "Foreign")]
+struct ForeignDef {
+	#[delegate_check]
+	builder: Builder,
+}
@@ -0,0 +1,9 @@
+use check_initial_state_derive::CheckInitialState;
+
+fn main() {}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(empty_via(Vec = "is empty"))]
+struct Struct {
+	items: Vec<u8>,
+}
@@ -0,0 +1,16 @@
+use check_initial_state_derive::CheckInitialState;
+
+fn main() {}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(field_enum)]
+struct Duplicate {
+	max_retries: Option<u32>,
+	max__retries: Option<u32>,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(field_enum)]
+struct Keyword {
+	self_: Option<u32>,
+}
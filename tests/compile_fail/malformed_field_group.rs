@@ -0,0 +1,22 @@
+use check_initial_state_derive::CheckInitialState;
+
+fn main() {}
+
+#[derive(CheckInitialState)]
+struct Empty {
+	#[field_group = ""]
+	option: Option<u32>,
+}
+
+#[derive(CheckInitialState)]
+struct NotLiteral {
+	#[field_group(network)]
+	option: Option<u32>,
+}
+
+#[derive(CheckInitialState)]
+struct Duplicate {
+	#[field_group = "network"]
+	#[field_group = "storage"]
+	option: Option<u32>,
+}
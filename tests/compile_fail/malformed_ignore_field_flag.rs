@@ -0,0 +1,9 @@
+use check_initial_state_derive::CheckInitialState;
+
+fn main() {}
+
+#[derive(CheckInitialState)]
+struct Struct {
+	#[ignore_field = "yes"]
+	integer: i32,
+}
@@ -0,0 +1,16 @@
+use check_initial_state_derive::CheckInitialState;
+use std::marker::PhantomData;
+
+fn main() {}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(ignore_types(PhantomData<u8>))]
+struct Generic {
+	marker: PhantomData<u8>,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(ignore_types(Instant = "now"))]
+struct Mapping {
+	option: Option<i32>,
+}
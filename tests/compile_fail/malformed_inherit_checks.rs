@@ -0,0 +1,9 @@
+use check_initial_state_derive::CheckInitialState;
+
+fn main() {}
+
+#[derive(CheckInitialState)]
+#[inherit_checks("Base")]
+struct Composed {
+	host: Option<String>,
+}
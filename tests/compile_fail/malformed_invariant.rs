@@ -0,0 +1,16 @@
+use check_initial_state_derive::CheckInvariants;
+
+fn main() {}
+
+#[derive(CheckInvariants)]
+#[invariant]
+#[invariant()]
+#[invariant = "self.value > 0"]
+struct Malformed {
+	value: u8,
+}
+
+#[derive(CheckInvariants)]
+struct WithoutInvariants {
+	value: u8,
+}
@@ -0,0 +1,22 @@
+use check_initial_state_derive::CheckPhase;
+
+fn main() {}
+
+#[derive(CheckPhase)]
+struct Builder {
+	#[phase = "first"]
+	named: Option<u8>,
+	#[phase]
+	bare: Option<u8>,
+	#[phase = 1]
+	#[phase = 2]
+	twice: Option<u8>,
+	#[phase = 1]
+	#[ignore_field]
+	ignored: Option<u8>,
+}
+
+#[derive(CheckPhase)]
+struct Unphased {
+	option: Option<u8>,
+}
@@ -0,0 +1,26 @@
+use check_initial_state_derive::{post_build_check, CheckInitialState};
+
+fn main() {}
+
+#[derive(CheckInitialState)]
+struct Builder {
+	option: Option<u8>,
+}
+
+impl Builder {
+	#[post_build_check(unwrap)]
+	fn build(self) -> u8 {
+		self.option.unwrap()
+	}
+
+	#[post_build_check]
+	fn new() -> Self {
+		Self { option: None }
+	}
+
+	#[post_build_check(map_err)]
+	fn finish(self) {}
+}
+
+#[post_build_check]
+struct Misplaced;
@@ -0,0 +1,40 @@
+use check_initial_state_derive::CheckInitialState;
+
+fn main() {}
+
+#[derive(Default)]
+struct Provenance;
+
+#[derive(CheckInitialState)]
+struct Malformed {
+	option: Option<u8>,
+	#[provenance(option)]
+	with_arguments: Provenance,
+	#[provenance]
+	#[provenance]
+	twice: Provenance,
+	#[provenance]
+	#[ignore_field]
+	ignored: Provenance,
+}
+
+#[derive(CheckInitialState)]
+struct Several {
+	option: Option<u8>,
+	#[provenance]
+	first: Provenance,
+	#[provenance]
+	second: Provenance,
+}
+
+struct Foreign {
+	option: Option<u8>,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(remote = "Foreign")]
+struct ForeignDef {
+	option: Option<u8>,
+	#[provenance]
+	provenance: Provenance,
+}
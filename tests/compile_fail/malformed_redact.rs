@@ -0,0 +1,12 @@
+use check_initial_state_derive::CheckInitialState;
+
+fn main() {}
+
+#[derive(CheckInitialState)]
+struct Builder {
+	#[redact = true]
+	valued: Option<u8>,
+	#[redact]
+	#[redact]
+	twice: Option<u8>,
+}
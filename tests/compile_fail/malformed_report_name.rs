@@ -0,0 +1,17 @@
+use check_initial_state_derive::CheckInitialState;
+
+fn main() {}
+
+#[derive(CheckInitialState)]
+struct Builder {
+	#[report_name]
+	missing: Option<u8>,
+	#[report_name = "{name}"]
+	braces: Option<u8>,
+	#[report_name = "First"]
+	#[report_name = "Second"]
+	twice: Option<u8>,
+	#[ignore_field]
+	#[report_name = "Ignored"]
+	ignored: Option<u8>,
+}
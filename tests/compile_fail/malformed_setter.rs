@@ -0,0 +1,15 @@
+use check_initial_state_derive::CheckInitialState;
+
+fn main() {}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(constructor = "new", mutation_test, setter = "set-{}")]
+struct InvalidSetter {
+	option: Option<i32>,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(setter_hint = "{} setter")]
+struct InvalidSetterHint {
+	option: Option<i32>,
+}
@@ -0,0 +1,15 @@
+use check_initial_state_derive::CheckInitialState;
+
+fn main() {}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(setter_hint = "set_option")]
+struct WithoutPlaceholder {
+	option: Option<i32>,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(setter_hint = set_option)]
+struct NotLiteral {
+	option: Option<i32>,
+}
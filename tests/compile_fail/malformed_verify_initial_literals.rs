@@ -0,0 +1,14 @@
+use check_initial_state_derive::verify_initial_literals;
+
+fn main() {}
+
+#[verify_initial_literals(strict)]
+impl Options {}
+
+#[verify_initial_literals]
+struct Options {}
+
+#[verify_initial_literals]
+impl<T> Trait for [T] {}
+
+trait Trait {}
@@ -0,0 +1,15 @@
+use check_initial_state_derive::CheckInitialState;
+
+fn main() {}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(visibility = "crate-wide")]
+struct InvalidVisibility {
+	option: Option<i32>,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(visibility = pub)]
+struct UnquotedVisibility {
+	option: Option<i32>,
+}
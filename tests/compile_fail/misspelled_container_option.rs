@@ -0,0 +1,15 @@
+use check_initial_state_derive::CheckInitialState;
+
+fn main() {}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(constuctor = "new")]
+struct Struct {
+	option: Option<i32>,
+}
+
+impl Struct {
+	fn new() -> Self {
+		return Self { option: None };
+	}
+}
@@ -0,0 +1,15 @@
+use check_initial_state_derive::CheckInitialState;
+
+fn main() {}
+
+mod foreign {
+	pub struct Builder {
+		pub option: Option<i32>,
+	}
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(remote = "foreign::Builder", no_panic)]
+struct BuilderDef {
+	option: Option<i32>,
+}
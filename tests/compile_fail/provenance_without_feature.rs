@@ -0,0 +1,13 @@
+use check_initial_state_derive::CheckInitialState;
+
+fn main() {}
+
+#[derive(Default)]
+struct Provenance;
+
+#[derive(CheckInitialState)]
+struct Builder {
+	option: Option<u8>,
+	#[provenance]
+	provenance: Provenance,
+}
@@ -0,0 +1,15 @@
+use check_initial_state_derive::CheckInitialState;
+
+fn main() {}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(constructor = "Self::new", register)]
+struct Struct {
+	option: Option<i32>,
+}
+
+impl Struct {
+	fn new() -> Self {
+		Self { option: None }
+	}
+}
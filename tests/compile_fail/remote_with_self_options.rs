@@ -0,0 +1,21 @@
+use check_initial_state_derive::CheckInitialState;
+
+fn main() {}
+
+mod foreign {
+	pub struct Builder {
+		pub option: Option<i32>,
+	}
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(remote = "foreign::Builder", initial_const)]
+struct BuilderDef {
+	option: Option<i32>,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(remote = "foreign::Builder()")]
+struct MalformedRemote {
+	option: Option<i32>,
+}
@@ -0,0 +1,15 @@
+use check_initial_state_derive::CheckInitialState;
+
+fn main() {}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(constructor = "new", setter = "set_{}")]
+struct Struct {
+	option: Option<i32>,
+}
+
+impl Struct {
+	fn new() -> Self {
+		Self { option: None }
+	}
+}
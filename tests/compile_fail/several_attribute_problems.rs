@@ -0,0 +1,14 @@
+use check_initial_state_derive::CheckInitialState;
+
+fn main() {}
+
+#[derive(CheckInitialState)]
+struct Struct {
+	#[ignore_field(defualt = "0", reason = 1)]
+	first: i32,
+	#[expect_true(value)]
+	second: bool,
+	#[ignore_field(default = "None")]
+	#[expect_false]
+	third: Option<bool>,
+}
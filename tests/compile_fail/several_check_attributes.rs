@@ -0,0 +1,10 @@
+use check_initial_state_derive::CheckInitialState;
+
+fn main() {}
+
+#[derive(CheckInitialState)]
+struct Struct {
+	#[expect_false]
+	#[expect_true]
+	flag: bool,
+}
@@ -0,0 +1,13 @@
+use check_initial_state_derive::CheckInitialState;
+
+fn main() {}
+
+#[derive(CheckInitialState)]
+struct Builder {
+	option: Option<u8>,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(constructor = Self(Builder { option: None }), mutation_test)]
+#[repr(transparent)]
+struct Wrapper(Builder);
@@ -0,0 +1,12 @@
+#![deny(deprecated)]
+
+use check_initial_state_derive::CheckInitialState;
+
+fn main() {}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(warn_empty_check)]
+struct Struct {
+	#[ignore_field]
+	integer: i32,
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "coverage")]
+#![cfg_attr(coverage_nightly, feature(coverage_attribute))]
+
+use check_initial_state_derive::CheckInitialState;
+
+/// Checks that the code generated with `coverage` feature works the same.
+#[test]
+fn coverage_excluded_check() {
+	Struct::new().check_initial_state();
+}
+
+/// Checks the case when an `Option` field is `Some` with `coverage` feature.
+#[test]
+#[should_panic(expected = "Field `option` has Some value instead of None")]
+fn coverage_excluded_check_violated() {
+	Struct { option: Some(10) }.check_initial_state();
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(constructor = "new", mutation_test)]
+struct Struct {
+	option: Option<i32>,
+}
+
+impl Struct {
+	fn new() -> Self {
+		Self { option: None }
+	}
+}
@@ -0,0 +1,35 @@
+use check_initial_state_derive::CheckInitialState;
+
+/// Stands for a framework, which re-exports the runtime crate under its own
+/// name.
+mod facade {
+	pub use check_initial_state as check;
+}
+
+/// Checks that the trait is implemented through the re-exported path.
+#[test]
+fn trait_implemented() {
+	let builder = Builder { option: Some(10), started: false };
+	let error = facade::check::CheckInitialState::try_check_initial_state(&builder).unwrap_err();
+	assert_eq!(error.violations()[0].field(), "option");
+}
+
+/// Checks that a check through the re-exported path panics as usual.
+#[test]
+#[should_panic(expected = "Field `started` is set instead of unset")]
+fn check_panics() {
+	Builder { option: None, started: true }.check_initial_state();
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(crate = "crate::facade::check", constructor = "Self::new", auto_unset)]
+struct Builder {
+	option: Option<i32>,
+	started: bool,
+}
+
+impl Builder {
+	fn new() -> Self {
+		Self { option: None, started: false }
+	}
+}
@@ -0,0 +1,39 @@
+#![cfg(feature = "defmt")]
+
+use check_initial_state_derive::CheckInitialState;
+
+/// Checks that nothing is logged for a struct which is in initial state.
+#[test]
+fn defmt_report_without_violations() {
+	assert!(Struct { option: None, dirty: false }.initial_state_report_defmt());
+	assert!(defmt::export::fetch_bytes().is_empty());
+}
+
+/// Checks that each violation is logged.
+#[test]
+fn defmt_report_with_violations() {
+	let logged_before = defmt::export::fetch_string_index();
+	assert!(!Struct { option: Some(10), dirty: true }.initial_state_report_defmt());
+	// Each logged message interns its format string
+	assert_eq!(defmt::export::fetch_string_index() - logged_before, 2);
+	assert!(!defmt::export::fetch_bytes().is_empty());
+}
+
+/// Checks that a tagged message with braces is a valid format string.
+#[test]
+fn defmt_report_tagged() {
+	assert!(!Tagged { option: Some(10) }.initial_state_report_defmt());
+}
+
+#[derive(CheckInitialState)]
+struct Struct {
+	option: Option<i32>,
+	#[expect_false]
+	dirty: bool,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(message_prefix = "{hygiene} ")]
+struct Tagged {
+	option: Option<i32>,
+}
@@ -0,0 +1,70 @@
+use check_initial_state::CheckInitialState;
+
+/// Checks that nothing panics for a facade owning a builder in initial state,
+/// while other fields of the facade are not checked.
+#[test]
+fn delegated_initial_state() {
+	Facade { name: Some("facade".to_owned()), builder: Builder::default() }.check_initial_state();
+}
+
+/// Checks that the check of a facade reports fields of the delegated builder.
+#[test]
+#[should_panic(expected = "Field `enabled` is true instead of false")]
+fn delegated_violation() {
+	Facade { name: None, builder: Builder { option: None, enabled: true } }.check_initial_state();
+}
+
+/// Checks that all violations of the delegated builder are reported.
+#[test]
+fn delegated_reports() {
+	let facade = Facade { name: None, builder: Builder { option: Some(1), enabled: true } };
+	let mut report = Vec::new();
+	facade.write_initial_state_report(&mut report).unwrap();
+	assert_eq!(
+		String::from_utf8(report).unwrap(),
+		"Field `option` has Some value instead of None\nField `enabled` is true instead of false\n",
+	);
+	assert_eq!(facade.try_check_initial_state().unwrap_err().violations().len(), 2);
+}
+
+/// Checks that the context precedes the message of the delegated field.
+#[test]
+#[should_panic(expected = "facade scenario: Field `enabled` is true instead of false")]
+fn delegated_context() {
+	Facade { name: None, builder: Builder { option: None, enabled: true } }
+		.check_initial_state_with_context("facade scenario");
+}
+
+/// Checks that a tuple struct may delegate its check.
+#[test]
+#[should_panic(expected = "Field `option` has Some value instead of None")]
+fn delegated_tuple_field() {
+	Tuple(1, Builder { option: Some(1), enabled: false }).check_initial_state();
+}
+
+/// Checks that `delegate_check` is accepted by `check_initial_state_for!`.
+#[test]
+#[should_panic(expected = "Field `option` has Some value instead of None")]
+fn delegated_check_for() {
+	let facade = Facade { name: None, builder: Builder { option: Some(1), enabled: false } };
+	check_initial_state::check_initial_state_for!(facade, Facade { name, #[delegate_check] builder });
+}
+
+#[derive(CheckInitialState, Default)]
+struct Builder {
+	option: Option<i32>,
+	#[expect_false]
+	enabled: bool,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(constructor = Self { name: None, builder: Builder::default() })]
+struct Facade {
+	name: Option<String>,
+	#[delegate_check]
+	builder: Builder,
+}
+
+#[derive(CheckInitialState)]
+#[allow(dead_code)]
+struct Tuple(u8, #[delegate_check] Builder);
@@ -0,0 +1,54 @@
+use check_initial_state_derive::CheckInitialState;
+use std::time::Instant;
+
+/// Checks that the default instance is in initial state, taking ignored fields
+/// from their defaults.
+#[test]
+fn default_created() {
+	let builder = Builder::default();
+	builder.check_initial_state();
+	assert_eq!(builder.host, "localhost");
+	assert!(builder.created <= Instant::now());
+}
+
+/// Checks that a generic struct gets `Default` without bounds on its
+/// parameters.
+#[test]
+fn generic_default_created() {
+	Generic::<NotDefault>::default().check_initial_state();
+}
+
+/// Checks that both constructors may be generated.
+#[test]
+fn builder_fn_combined() {
+	Combined::default().check_initial_state();
+	Combined::builder().check_initial_state();
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(derive_default)]
+struct Builder {
+	_option: Option<i32>,
+	#[expect_true]
+	_enabled: bool,
+	#[expect_zero]
+	_count: u32,
+	#[ignore_field(default = "String::from(\"localhost\")")]
+	host: String,
+	#[ignore_field(default = Instant::now())]
+	created: Instant,
+}
+
+struct NotDefault;
+
+#[derive(CheckInitialState)]
+#[check_initial_state(derive_default)]
+struct Generic<T> {
+	_option: Option<T>,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(derive_default, builder_fn)]
+struct Combined {
+	_option: Option<i32>,
+}
@@ -0,0 +1,63 @@
+use check_initial_state_derive::CheckInitialState;
+
+/// Checks that the state of all checked fields is rendered, and fields which
+/// are not in initial state are marked.
+#[test]
+fn state_rendered() {
+	let builder = Builder { option: None, enabled: true, count: 0, _ignored: Some(1) };
+	assert_eq!(
+		format!("{}", builder),
+		"Fields of `Builder`:\n    option  | None\n  * enabled | true\n    count   | 0",
+	);
+}
+
+/// Checks that nothing is marked for a struct in initial state.
+#[test]
+fn initial_state_rendered() {
+	let builder = Builder { option: None, enabled: false, count: 0, _ignored: None };
+	builder.check_initial_state();
+	assert!(!format!("{}", builder).contains('*'));
+}
+
+/// Checks that report names are rendered, and width specifiers are ignored.
+#[test]
+fn report_names_rendered() {
+	assert_eq!(
+		format!("{:>40}", Renamed { option: Some(1) }),
+		"Fields of `Renamed`:\n  * Renamed option | Some",
+	);
+}
+
+/// Checks that a generic struct is rendered.
+#[test]
+fn generic_rendered() {
+	assert_eq!(
+		Generic { option: Some("value") }.to_string(),
+		"Fields of `Generic`:\n  * option | Some",
+	);
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(derive_display)]
+struct Builder {
+	option: Option<i32>,
+	#[expect_false]
+	enabled: bool,
+	#[expect_zero]
+	count: u32,
+	#[ignore_field]
+	_ignored: Option<i32>,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(derive_display)]
+struct Renamed {
+	#[report_name = "Renamed option"]
+	option: Option<i32>,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(derive_display)]
+struct Generic<T> {
+	option: Option<T>,
+}
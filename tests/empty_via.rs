@@ -0,0 +1,52 @@
+use check_initial_state_derive::CheckInitialState;
+use std::collections::HashMap;
+
+/// Checks fields which are empty according to their mapped methods.
+#[test]
+fn empty_fields() {
+	Struct::new().check_initial_state();
+}
+
+/// Checks the case when a mapped field is not empty.
+#[test]
+#[should_panic(expected = "Field `items` is not empty according to `is_empty()`")]
+fn non_empty_field() {
+	let mut instance = Struct::new();
+	instance.items.push(1);
+	instance.check_initial_state();
+}
+
+/// Checks that a mapping is applied to a type written with a path.
+#[test]
+#[should_panic(expected = "Field `names` is not empty according to `is_empty()`")]
+fn non_empty_field_with_path_type() {
+	let mut instance = Struct::new();
+	instance.names.push(String::new());
+	instance.check_initial_state();
+}
+
+/// Checks that a check attribute takes precedence over a mapping.
+#[test]
+fn check_attribute_overrides_mapping() {
+	let mut instance = Struct::new();
+	instance.preset.push(1);
+	instance.check_initial_state();
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(constructor = "new", mutation_test)]
+#[check_initial_state(empty_via(Vec = "is_empty", HashMap = is_empty))]
+struct Struct {
+	option: Option<i32>,
+	items: Vec<u8>,
+	names: std::vec::Vec<String>,
+	map: HashMap<u8, u8>,
+	#[expect_matches(_)]
+	preset: Vec<u8>,
+}
+
+impl Struct {
+	fn new() -> Self {
+		Self { option: None, items: Vec::new(), names: Vec::new(), map: HashMap::new(), preset: vec![] }
+	}
+}
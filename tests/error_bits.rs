@@ -0,0 +1,61 @@
+use check_initial_state_derive::CheckInitialState;
+
+/// Checks that no bits are set for a struct in initial state.
+#[test]
+fn no_error_bits() {
+	assert_eq!(Struct::new().initial_state_errors(), 0);
+}
+
+/// Checks that a bit is set for each checked field which is not in initial
+/// state.
+#[test]
+fn error_bits_set() {
+	let instance = Struct { option: Some(1), ignored: Some(1), dirty: true, count: 0 };
+	assert_eq!(instance.initial_state_errors(), 0b011);
+}
+
+/// Checks the mapping of bits to field names.
+#[test]
+fn error_bit_names() {
+	assert_eq!(
+		Struct::INITIAL_STATE_ERROR_BITS,
+		[(0b001, "option"), (0b010, "dirty"), (0b100, "count")],
+	);
+}
+
+/// Checks error bits of a struct compared with a reference instance.
+#[test]
+fn error_bits_compared_with_reference() {
+	assert_eq!(Reference { option: None }.initial_state_errors(), 0b1);
+}
+
+#[allow(dead_code)]
+#[derive(CheckInitialState)]
+#[check_initial_state(constructor = "new", error_bits)]
+struct Struct {
+	option: Option<i32>,
+	#[ignore_field]
+	ignored: Option<i32>,
+	#[expect_false]
+	dirty: bool,
+	#[expect_zero]
+	count: u8,
+}
+
+impl Struct {
+	fn new() -> Self {
+		Self { option: None, ignored: Some(1), dirty: false, count: 0 }
+	}
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(compare_with = "Self::new", error_bits)]
+struct Reference {
+	option: Option<i32>,
+}
+
+impl Reference {
+	fn new() -> Self {
+		Self { option: Some(1) }
+	}
+}
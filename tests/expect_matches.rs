@@ -0,0 +1,44 @@
+use check_initial_state_derive::CheckInitialState;
+
+/// Checks fields which match their patterns.
+#[test]
+fn fields_match_patterns() {
+	Struct { option: None, state: State::Idle, limit: None }.check_initial_state();
+	Struct { option: None, state: State::Idle, limit: Some(0) }.check_initial_state();
+}
+
+/// Checks the case when a field doesn't match its pattern.
+#[test]
+#[should_panic(expected = "Field `state` doesn't match `State::Idle`")]
+fn path_pattern_mismatch() {
+	Struct { option: None, state: State::Running(1), limit: None }.check_initial_state();
+}
+
+/// Checks the case when a field doesn't match its or-pattern.
+#[test]
+#[should_panic(expected = "Field `limit` doesn't match `None | Some(0)`")]
+fn or_pattern_mismatch() {
+	Struct { option: None, state: State::Idle, limit: Some(10) }.check_initial_state();
+}
+
+#[allow(dead_code)]
+enum State {
+	Idle,
+	Running(u32),
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(constructor = "new", mutation_test)]
+struct Struct {
+	option: Option<i32>,
+	#[expect_matches(State::Idle)]
+	state: State,
+	#[expect_matches(None | Some(0))]
+	limit: Option<u32>,
+}
+
+impl Struct {
+	fn new() -> Self {
+		Self { option: None, state: State::Idle, limit: Some(0) }
+	}
+}
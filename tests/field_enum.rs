@@ -0,0 +1,101 @@
+use check_initial_state_derive::CheckInitialState;
+
+/// Checks that a struct in initial state reports no fields.
+#[test]
+fn initial_state_accepted() {
+	assert_eq!(Builder::default().try_check_initial_state_fields(), Ok(()));
+}
+
+/// Checks that all fields which are not in initial state are reported in the
+/// order of declaration, and may be matched exhaustively.
+#[test]
+fn violations_reported() {
+	let builder = Builder { max_retries: 1, _ignored: None, r#type: Some(1), option_2: Some(2) };
+	let fields = builder.try_check_initial_state_fields().unwrap_err();
+	assert_eq!(fields, [BuilderField::MaxRetries, BuilderField::Type, BuilderField::Option2]);
+	let names = fields.iter()
+		.map(|field| match field {
+			BuilderField::MaxRetries => "retries",
+			BuilderField::Type => "type",
+			BuilderField::Option2 => "second",
+		})
+		.collect::<Vec<_>>();
+	assert_eq!(names, ["retries", "type", "second"]);
+}
+
+/// Checks that report names are returned by the enum.
+#[test]
+fn names_returned() {
+	assert_eq!(BuilderField::MaxRetries.name(), "max_retries");
+	assert_eq!(BuilderField::Type.name(), "r#type");
+	assert_eq!(BuilderField::Option2.name(), "Second option");
+}
+
+/// Checks that a generic struct gets a non-generic enum.
+#[test]
+fn generic_struct_supported() {
+	let generic = Generic { option: Some("value") };
+	assert_eq!(generic.try_check_initial_state_fields(), Err(vec![GenericField::Option]));
+}
+
+#[derive(CheckInitialState, Default)]
+#[check_initial_state(field_enum)]
+struct Builder {
+	#[expect_zero]
+	max_retries: u32,
+	#[ignore_field]
+	_ignored: Option<i32>,
+	r#type: Option<i32>,
+	#[report_name = "Second option"]
+	option_2: Option<i32>,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(field_enum)]
+struct Generic<T> {
+	option: Option<T>,
+}
+
+/// Checks that the enum is converted from and to field names as they are
+/// written in the struct.
+#[test]
+fn strings_converted() {
+	for field in [BuilderField::MaxRetries, BuilderField::Type, BuilderField::Option2] {
+		assert_eq!(field.to_string().parse(), Ok(field));
+	}
+	assert_eq!(BuilderField::Option2.to_string(), "option_2");
+	assert_eq!(format!("{:>10}", BuilderField::Type), "    r#type");
+	let error = "Second option".parse::<BuilderField>().unwrap_err();
+	assert_eq!(error.struct_name(), "Builder");
+	assert_eq!(error.name(), "Second option");
+	assert!("_ignored".parse::<BuilderField>().is_err());
+}
+
+/// Checks that violations may be filtered by names, e.g. read from
+/// configuration.
+#[test]
+fn violations_filtered() {
+	let allowed = "max_retries,option_2".split(',')
+		.map(|name| name.parse::<BuilderField>().unwrap())
+		.collect::<Vec<_>>();
+	let builder = Builder { max_retries: 1, _ignored: None, r#type: Some(1), option_2: Some(2) };
+	let unexpected = builder.try_check_initial_state_fields().unwrap_err().into_iter()
+		.filter(|field| !allowed.contains(field))
+		.collect::<Vec<_>>();
+	assert_eq!(unexpected, [BuilderField::Type]);
+}
+
+/// Checks that an enum without variants is generated for a struct without
+/// checked fields.
+#[test]
+fn empty_enum_generated() {
+	assert!("option".parse::<EmptyField>().is_err());
+	Empty { _ignored: None }.try_check_initial_state_fields().unwrap();
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(field_enum)]
+struct Empty {
+	#[ignore_field]
+	_ignored: Option<i32>,
+}
@@ -0,0 +1,50 @@
+use check_initial_state_derive::CheckInitialState;
+
+/// Checks that metadata covers all fields, including ignored ones, in the
+/// order of declaration.
+#[test]
+fn field_metadata() {
+	let names = Struct::fields().iter().map(|field| field.name()).collect::<Vec<_>>();
+	assert_eq!(names, ["host", "attempt", "r#type", "cache"]);
+	let ignored = Struct::fields().iter().map(|field| field.ignored()).collect::<Vec<_>>();
+	assert_eq!(ignored, [false, true, false, true]);
+	let reasons = Struct::fields().iter().map(|field| field.reason()).collect::<Vec<_>>();
+	assert_eq!(reasons, [None, Some("Is set by the scheduler"), None, None]);
+	let groups = Struct::fields().iter().map(|field| field.group()).collect::<Vec<_>>();
+	assert_eq!(groups, [Some("network"), None, None, Some("storage")]);
+	assert!(Empty::fields().is_empty());
+}
+
+/// Checks that metadata is available in `const` context.
+#[test]
+fn const_evaluation() {
+	const GROUPED: usize = {
+		let fields = Struct::fields();
+		let mut count = 0;
+		let mut index = 0;
+		while index < fields.len() {
+			if fields[index].group().is_some() {
+				count += 1;
+			}
+			index += 1;
+		}
+		count
+	};
+	assert_eq!(GROUPED, 2);
+}
+
+#[derive(CheckInitialState)]
+#[allow(dead_code)]
+struct Struct {
+	#[field_group = "network"]
+	host: Option<String>,
+	#[ignore_field(reason = "Is set by the scheduler")]
+	attempt: u32,
+	r#type: Option<u8>,
+	#[ignore_field]
+	#[field_group = "storage"]
+	cache: Vec<u8>,
+}
+
+#[derive(CheckInitialState)]
+struct Empty {}
@@ -0,0 +1,36 @@
+use check_initial_state_derive::CheckInitialState;
+
+/// Checks that each checked field is visited with its state in the order of
+/// declaration, while ignored fields are skipped.
+#[test]
+fn field_state_visitor() {
+	let builder =
+		Builder { option: Some(1), enabled: false, count: 3, r#type: None, _ignored: Some(1) };
+	let mut states = Vec::new();
+	builder.for_each_field_state(|name, set| states.push((name, set)));
+	assert_eq!(states, [("option", true), ("enabled", false), ("count", true), ("r#type", false)]);
+}
+
+/// Checks that a struct without checked fields never calls the visitor.
+#[test]
+fn no_checked_fields() {
+	Empty { _ignored: Some(1) }.for_each_field_state(|name, _| panic!("`{}` visited", name));
+}
+
+#[derive(CheckInitialState)]
+struct Builder {
+	option: Option<i32>,
+	#[expect_false]
+	enabled: bool,
+	#[expect_zero]
+	count: u32,
+	r#type: Option<u8>,
+	#[ignore_field]
+	_ignored: Option<i32>,
+}
+
+#[derive(CheckInitialState)]
+struct Empty {
+	#[ignore_field]
+	_ignored: Option<i32>,
+}
@@ -0,0 +1,40 @@
+use check_initial_state_derive::CheckInitialState;
+use serde::{Deserialize, Serialize};
+use smart_default::SmartDefault;
+
+/// Checks that helper attributes of other derives leave the checks intact.
+#[test]
+fn other_derives_combined() {
+	Request::default().check_initial_state();
+}
+
+/// Checks that a foreign attribute, which merely ends with `ignore_field`,
+/// doesn't exclude the field from the check.
+#[test]
+#[should_panic(expected = "Field `option` has Some value instead of None")]
+fn foreign_ignore_field_not_matched() {
+	Request { option: Some(1), ..Request::default() }.check_initial_state();
+}
+
+/// Checks that a field of a smart default is checked against the default in
+/// presence of other derives' attributes.
+#[test]
+#[should_panic(expected = "Field `retries` doesn't equal `3`")]
+fn smart_default_checked() {
+	Request { retries: 0, ..Request::default() }.check_initial_state();
+}
+
+#[derive(CheckInitialState, Deserialize, Serialize, SmartDefault)]
+#[rustfmt::ignore_field]
+#[serde(deny_unknown_fields)]
+struct Request {
+	#[serde(rename = "id")]
+	#[rustfmt::ignore_field]
+	option: Option<i32>,
+	#[default(3)]
+	#[serde(default)]
+	retries: u32,
+	#[serde(skip)]
+	#[ignore_field]
+	_cache: Option<String>,
+}
@@ -0,0 +1,37 @@
+use check_initial_state_derive::CheckInitialState;
+
+/// Checks fields which are ignored by a boolean flag or by a build
+/// configuration.
+#[test]
+fn ignore_field_flag() {
+	Struct { option: None, integer: 10, cached: Some(10), overridden: None }.check_initial_state();
+}
+
+/// Checks the case when a field annotated with `ignore_field = false` is `Some`.
+#[test]
+#[should_panic(expected = "Field `option` has Some value instead of None")]
+fn not_ignored_field_is_some() {
+	Struct { option: Some(10), integer: 10, cached: None, overridden: None }.check_initial_state();
+}
+
+/// Checks the case when a field which `ignore_field` is overridden by a
+/// following `ignore_field = false` is `Some`.
+#[test]
+#[should_panic(expected = "Field `overridden` has Some value instead of None")]
+fn overridden_ignore_field_is_some() {
+	Struct { option: None, integer: 10, cached: None, overridden: Some(10) }.check_initial_state();
+}
+
+#[allow(dead_code)]
+#[derive(CheckInitialState)]
+struct Struct {
+	#[ignore_field = false]
+	option: Option<i32>,
+	#[ignore_field = true]
+	integer: i32,
+	#[cfg_attr(test, ignore_field)]
+	cached: Option<i32>,
+	#[ignore_field]
+	#[cfg_attr(test, ignore_field = false)]
+	overridden: Option<i32>,
+}
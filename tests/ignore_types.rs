@@ -0,0 +1,64 @@
+use check_initial_state_derive::CheckInitialState;
+use std::marker::PhantomData;
+use std::time::Instant;
+
+/// Checks that fields of listed types are skipped, whatever their state is.
+#[test]
+fn listed_types_skipped() {
+	Builder::new().check_initial_state();
+	assert_eq!(Builder::FIELD_NAMES, ["option", "started"]);
+}
+
+/// Checks that fields of other types are still checked.
+#[test]
+#[should_panic(expected = "Field `option` has Some value instead of None")]
+fn other_fields_checked() {
+	Builder { option: Some(1), ..Builder::new() }.check_initial_state();
+}
+
+/// Checks that a field annotated with a check attribute is checked even if its
+/// type is listed.
+#[test]
+#[should_panic(expected = "Field `started` doesn't match `Metrics(false)`")]
+fn annotated_field_checked() {
+	Builder { started: Metrics(true), ..Builder::new() }.check_initial_state();
+}
+
+/// Checks that a listed `Option` type is not reported as ignored without a
+/// reason.
+#[test]
+fn listed_option_has_reason() {
+	Warned { _instant: Some(Instant::now()), _marker: PhantomData }.check_initial_state();
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(ignore_types(PhantomData, std::time::Instant, Metrics))]
+struct Builder {
+	option: Option<i32>,
+	_marker: PhantomData<i32>,
+	_created: Instant,
+	_metrics: Metrics,
+	#[expect_matches(Metrics(false))]
+	started: Metrics,
+}
+
+impl Builder {
+	fn new() -> Self {
+		Self {
+			option: None,
+			_marker: PhantomData,
+			_created: Instant::now(),
+			_metrics: Metrics(true),
+			started: Metrics(false),
+		}
+	}
+}
+
+struct Metrics(bool);
+
+#[derive(CheckInitialState)]
+#[check_initial_state(warn_ignored_option, ignore_types(Option, PhantomData))]
+struct Warned {
+	_instant: Option<Instant>,
+	_marker: PhantomData<u8>,
+}
@@ -0,0 +1,21 @@
+#![deny(deprecated)]
+
+use check_initial_state_derive::CheckInitialState;
+
+/// Checks that an `Option` field ignored with a reason compiles without
+/// warnings and is not checked.
+#[test]
+fn ignored_option_with_reason() {
+	Struct { option: None, preset: Some(10), integer: 10 }.check_initial_state();
+}
+
+#[allow(dead_code)]
+#[derive(CheckInitialState)]
+#[check_initial_state(warn_ignored_option)]
+struct Struct {
+	option: Option<i32>,
+	#[ignore_field(reason = "Preset is expected to be configured by default")]
+	preset: Option<i32>,
+	#[ignore_field]
+	integer: i32,
+}
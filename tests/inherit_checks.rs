@@ -0,0 +1,52 @@
+use check_initial_state_derive::CheckInitialState;
+
+/// Checks that a composed struct checks the fields inherited from its bases
+/// along with its own ones.
+#[test]
+fn inherited_fields_checked() {
+	let mut builder = Composed::default();
+	builder.check_initial_state();
+	builder.host = Some("localhost".to_owned());
+	let mut report = Vec::new();
+	builder.write_initial_state_report(&mut report).unwrap();
+	assert_eq!(report, b"Field `host` has Some value instead of None\n");
+}
+
+/// Defines a struct with the fields shared by several builders, optionally
+/// extended with own fields.
+macro_rules! network_builder {
+	($name:ident { $($field:ident: $ty:ty),* $(,)? }) => {
+		#[derive(CheckInitialState, Default)]
+		#[inherit_checks(Network, nested::Limits)]
+		struct $name {
+			host: Option<String>,
+			port: Option<u16>,
+			#[expect_zero]
+			retries: u32,
+			$($field: $ty),*
+		}
+	};
+}
+
+#[derive(CheckInitialState)]
+#[allow(dead_code)]
+struct Network {
+	host: Option<String>,
+	port: Option<u16>,
+	#[ignore_field]
+	label: String,
+}
+
+mod nested {
+	use check_initial_state_derive::CheckInitialState;
+
+	#[derive(CheckInitialState)]
+	#[allow(dead_code)]
+	pub struct Limits<T> {
+		#[expect_zero]
+		retries: u32,
+		timeout: Option<T>,
+	}
+}
+
+network_builder!(Composed { timeout: Option<u64>, user: Option<String> });
@@ -0,0 +1,34 @@
+use check_initial_state_derive::CheckInitialState;
+use std::fmt::Debug;
+
+static TEMPLATE: Struct = Struct::INITIAL;
+
+/// Checks that `INITIAL` constant has checked fields set to `None` and ignored
+/// fields set to their defaults.
+#[test]
+fn initial_const() {
+	TEMPLATE.check_initial_state();
+	assert_eq!(TEMPLATE._integer, 10);
+	assert_eq!(TEMPLATE._string, "value");
+	assert_eq!(TEMPLATE._unquoted, 3);
+	Generic::<String>::INITIAL.check_initial_state();
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(initial_const)]
+struct Struct {
+	option: Option<String>,
+	#[ignore_field(default = "5 * 2")]
+	_integer: i32,
+	option2: Option<u8>,
+	#[ignore_field(default = "\"value\"")]
+	_string: &'static str,
+	#[ignore_field(default = 1 + 2, reason = "Is specified without quotes")]
+	_unquoted: u8,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(initial_const)]
+struct Generic<T: Debug> {
+	option: Option<T>,
+}
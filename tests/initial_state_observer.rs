@@ -0,0 +1,84 @@
+use check_initial_state::{InitialStateObserver, Violation};
+use check_initial_state_derive::CheckInitialState;
+
+/// Checks that each checked field is observed in the order of declaration,
+/// followed by its violation, and the check is completed.
+#[test]
+fn events_observed() {
+	let mut observer = Recorder::default();
+	Builder { option: Some(1), enabled: false, count: 0, _ignored: Some(1) }
+		.check_with_observer(&mut observer);
+	assert_eq!(
+		observer.events,
+		[
+			"field option set",
+			"violation Field `option` has Some value instead of None",
+			"field enabled unset",
+			"field count unset",
+			"complete Builder",
+		],
+	);
+}
+
+/// Checks that an observer implementing `on_field()` only is supported, and
+/// nothing panics on violations.
+#[test]
+fn default_methods() {
+	let mut observer = Counter(0);
+	Builder { option: Some(1), enabled: true, count: 2, _ignored: None }
+		.check_with_observer(&mut observer);
+	assert_eq!(observer.0, 3);
+}
+
+/// Checks that a trait object is accepted as an observer.
+#[test]
+fn dyn_observer() {
+	let mut recorder = Recorder::default();
+	let observer: &mut dyn InitialStateObserver = &mut recorder;
+	Empty { _ignored: None }.check_with_observer(observer);
+	assert_eq!(recorder.events, ["complete Empty"]);
+}
+
+#[derive(Default)]
+struct Recorder {
+	events: Vec<String>,
+}
+
+impl InitialStateObserver for Recorder {
+	fn on_field(&mut self, name: &'static str, is_set: bool) {
+		self.events.push(format!("field {} {}", name, if is_set { "set" } else { "unset" }));
+	}
+
+	fn on_violation(&mut self, violation: &Violation) {
+		self.events.push(format!("violation {}", violation));
+	}
+
+	fn on_complete(&mut self, struct_name: &'static str) {
+		self.events.push(format!("complete {}", struct_name));
+	}
+}
+
+struct Counter(usize);
+
+impl InitialStateObserver for Counter {
+	fn on_field(&mut self, _: &'static str, _: bool) {
+		self.0 += 1;
+	}
+}
+
+#[derive(CheckInitialState)]
+struct Builder {
+	option: Option<i32>,
+	#[expect_false]
+	enabled: bool,
+	#[expect_zero]
+	count: u32,
+	#[ignore_field]
+	_ignored: Option<i32>,
+}
+
+#[derive(CheckInitialState)]
+struct Empty {
+	#[ignore_field]
+	_ignored: Option<i32>,
+}
@@ -0,0 +1,36 @@
+use check_initial_state_derive::CheckInitialState;
+use std::collections::HashMap;
+
+/// Checks that names and types of checked fields are listed, while ignored
+/// fields are not.
+#[test]
+fn introspection() {
+	assert_eq!(Struct::FIELD_NAMES, ["option", "map", "callback", "pair", "reference"]);
+	assert_eq!(
+		Struct::FIELD_TYPES,
+		[
+			"Option<String>",
+			"Option<HashMap<String, Vec<u8>>>",
+			"Option<Box<dyn Fn(u8) -> u8 + Send>>",
+			"::std::option::Option<(i32, [u8; 4])>",
+			"Option<&'static str>",
+		],
+	);
+	assert_eq!(Empty::FIELD_NAMES.len(), 0);
+	assert_eq!(Empty::FIELD_TYPES.len(), 0);
+}
+
+#[derive(CheckInitialState)]
+#[allow(dead_code)]
+struct Struct {
+	option: Option<String>,
+	#[ignore_field]
+	integer: i32,
+	map: Option<HashMap<String, Vec<u8>>>,
+	callback: Option<Box<dyn Fn(u8) -> u8 + Send>>,
+	pair: ::std::option::Option<(i32, [u8; 4])>,
+	reference: Option<&'static str>,
+}
+
+#[derive(CheckInitialState)]
+struct Empty {}
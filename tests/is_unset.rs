@@ -0,0 +1,50 @@
+use check_initial_state::IsUnset;
+use check_initial_state_derive::CheckInitialState;
+
+/// Checks that a custom type implementing `IsUnset` is checked without
+/// annotations.
+#[test]
+fn custom_type_unset() {
+	Builder::default().check_initial_state();
+}
+
+/// Checks that a set custom field is reported with set/unset wording.
+#[test]
+#[should_panic(expected = "Field `endpoint` is set instead of unset")]
+fn custom_type_set() {
+	Builder { endpoint: Endpoint("localhost".to_owned()), ..Builder::default() }
+		.check_initial_state();
+}
+
+/// Checks that `Option` fields keep their wording.
+#[test]
+#[should_panic(expected = "Field `option` has Some value instead of None")]
+fn option_set() {
+	Builder { option: Some(1), ..Builder::default() }.check_initial_state();
+}
+
+/// Checks that the state table and reports use set/unset labels.
+#[test]
+fn custom_type_reported() {
+	let builder = Builder { endpoint: Endpoint("localhost".to_owned()), ..Builder::default() };
+	let mut report = Vec::new();
+	builder.write_initial_state_report(&mut report).unwrap();
+	assert_eq!(String::from_utf8(report).unwrap(), "Field `endpoint` is set instead of unset\n");
+	assert!(!IsUnset::is_unset(&Some(1)));
+	assert!(IsUnset::is_unset(&None::<i32>));
+}
+
+#[derive(Default)]
+struct Endpoint(String);
+
+impl IsUnset for Endpoint {
+	fn is_unset(&self) -> bool {
+		self.0.is_empty()
+	}
+}
+
+#[derive(CheckInitialState, Default)]
+struct Builder {
+	option: Option<i32>,
+	endpoint: Endpoint,
+}
@@ -0,0 +1,55 @@
+#![cfg(feature = "json")]
+
+use check_initial_state_derive::CheckInitialState;
+use smart_default::SmartDefault;
+
+/// Checks JSON report of a struct which is in initial state.
+#[test]
+fn json_report_without_violations() {
+	let report = Struct { option: None, option2: None, _integer: 10 }.initial_state_report_json();
+	assert_eq!(report, r#"{"struct":"Struct","violations":[]}"#);
+}
+
+/// Checks JSON report of a struct with several `Some` fields.
+#[test]
+fn json_report_with_violations() {
+	let report =
+		Struct { option: Some(10), option2: Some("value".to_owned()), _integer: 10 }
+			.initial_state_report_json();
+	assert_eq!(
+		report,
+		"{\"struct\":\"Struct\",\"violations\":[\
+			{\"field\":\"option\",\"reason\":\"has Some value instead of None\"},\
+			{\"field\":\"option2\",\"reason\":\"has Some value instead of None\"}]}",
+	);
+}
+
+/// Checks that reasons containing text of the attributes are escaped, so the
+/// report stays valid JSON.
+#[test]
+fn json_report_escaped() {
+	let report = Quoted { name: Some("y"), label: String::from("other") }.initial_state_report_json();
+	assert_eq!(
+		report,
+		"{\"struct\":\"Quoted\",\"violations\":[\
+			{\"field\":\"name\",\"reason\":\"doesn't match `Some(\\\"x\\\")`\"},\
+			{\"field\":\"label\",\"reason\":\"doesn't equal `\\\"a\\\\\\\\b\\\"`\"}]}",
+	);
+}
+
+#[derive(CheckInitialState)]
+struct Struct {
+	option: Option<i32>,
+	option2: Option<String>,
+	#[ignore_field]
+	_integer: i32,
+}
+
+#[derive(CheckInitialState, SmartDefault)]
+struct Quoted {
+	#[expect_matches(Some("x"))]
+	#[default(Some("x"))]
+	name: Option<&'static str>,
+	#[default = "a\\b"]
+	label: String,
+}
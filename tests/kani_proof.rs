@@ -0,0 +1,30 @@
+#![cfg(feature = "kani")]
+
+use check_initial_state_derive::CheckInitialState;
+
+/// Checks that requesting proof harnesses keeps the struct usable outside of
+/// `cargo kani`, where the harnesses are not compiled.
+#[test]
+fn kani_proof_outside_of_kani() {
+	Counter::new().check_initial_state();
+	Counter::with_limit(10).check_initial_state();
+}
+
+#[allow(dead_code)]
+#[derive(CheckInitialState)]
+#[check_initial_state(constructors("new", "Self::with_limit(10)"), kani_proof)]
+struct Counter {
+	overflow: Option<u32>,
+	#[ignore_field]
+	limit: u32,
+}
+
+impl Counter {
+	fn new() -> Self {
+		Self { overflow: None, limit: u32::MAX }
+	}
+
+	fn with_limit(limit: u32) -> Self {
+		Self { overflow: None, limit }
+	}
+}
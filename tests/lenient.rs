@@ -0,0 +1,40 @@
+use check_initial_state_derive::CheckInitialState;
+
+/// Checks that non-`Option` fields are skipped.
+#[test]
+fn non_option_fields_skipped() {
+	Struct { option: None, integer: 10, name: "name".to_owned(), flag: false }
+		.check_initial_state();
+}
+
+/// Checks that `Option` fields are still checked.
+#[test]
+#[should_panic(expected = "Field `option` has Some value instead of None")]
+fn option_field_checked() {
+	Struct { option: Some(1), integer: 0, name: String::new(), flag: false }
+		.check_initial_state();
+}
+
+/// Checks that check attributes are still applied.
+#[test]
+#[should_panic(expected = "Field `flag` is true instead of false")]
+fn check_attribute_applied() {
+	Struct { option: None, integer: 0, name: String::new(), flag: true }
+		.check_initial_state();
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(lenient, constructor = "new", mutation_test)]
+struct Struct {
+	option: Option<i32>,
+	integer: i32,
+	name: String,
+	#[expect_false]
+	flag: bool,
+}
+
+impl Struct {
+	fn new() -> Self {
+		Self { option: None, integer: 10, name: String::new(), flag: false }
+	}
+}
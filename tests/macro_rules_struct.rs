@@ -0,0 +1,58 @@
+#![deny(deprecated)]
+
+use check_initial_state_derive::CheckInitialState;
+
+macro_rules! builder {
+	($name:ident, $constructor:expr, $($(#[$meta:meta])* $field:ident: $ty:ty),*) => {
+		#[allow(dead_code)]
+		#[derive(CheckInitialState)]
+		#[check_initial_state(
+			constructor = $constructor,
+			mutation_test,
+			empty_via(Vec = is_empty),
+			warn_ignored_option,
+		)]
+		struct $name {
+			$($(#[$meta])* $field: $ty,)*
+			#[ignore_field(default = 0, reason = "Hardcoded by the macro")]
+			preset: Option<i32>,
+		}
+
+		impl $name {
+			fn new() -> Self {
+				Self { $($field: Default::default(),)* preset: Some(1) }
+			}
+		}
+	};
+}
+
+builder!(
+	Builder,
+	new,
+	option: Option<i32>,
+	items: Vec<u8>,
+	#[expect_false] dirty: bool,
+	#[ignore_field(reason = "Passed to the macro")] cached: Option<i32>
+);
+
+/// Checks a struct generated by `macro_rules!` in initial state.
+#[test]
+fn macro_rules_struct() {
+	Builder { cached: Some(1), ..Builder::new() }.check_initial_state();
+}
+
+/// Checks the case when a field of a struct generated by `macro_rules!`,
+/// which type is passed to the macro, is not empty.
+#[test]
+#[should_panic(expected = "Field `items` is not empty according to `is_empty()`")]
+fn macro_rules_struct_field_not_empty() {
+	Builder { items: vec![1], ..Builder::new() }.check_initial_state();
+}
+
+/// Checks the case when a field of a struct generated by `macro_rules!`,
+/// which check attribute is passed to the macro, is violated.
+#[test]
+#[should_panic(expected = "Field `dirty` is true instead of false")]
+fn macro_rules_struct_check_attribute() {
+	Builder { dirty: true, ..Builder::new() }.check_initial_state();
+}
@@ -0,0 +1,39 @@
+use check_initial_state_derive::CheckInitialState;
+
+/// Checks that the panic message is surrounded with the prefix and the suffix.
+#[test]
+#[should_panic(expected = "[builder-hygiene] Field `option` has Some value instead of None {see docs}")]
+fn panic_message_tagged() {
+	Builder { option: Some(1) }.check_initial_state();
+}
+
+/// Checks that each report line is surrounded with the prefix and the suffix.
+#[test]
+fn report_tagged() {
+	let mut report = Vec::new();
+	Builder { option: Some(1) }.write_initial_state_report(&mut report).unwrap();
+	assert_eq!(
+		String::from_utf8(report).unwrap(),
+		"[builder-hygiene] Field `option` has Some value instead of None {see docs}\n",
+	);
+}
+
+/// Checks that the diff message of comparison mode is tagged.
+#[test]
+#[should_panic(expected = "[builder-hygiene] Field `option` has Some value instead of None\n")]
+fn diff_message_tagged() {
+	Compared { option: Some(1) }.check_initial_state();
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(message_prefix = "[builder-hygiene] ", message_suffix = " {see docs}")]
+struct Builder {
+	option: Option<i32>,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(compare_with = "Self { option: None }")]
+#[check_initial_state(message_prefix = "[builder-hygiene] ")]
+struct Compared {
+	option: Option<i32>,
+}
@@ -0,0 +1,82 @@
+#![cfg(feature = "metrics")]
+
+use check_initial_state::CheckInitialState;
+use metrics::{Counter, Gauge, Histogram, Key, KeyName, Metadata, Recorder, SharedString, Unit};
+use std::collections::HashMap;
+use std::panic;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Checks that each check is counted.
+#[test]
+fn checks_counted() {
+	let recorder = TestRecorder::default();
+	metrics::with_local_recorder(&recorder, || {
+		Struct { option: None, dirty: false }.check_initial_state();
+		Struct { option: None, dirty: false }.try_check_initial_state().unwrap();
+	});
+	assert_eq!(recorder.value("check_initial_state.checks{struct=Struct}"), 2);
+	assert_eq!(recorder.value("check_initial_state.violations{struct=Struct,field=option}"), 0);
+}
+
+/// Checks that each violation is counted per field.
+#[test]
+fn violations_counted() {
+	let recorder = TestRecorder::default();
+	metrics::with_local_recorder(&recorder, || {
+		let _ = Struct { option: Some(1), dirty: true }.try_check_initial_state();
+		let result =
+			panic::catch_unwind(|| Struct { option: Some(1), dirty: false }.check_initial_state());
+		assert!(result.is_err());
+	});
+	assert_eq!(recorder.value("check_initial_state.checks{struct=Struct}"), 2);
+	assert_eq!(recorder.value("check_initial_state.violations{struct=Struct,field=option}"), 2);
+	assert_eq!(recorder.value("check_initial_state.violations{struct=Struct,field=dirty}"), 1);
+}
+
+#[derive(CheckInitialState)]
+struct Struct {
+	option: Option<i32>,
+	#[expect_false]
+	dirty: bool,
+}
+
+/// Records counters keyed by their names and labels, e.g.
+/// `name{label=value}`.
+#[derive(Default)]
+struct TestRecorder {
+	counters: Mutex<HashMap<String, Arc<AtomicU64>>>,
+}
+
+impl TestRecorder {
+	/// Value of the counter with `key`, or `0` if it is not registered.
+	fn value(&self, key: &str) -> u64 {
+		let counters = self.counters.lock().unwrap();
+		counters.get(key).map_or(0, |counter| counter.load(Ordering::SeqCst))
+	}
+}
+
+impl Recorder for TestRecorder {
+	fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+	fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+	fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+	fn register_counter(&self, key: &Key, _: &Metadata<'_>) -> Counter {
+		let labels = key.labels()
+			.map(|label| format!("{}={}", label.key(), label.value()))
+			.collect::<Vec<_>>();
+		let key = format!("{}{{{}}}", key.name(), labels.join(","));
+		let mut counters = self.counters.lock().unwrap();
+		Counter::from_arc(counters.entry(key).or_default().clone())
+	}
+
+	fn register_gauge(&self, _: &Key, _: &Metadata<'_>) -> Gauge {
+		Gauge::noop()
+	}
+
+	fn register_histogram(&self, _: &Key, _: &Metadata<'_>) -> Histogram {
+		Histogram::noop()
+	}
+}
@@ -0,0 +1,37 @@
+use check_initial_state_derive::CheckInitialState;
+use std::panic::{self, Location};
+use std::sync::Mutex;
+
+/// Location of the last panic, recorded by the panic hook.
+static PANIC_LOCATION: Mutex<Option<(String, u32)>> = Mutex::new(None);
+
+/// Checks that the panic points to the caller of `check_initial_state()` by
+/// default, and to the generated code when `msrv` predates `#[track_caller]`.
+#[test]
+fn panic_location() {
+	panic::set_hook(Box::new(|info| {
+		let location = info.location().unwrap();
+		*PANIC_LOCATION.lock().unwrap() = Some((location.file().to_owned(), location.line()));
+	}));
+	let caller = Location::caller();
+	let result = panic::catch_unwind(|| Recent { option: Some(1) }.check_initial_state());
+	let recent_location = PANIC_LOCATION.lock().unwrap().take();
+	let result_old = panic::catch_unwind(|| Old { option: Some(1) }.check_initial_state());
+	let old_location = PANIC_LOCATION.lock().unwrap().take();
+	let _ = panic::take_hook();
+	assert!(result.is_err());
+	assert!(result_old.is_err());
+	assert_eq!(recent_location, Some((file!().to_owned(), caller.line() + 1)));
+	assert_ne!(old_location, Some((file!().to_owned(), caller.line() + 3)));
+}
+
+#[derive(CheckInitialState)]
+struct Recent {
+	option: Option<i32>,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(msrv = "1.40")]
+struct Old {
+	option: Option<i32>,
+}
@@ -0,0 +1,37 @@
+#![cfg(feature = "no-panic")]
+
+use check_initial_state::Violation;
+use check_initial_state_derive::CheckInitialState;
+
+/// Checks that an instance in initial state passes.
+#[test]
+fn initial_state() {
+	assert_eq!(Builder::default().check_initial_state_no_panic(), Ok(()));
+}
+
+/// Checks that the first field which is not in initial state is returned.
+#[test]
+fn first_violation_returned() {
+	let builder = Builder { option: Some(1), enabled: true, count: 0, _ignored: Some(1) };
+	assert_eq!(
+		builder.check_initial_state_no_panic(),
+		Err(Violation::new("option", "has Some value instead of None")),
+	);
+	let builder = Builder { count: 2, _ignored: Some(1), ..Builder::default() };
+	assert_eq!(
+		builder.check_initial_state_no_panic(),
+		Err(Violation::new("count", "is non-zero instead of zero")),
+	);
+}
+
+#[derive(CheckInitialState, Default)]
+#[check_initial_state(no_panic)]
+struct Builder {
+	option: Option<i32>,
+	#[expect_false]
+	enabled: bool,
+	#[expect_zero]
+	count: u32,
+	#[ignore_field]
+	_ignored: Option<i32>,
+}
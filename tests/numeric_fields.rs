@@ -0,0 +1,37 @@
+use check_initial_state_derive::CheckInitialState;
+
+/// Checks numeric fields which are zero.
+#[test]
+fn numeric_fields_in_initial_state() {
+	Struct { option: None, retries: 0, total: 0.0 }.check_initial_state();
+}
+
+/// Checks the case when an integer field is non-zero.
+#[test]
+#[should_panic(expected = "Field `retries` is non-zero instead of zero")]
+fn integer_field_not_zero() {
+	Struct { option: None, retries: 3, total: 0.0 }.check_initial_state();
+}
+
+/// Checks the case when a float field is non-zero.
+#[test]
+#[should_panic(expected = "Field `total` is non-zero instead of zero")]
+fn float_field_not_zero() {
+	Struct { option: None, retries: 0, total: 0.5 }.check_initial_state();
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(constructor = "new", mutation_test, initial_const)]
+struct Struct {
+	option: Option<i32>,
+	#[expect_zero]
+	retries: u32,
+	#[expect_zero]
+	total: f64,
+}
+
+impl Struct {
+	fn new() -> Self {
+		Self::INITIAL
+	}
+}
@@ -0,0 +1,86 @@
+use check_initial_state_derive::CheckInitialState;
+
+/// Checks that nothing panics for a struct in initial state.
+#[test]
+fn option_alias_none() {
+	Builder::new().check_initial_state();
+}
+
+/// Checks that an `Option`-like field is reported like an `Option` field.
+#[test]
+#[should_panic(expected = "Field `id` has Some value instead of None")]
+fn option_alias_some() {
+	Builder { id: MaybeId(Some(1)), token: Token::default(), _raw: None }.check_initial_state();
+}
+
+/// Checks that all generated reports take `option_alias` fields into account.
+#[test]
+fn option_alias_reported() {
+	let builder = Builder { id: MaybeId(Some(1)), token: Token { set: true }, _raw: None };
+	let mut report = Vec::new();
+	builder.write_initial_state_report(&mut report).unwrap();
+	assert_eq!(
+		String::from_utf8(report).unwrap(),
+		"Field `id` has Some value instead of None\nField `token` has Some value instead of None\n",
+	);
+	assert_eq!(builder.initial_state_errors(), 0b11);
+}
+
+/// Checks that `option_alias` fields are compared with a reference instance.
+#[test]
+#[should_panic(expected = "`id`: Some => None")]
+fn option_alias_compared() {
+	Compared { id: MaybeId(None) }.check_initial_state();
+}
+
+/// Checks that `option_alias` is accepted by `check_initial_state_for!`.
+#[test]
+#[should_panic(expected = "Field `token` has Some value instead of None")]
+fn option_alias_check_for() {
+	let builder = Builder { id: MaybeId(None), token: Token { set: true }, _raw: None };
+	check_initial_state::check_initial_state_for!(builder, Builder { #[option_alias] token });
+}
+
+/// Newtype behaving like `Option`.
+struct MaybeId(Option<u64>);
+
+impl MaybeId {
+	fn is_some(&self) -> bool {
+		self.0.is_some()
+	}
+}
+
+/// Type exposing `is_some()` without wrapping `Option`.
+#[derive(Default)]
+struct Token {
+	set: bool,
+}
+
+impl Token {
+	fn is_some(&self) -> bool {
+		self.set
+	}
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(constructor = "Self::new", mutation_test, error_bits)]
+struct Builder {
+	#[option_alias]
+	id: MaybeId,
+	#[option_alias]
+	token: Token,
+	_raw: Option<u8>,
+}
+
+impl Builder {
+	fn new() -> Self {
+		Self { id: MaybeId(None), token: Token::default(), _raw: None }
+	}
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(compare_with = "Self { id: MaybeId(Some(0)) }")]
+struct Compared {
+	#[option_alias]
+	id: MaybeId,
+}
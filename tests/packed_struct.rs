@@ -0,0 +1,99 @@
+use check_initial_state_derive::CheckInitialState;
+
+/// Checks a packed struct which fields are in initial state.
+#[test]
+fn packed_struct_in_initial_state() {
+	Packet::new().check_initial_state();
+}
+
+/// Checks the case when an `Option` field of a packed struct is `Some`.
+#[test]
+#[should_panic(expected = "Field `checksum` has Some value instead of None")]
+fn packed_option_field_is_some() {
+	Packet { checksum: Some(10), ..Packet::new() }.check_initial_state();
+}
+
+/// Checks the case when a field of a packed struct doesn't match its pattern.
+#[test]
+#[should_panic(expected = "Field `kind` doesn't match `0 | 1`")]
+fn packed_field_does_not_match() {
+	Packet { kind: 2, ..Packet::new() }.check_initial_state();
+}
+
+/// Checks that all violations of a packed struct are reported.
+#[test]
+fn packed_struct_report() {
+	let mut report = Vec::new();
+	Packet { checksum: Some(10), length: 10, ..Packet::new() }
+		.write_initial_state_report(&mut report)
+		.unwrap();
+	assert_eq!(
+		String::from_utf8(report).unwrap(),
+		"Field `checksum` has Some value instead of None\nField `length` is non-zero instead of \
+			zero\n",
+	);
+}
+
+/// Checks the case when an `Option` field of a packed struct in `lenient` mode
+/// is `Some`.
+#[test]
+#[should_panic(expected = "Field `sequence` has Some value instead of None")]
+fn packed_lenient_option_field_is_some() {
+	Header { sequence: Some(1), version: 1 }.check_initial_state();
+}
+
+/// Checks the case when a field of a packed struct differs from the one of the
+/// reference instance.
+#[test]
+#[should_panic(expected = "Field `sequence` has None value instead of Some")]
+fn packed_field_differs_from_reference() {
+	Frame { sequence: None }.check_initial_state();
+}
+
+#[derive(Clone, Copy, CheckInitialState)]
+#[check_initial_state(constructor = "new", mutation_test)]
+#[repr(C, packed)]
+struct Packet {
+	checksum: Option<u32>,
+	#[expect_zero]
+	length: u16,
+	#[expect_true]
+	valid: bool,
+	#[expect_matches(0 | 1)]
+	kind: u8,
+	#[ignore_field]
+	id: u64,
+}
+
+impl Packet {
+	fn new() -> Self {
+		Self { checksum: None, length: 0, valid: true, kind: 0, id: 7 }
+	}
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(lenient, constructor = "new", mutation_test)]
+#[repr(C, packed(2))]
+struct Header {
+	sequence: Option<u64>,
+	version: u8,
+}
+
+impl Header {
+	fn new() -> Self {
+		Self { sequence: None, version: 1 }
+	}
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(compare_with = "Self::new")]
+#[repr(C, packed)]
+struct Frame {
+	sequence: Option<u64>,
+}
+
+impl Frame {
+	fn new() -> Self {
+		Self { sequence: Some(0) }
+	}
+}
@@ -0,0 +1,62 @@
+use check_initial_state::InitialStatePanic;
+use check_initial_state_derive::CheckInitialState;
+use std::panic::{self, UnwindSafe};
+
+/// Checks that the payload names the struct and the first violating field.
+#[test]
+fn payload_names_violating_field() {
+	let payload = catch_payload(|| Builder { option: None, enabled: true }.check_initial_state());
+	assert_eq!(payload.struct_name(), "Builder");
+	assert_eq!(payload.violation().field(), "enabled");
+	assert_eq!(payload.violation().reason(), "is true instead of false");
+}
+
+/// Checks that nothing panics for an instance in initial state.
+#[test]
+fn initial_state_not_panics() {
+	Builder { option: None, enabled: false }.check_initial_state();
+}
+
+/// Checks that the payload describes the first violation in comparison mode.
+#[test]
+fn payload_in_compare_with_mode() {
+	let payload = catch_payload(|| Compared { option: None }.check_initial_state());
+	assert_eq!(payload.struct_name(), "Compared");
+	assert_eq!(payload.violation().field(), "option");
+}
+
+/// Checks that the payload is displayed like the panic message.
+#[test]
+fn payload_displayed() {
+	let payload = catch_payload(|| Builder { option: Some(1), enabled: false }.check_initial_state());
+	assert_eq!(
+		payload.to_string(),
+		"`Builder` is not in initial state: Field `option` has Some value instead of None",
+	);
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(panic_payload, constructor = "Self::new", mutation_test)]
+struct Builder {
+	option: Option<i32>,
+	#[expect_false]
+	enabled: bool,
+}
+
+impl Builder {
+	fn new() -> Self {
+		Self { option: None, enabled: false }
+	}
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(panic_payload, compare_with = "Self { option: Some(1) }")]
+struct Compared {
+	option: Option<i32>,
+}
+
+/// Runs `check`, expecting it to panic with `InitialStatePanic` payload.
+fn catch_payload(check: impl FnOnce() + UnwindSafe) -> InitialStatePanic {
+	let payload = panic::catch_unwind(check).unwrap_err();
+	payload.downcast_ref::<InitialStatePanic>().unwrap().clone()
+}
@@ -0,0 +1,86 @@
+#![cfg(feature = "item-macros")]
+
+use check_initial_state::MissingFieldsError;
+use check_initial_state_derive::{post_build_check, CheckInitialState};
+
+/// Checks that a completely populated builder is built.
+#[test]
+fn all_fields_set() {
+	let builder = ServerBuilder { host: Some("localhost".to_owned()), port: Some(80), retries: 3 };
+	assert_eq!(builder.build(), Server { host: "localhost".to_owned(), port: 80, retries: 3 });
+}
+
+/// Checks that the panic lists all unset fields.
+#[test]
+#[should_panic(expected = "ServerBuilder` is not completely built, unset fields: `host`, `Port`")]
+fn unset_fields_panic() {
+	ServerBuilder::default().build();
+}
+
+/// Checks that unset fields are returned as the error of the method.
+#[test]
+fn unset_fields_returned() {
+	let builder = ServerBuilder { host: Some("localhost".to_owned()), ..ServerBuilder::default() };
+	let error = builder.try_build().unwrap_err();
+	assert_eq!(error.fields(), ["Port"]);
+	assert!(error.builder().ends_with("ServerBuilder"));
+}
+
+/// Checks that the error is converted into the error type of the method.
+#[test]
+fn error_converted() {
+	let mut builder = ServerBuilder::default();
+	assert_eq!(builder.build_by_ref(), Err(BuildError::Missing(vec!["host", "Port"])));
+	builder.host = Some("localhost".to_owned());
+	builder.port = Some(80);
+	assert_eq!(
+		builder.build_by_ref(),
+		Ok(Server { host: "localhost".to_owned(), port: 80, retries: 0 }),
+	);
+}
+
+#[derive(CheckInitialState, Default)]
+struct ServerBuilder {
+	host: Option<String>,
+	#[report_name = "Port"]
+	port: Option<u16>,
+	// Is optional
+	#[ignore_field]
+	retries: u8,
+}
+
+impl ServerBuilder {
+	#[post_build_check]
+	fn build(self) -> Server {
+		Server { host: self.host.unwrap(), port: self.port.unwrap(), retries: self.retries }
+	}
+
+	#[post_build_check(map_err)]
+	fn try_build(self) -> Result<Server, MissingFieldsError> {
+		Ok(Server { host: self.host.unwrap(), port: self.port.unwrap(), retries: self.retries })
+	}
+
+	#[post_build_check(map_err)]
+	fn build_by_ref(&self) -> Result<Server, BuildError> {
+		let host = self.host.clone().unwrap();
+		Ok(Server { host, port: self.port.unwrap(), retries: self.retries })
+	}
+}
+
+#[derive(Debug, PartialEq)]
+struct Server {
+	host: String,
+	port: u16,
+	retries: u8,
+}
+
+#[derive(Debug, PartialEq)]
+enum BuildError {
+	Missing(Vec<&'static str>),
+}
+
+impl From<MissingFieldsError> for BuildError {
+	fn from(error: MissingFieldsError) -> Self {
+		Self::Missing(error.fields().to_vec())
+	}
+}
@@ -0,0 +1,108 @@
+use check_initial_state::InitialStatePanic;
+use check_initial_state_derive::CheckInitialState;
+use std::panic;
+
+/// Ensures that `Option` fields of associated type projections are checked
+/// without bounds on the projected types, e.g. `Debug` or `Default`.
+#[test]
+fn projection_fields_checked() {
+	Builder::<Opaque>::new().check_initial_state();
+	let builder = Builder::<Opaque> { output: None, limit: Some(1), _items: None };
+	assert_eq!(builder.initial_state_errors(), 0b10);
+	assert_eq!(builder.violations().collect::<Vec<_>>(), ["limit"]);
+}
+
+/// Ensures that the panic message names the field of a projected type.
+#[test]
+#[should_panic(expected = "Field `output` has Some value instead of None")]
+fn projection_field_reported() {
+	Builder::<Opaque> { output: Some(Token), limit: None, _items: None }.check_initial_state();
+}
+
+/// Ensures that projected types are rendered as they are written.
+#[test]
+fn projection_types_rendered() {
+	assert_eq!(
+		Builder::<Opaque>::FIELD_TYPES,
+		["Option<<T as Config>::Output>", "Option<T::Limit>"],
+	);
+	assert_eq!(
+		Nested::<u8>::FIELD_TYPES,
+		["Option<<Vec<T> as IntoIterator>::Item>", "Option<for<'a> fn(&'a T)>"],
+	);
+}
+
+/// Ensures that a reference instance is compared field by field for a struct
+/// with projections.
+#[test]
+fn projection_fields_compared() {
+	let payload = panic::catch_unwind(|| {
+		Compared::<Opaque> { output: Some(Token) }.check_initial_state();
+	})
+		.unwrap_err();
+	assert_eq!(payload.downcast_ref::<InitialStatePanic>().unwrap().violation().field(), "output");
+}
+
+/// Ensures that the initial constant is generated for fields of projected
+/// types.
+#[test]
+fn projection_initial_const() {
+	Initial::<Opaque>::INITIAL.check_initial_state();
+}
+
+/// Configuration of a builder, which defines the types of its fields.
+trait Config {
+	type Output;
+	type Limit;
+}
+
+/// Configuration, which types implement no traits except the required ones.
+struct Opaque;
+
+/// Output, which implements `Default` for mutation tests only.
+#[derive(Default)]
+struct Token;
+
+impl Config for Opaque {
+	type Output = Token;
+	type Limit = u8;
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(constructor = "Builder::<Opaque>::new", mutation_test)]
+#[check_initial_state(error_bits, violations_iter)]
+struct Builder<T: Config>
+where
+	<T as Config>::Output: Sized,
+{
+	output: Option<<T as Config>::Output>,
+	limit: Option<T::Limit>,
+	#[ignore_field]
+	_items: Option<Vec<<T as Config>::Output>>,
+}
+
+impl Builder<Opaque> {
+	fn new() -> Self {
+		Self { output: None, limit: None, _items: Some(Vec::new()) }
+	}
+}
+
+#[derive(CheckInitialState)]
+struct Nested<T> {
+	_item: Option<<Vec<T> as IntoIterator>::Item>,
+	_callback: Option<for<'a> fn(&'a T)>,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(compare_with = "Self { output: None }", panic_payload)]
+struct Compared<T: Config> {
+	output: Option<<T as Config>::Output>,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(initial_const)]
+struct Initial<T: Config> {
+	_output: Option<<T as Config>::Output>,
+	#[expect_false]
+	_enabled: bool,
+}
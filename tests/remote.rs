@@ -0,0 +1,50 @@
+use check_initial_state::InitialStateError;
+use check_initial_state_derive::CheckInitialState;
+
+/// Checks fields of a foreign struct which are in initial state.
+#[test]
+fn remote_in_initial_state() {
+	let builder = foreign::Builder { option: None, enabled: false, retries: 7 };
+	BuilderDef::check_initial_state(&builder);
+}
+
+/// Checks the case when a mirrored field of a foreign struct is not in
+/// initial state.
+#[test]
+#[should_panic(expected = "Field `enabled` is true instead of false")]
+fn remote_field_not_in_initial_state() {
+	let builder = foreign::Builder { option: None, enabled: true, retries: 7 };
+	BuilderDef::check_initial_state(&builder);
+}
+
+/// Checks that all violations of a foreign struct are reported.
+#[test]
+fn remote_violations_reported() {
+	let builder = foreign::Builder { option: Some(1), enabled: true, retries: 7 };
+	let error: InitialStateError = BuilderDef::try_check_initial_state(&builder).unwrap_err();
+	assert_eq!(error.struct_name(), "Builder");
+	let fields = error.violations().iter().map(|violation| violation.field()).collect::<Vec<_>>();
+	assert_eq!(fields, ["option", "enabled"]);
+}
+
+/// Stands for a third-party crate, whose types cannot be annotated with the
+/// derive.
+mod foreign {
+	#[allow(dead_code)]
+	pub struct Builder {
+		pub option: Option<i32>,
+		pub enabled: bool,
+		pub retries: u32,
+	}
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(remote = "foreign::Builder")]
+#[allow(dead_code)]
+struct BuilderDef {
+	option: Option<i32>,
+	#[expect_false]
+	enabled: bool,
+	#[ignore_field]
+	retries: u32,
+}
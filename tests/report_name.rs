@@ -0,0 +1,53 @@
+use check_initial_state::CheckInitialState;
+use check_initial_state_derive::check_initial_state_for;
+
+/// Checks that the panic message names the field by its report name.
+#[test]
+#[should_panic(expected = "Field `Override timestamp` has Some value instead of None")]
+fn panic_message_uses_report_name() {
+	Builder { cfg_ovr_ts: Some(1), flag: false }.check_initial_state();
+}
+
+/// Checks that violations and reports name the field by its report name.
+#[test]
+fn reports_use_report_name() {
+	let builder = Builder { cfg_ovr_ts: Some(1), flag: true };
+	let error = builder.try_check_initial_state().unwrap_err();
+	let fields = error.violations().iter().map(|violation| violation.field()).collect::<Vec<_>>();
+	assert_eq!(fields, ["Override timestamp", "flag"]);
+	let mut report = Vec::new();
+	builder.write_initial_state_report(&mut report).unwrap();
+	assert!(String::from_utf8(report).unwrap().starts_with("Field `Override timestamp` "));
+}
+
+/// Checks that introspection keeps the names as they are written.
+#[test]
+fn field_names_kept() {
+	assert_eq!(Builder::FIELD_NAMES, ["cfg_ovr_ts", "flag"]);
+}
+
+/// Checks that `check_initial_state_for!` names the field by its report name.
+#[test]
+#[should_panic(expected = "Field `Override timestamp` has Some value instead of None")]
+fn check_for_uses_report_name() {
+	let builder = Builder { cfg_ovr_ts: Some(1), flag: false };
+	check_initial_state_for!(builder, Builder {
+		#[report_name = "Override timestamp"]
+		cfg_ovr_ts,
+	});
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(constructor = "Self::new", mutation_test)]
+struct Builder {
+	#[report_name = "Override timestamp"]
+	cfg_ovr_ts: Option<u64>,
+	#[expect_false]
+	flag: bool,
+}
+
+impl Builder {
+	fn new() -> Self {
+		Self { cfg_ovr_ts: None, flag: false }
+	}
+}
@@ -0,0 +1,55 @@
+#![cfg(feature = "rstest")]
+
+use check_initial_state_derive::CheckInitialState;
+use rstest::rstest;
+
+/// Checks that the generated fixture provides an instance in initial state.
+#[rstest]
+fn fixture_provides_instance(http_builder: HttpBuilder) {
+	assert_eq!(http_builder.retries, 3);
+}
+
+/// Checks that the generated fixture is combined with test parameters.
+#[rstest]
+#[case(1)]
+#[case(2)]
+fn fixture_with_cases(mut http_builder: HttpBuilder, #[case] timeout: u32) {
+	http_builder.timeout = Some(timeout);
+	assert_eq!(http_builder.timeout, Some(timeout));
+}
+
+/// Checks that a generic fixture keeps where clauses referring to `Self`.
+#[rstest]
+fn generic_fixture(buffer: Buffer<'static>) {
+	assert!(buffer.content.is_none());
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(constructor = "new", fixture)]
+struct HttpBuilder {
+	timeout: Option<u32>,
+	#[ignore_field]
+	retries: u32,
+}
+
+impl HttpBuilder {
+	fn new() -> Self {
+		Self { timeout: None, retries: 3 }
+	}
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(constructor = "Self::new", fixture)]
+struct Buffer<'a>
+where
+	Self: Send,
+	Option<Self>: Sized,
+{
+	content: Option<&'a str>,
+}
+
+impl Buffer<'_> {
+	fn new() -> Self {
+		Self { content: None }
+	}
+}
@@ -0,0 +1,52 @@
+use check_initial_state_derive::CheckInitialState;
+
+/// Checks that instances with the same fields set, but different values, are
+/// in the same state.
+#[test]
+fn values_ignored() {
+	let left = Builder { option: Some(1), enabled: true, count: 1, _ignored: None };
+	let right = Builder { option: Some(2), enabled: true, count: 5, _ignored: Some(1) };
+	left.same_state_as(&right);
+	Builder::default().same_state_as(&Builder::default());
+}
+
+/// Checks that a field set in `self` only is reported.
+#[test]
+#[should_panic(expected = "Field `option` is Some in this instance, but None in the other one")]
+fn set_in_self() {
+	Builder { option: Some(1), ..Builder::default() }.same_state_as(&Builder::default());
+}
+
+/// Checks that a field set in `other` only is reported with its state labels.
+#[test]
+#[should_panic(expected = "Field `count` is 0 in this instance, but non-zero in the other one")]
+fn set_in_other() {
+	Builder::default().same_state_as(&Builder { count: 3, ..Builder::default() });
+}
+
+/// Checks that the first differing field is reported.
+#[test]
+#[should_panic(expected = "Field `enabled` is false in this instance, but true in the other one")]
+fn first_difference_reported() {
+	let other = Builder { option: Some(1), enabled: true, count: 1, _ignored: None };
+	Builder { option: Some(2), ..Builder::default() }.same_state_as(&other);
+}
+
+/// Checks that a reset leaves the builder in the state of a new one.
+#[test]
+fn reset_compared() {
+	let mut builder = Builder { option: Some(1), enabled: true, count: 1, _ignored: None };
+	builder = Builder { _ignored: builder._ignored, ..Builder::default() };
+	builder.same_state_as(&Builder::default());
+}
+
+#[derive(CheckInitialState, Default)]
+struct Builder {
+	option: Option<i32>,
+	#[expect_false]
+	enabled: bool,
+	#[expect_zero]
+	count: u32,
+	#[ignore_field]
+	_ignored: Option<i32>,
+}
@@ -0,0 +1,60 @@
+#![deny(deprecated)]
+
+use check_initial_state_derive::CheckInitialState;
+use serde::Deserialize;
+
+/// Checks that fields skipped or defaulted by `serde` are ignored.
+#[test]
+fn serde_skipped_and_defaulted_fields_ignored() {
+	Config { timeout: None, cache: Some(1), retries: 3, name: None, port: 0 }
+		.check_initial_state();
+}
+
+/// Checks that a defaulted `Option` field is still checked.
+#[test]
+#[should_panic(expected = "Field `name` has Some value instead of None")]
+fn serde_defaulted_option_checked() {
+	Config { timeout: None, cache: None, retries: 0, name: Some("name".to_owned()), port: 0 }
+		.check_initial_state();
+}
+
+/// Checks that an explicit check attribute takes precedence over
+/// `serde` attributes.
+#[test]
+#[should_panic(expected = "Field `port` is non-zero instead of zero")]
+fn explicit_annotation_takes_precedence() {
+	Config { timeout: None, cache: None, retries: 0, name: None, port: 80 }
+		.check_initial_state();
+}
+
+/// Checks that all non-`Option` fields are ignored if the struct is
+/// defaulted by `serde`.
+#[test]
+fn serde_defaulted_struct() {
+	Defaulted { option: None, retries: 3 }.check_initial_state();
+}
+
+#[allow(dead_code)]
+#[derive(CheckInitialState, Deserialize)]
+#[check_initial_state(serde, warn_ignored_option)]
+struct Config {
+	timeout: Option<u32>,
+	#[serde(skip)]
+	cache: Option<u32>,
+	#[serde(default, rename = "retry_count")]
+	retries: u32,
+	#[serde(default)]
+	name: Option<String>,
+	#[serde(default)]
+	#[expect_zero]
+	port: u16,
+}
+
+#[allow(dead_code)]
+#[derive(CheckInitialState, Default, Deserialize)]
+#[check_initial_state(serde)]
+#[serde(default)]
+struct Defaulted {
+	option: Option<u32>,
+	retries: u32,
+}
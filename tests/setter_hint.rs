@@ -0,0 +1,88 @@
+use check_initial_state_derive::CheckInitialState;
+
+/// Checks that the setter named by the convention is suggested for a stray
+/// value.
+#[test]
+#[should_panic(expected = "Field `endpoint` has Some value instead of None, was it set via \
+	`set_endpoint(...)`?")]
+fn setter_suggested() {
+	Hinted { endpoint: Some(1), ..Hinted::default() }.check_initial_state();
+}
+
+/// Checks that the raw prefix of a field name is dropped in the suggestion.
+#[test]
+#[should_panic(expected = "Field `r#type` is true instead of false, was it set via `set_type(...)`?")]
+fn raw_field_suggested() {
+	Hinted { r#type: true, ..Hinted::default() }.check_initial_state();
+}
+
+/// Checks that nothing is suggested for a field which is expected to be set.
+#[test]
+#[should_panic(expected = "Field `ready` is false instead of true")]
+fn missing_value_not_suggested() {
+	let result = std::panic::catch_unwind(|| {
+		Hinted { ready: false, ..Hinted::default() }.check_initial_state();
+	});
+	let payload = result.unwrap_err();
+	let message = payload.downcast_ref::<String>().unwrap();
+	assert!(!message.contains("was it set via"), "{}", message);
+	std::panic::resume_unwind(payload);
+}
+
+/// Checks that the convention of `setter` option is suggested by default.
+#[test]
+#[should_panic(expected = "Field `option` has Some value instead of None, was it set via \
+	`with_option(...)`?")]
+fn setter_option_suggested() {
+	let mut builder = Mutated::new();
+	builder.with_option(1);
+	builder.check_initial_state();
+}
+
+/// Checks that nothing is suggested without a convention.
+#[test]
+#[should_panic(expected = "Field `option` has Some value instead of None")]
+fn nothing_suggested_by_default() {
+	let result = std::panic::catch_unwind(|| Plain { option: Some(1) }.check_initial_state());
+	let payload = result.unwrap_err();
+	let message = payload.downcast_ref::<String>().unwrap();
+	assert!(!message.contains("was it set via"), "{}", message);
+	std::panic::resume_unwind(payload);
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(setter_hint = "set_{}")]
+struct Hinted {
+	endpoint: Option<u32>,
+	#[expect_false]
+	r#type: bool,
+	#[expect_true]
+	ready: bool,
+}
+
+impl Default for Hinted {
+	fn default() -> Self {
+		Self { endpoint: None, r#type: false, ready: true }
+	}
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(constructor = "new", mutation_test, setter = "with_{}")]
+struct Mutated {
+	option: Option<i32>,
+}
+
+impl Mutated {
+	fn new() -> Self {
+		Self { option: None }
+	}
+
+	fn with_option(&mut self, value: i32) {
+		self.option = Some(value);
+	}
+}
+
+#[derive(CheckInitialState)]
+struct Plain {
+	option: Option<i32>,
+}
@@ -0,0 +1,42 @@
+// Standard macros are shadowed, so any unqualified call in the generated code
+// fails to compile
+#[allow(unused_macros)]
+macro_rules! panic {
+	($($tokens:tt)*) => {
+		compile_error!("unqualified `panic!` in generated code")
+	};
+}
+
+#[allow(unused_macros)]
+macro_rules! assert {
+	($($tokens:tt)*) => {
+		compile_error!("unqualified `assert!` in generated code")
+	};
+}
+
+use check_initial_state::assert_initial_state;
+use check_initial_state_derive::{check_initial_state_for, CheckInitialState};
+
+/// Checks that the generated code compiles while standard macros are shadowed,
+/// and panics as usual.
+#[test]
+#[should_panic(expected = "Field `option` has Some value instead of None")]
+fn shadowed_macros_not_used() {
+	assert_initial_state!(Builder::new());
+	let builder = Builder::new();
+	check_initial_state_for!(builder, Builder { option, other });
+	Builder { option: Some(1), other: None }.check_initial_state();
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(constructor = "Self::new", mutation_test, setter_hint = "with_{}")]
+struct Builder {
+	option: Option<i32>,
+	other: Option<String>,
+}
+
+impl Builder {
+	fn new() -> Self {
+		Self { option: None, other: None }
+	}
+}
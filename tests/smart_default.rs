@@ -0,0 +1,72 @@
+use check_initial_state_derive::CheckInitialState;
+use smart_default::SmartDefault;
+
+/// Checks that fields equal to their `smart-default` defaults pass the check.
+#[test]
+fn defaults_passed() {
+	Builder::default().check_initial_state();
+}
+
+/// Checks that a field differing from its numeric default is reported.
+#[test]
+#[should_panic(expected = "Field `retries` doesn't equal `3`")]
+fn numeric_default_violation() {
+	Builder { retries: 5, ..Builder::default() }.check_initial_state();
+}
+
+/// Checks that a string literal default is converted like `smart-default` does.
+#[test]
+#[should_panic(expected = "Field `name` doesn't equal `\"builder\"`")]
+fn string_default_violation() {
+	Builder { name: String::from("other"), ..Builder::default() }.check_initial_state();
+}
+
+/// Checks that a default written as code is compared.
+#[test]
+#[should_panic(expected = "Field `items` doesn't equal `vec![1, 2]`")]
+fn code_default_violation() {
+	Builder { items: Vec::new(), ..Builder::default() }.check_initial_state();
+}
+
+/// Checks that an `Option` field with `Some` default is expected to equal it.
+#[test]
+#[should_panic(expected = "Field `timeout` doesn't equal `Some(10)`")]
+fn some_default_violation() {
+	Builder { timeout: None, ..Builder::default() }.check_initial_state();
+}
+
+/// Checks that `None` default keeps the usual check.
+#[test]
+#[should_panic(expected = "Field `token` has Some value instead of None")]
+fn none_default_checked() {
+	Builder { token: Some(1), ..Builder::default() }.check_initial_state();
+}
+
+/// Checks that a check attribute takes precedence over the default.
+#[test]
+#[should_panic(expected = "Field `count` is non-zero instead of zero")]
+fn check_attribute_overrides_default() {
+	Overridden { count: 0 }.check_initial_state();
+	Overridden::default().check_initial_state();
+}
+
+#[derive(CheckInitialState, SmartDefault)]
+struct Builder {
+	#[default(3)]
+	retries: u32,
+	#[default = "builder"]
+	name: String,
+	#[default(_code = "vec![1, 2]")]
+	items: Vec<u8>,
+	#[default(Some(10))]
+	timeout: Option<u32>,
+	#[default(None)]
+	token: Option<i32>,
+}
+
+#[derive(CheckInitialState, SmartDefault)]
+struct Overridden {
+	#[default(5)]
+	#[expect_zero]
+	count: u32,
+}
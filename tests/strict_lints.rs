@@ -0,0 +1,22 @@
+#![deny(dead_code, clippy::panic)]
+
+use check_initial_state_derive::CheckInitialState;
+
+/// Checks that the generated code doesn't trigger lints, which a crate with a
+/// strict lint policy may deny, while its methods are left unused.
+#[test]
+fn strict_lints() {
+	Struct { option: None }.check_initial_state();
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(constructor = "Self::new", mutation_test)]
+struct Struct {
+	option: Option<i32>,
+}
+
+impl Struct {
+	fn new() -> Self {
+		Self { option: None }
+	}
+}
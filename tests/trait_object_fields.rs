@@ -0,0 +1,94 @@
+use check_initial_state::CheckInitialState;
+use std::fmt::Debug;
+use std::rc::Rc;
+
+/// Checks that nothing panics for trait object fields in initial state.
+#[test]
+fn initial_state() {
+	Handlers::default().check_initial_state();
+	Borrowed::<dyn Handler> { handler: None, debug: None }.check_initial_state();
+}
+
+/// Checks that a boxed trait object field is checked.
+#[test]
+#[should_panic(expected = "Field `handler` has Some value instead of None")]
+fn boxed_violation() {
+	Handlers { handler: Some(Box::new(Noop)), ..Handlers::default() }.check_initial_state();
+}
+
+/// Checks that a trait object field, which is neither `Send` nor `Sync`, is
+/// checked by the fallible check.
+#[test]
+fn shared_violation() {
+	let handlers = Handlers { shared: Some(Rc::new(Noop)), ..Handlers::default() };
+	let error = handlers.try_check_initial_state().unwrap_err();
+	assert_eq!(error.violations()[0].field(), "shared");
+	assert_eq!(handlers.violations().collect::<Vec<_>>(), ["shared"]);
+	handlers.same_state_as(&Handlers { shared: Some(Rc::new(Noop)), ..Handlers::default() });
+}
+
+/// Checks that a struct generic over an unsized type isn't bounded by `Sized`.
+#[test]
+#[should_panic(expected = "Field `handler` has Some value instead of None")]
+fn unsized_parameter_violation() {
+	let handler: &dyn Handler = &Noop;
+	Borrowed { handler: Some(handler), debug: None }.check_initial_state();
+}
+
+/// Checks that trait object types are rendered the way they are written.
+#[test]
+fn types_rendered() {
+	assert_eq!(
+		Handlers::FIELD_TYPES,
+		[
+			"Option<Box<dyn Handler>>",
+			"Option<Box<dyn Handler + Send + Sync + 'static>>",
+			"Option<Rc<dyn Handler>>",
+			"Option<Box<dyn for<'a> Fn(&'a str) -> &'a str>>",
+			"Option<Box<dyn Iterator<Item = u8>>>",
+			"Vec<Box<dyn Handler>>",
+		],
+	);
+	assert_eq!(Borrowed::<dyn Handler>::FIELD_TYPES, ["Option<&'a T>", "Option<&'a dyn Debug>"]);
+}
+
+/// Checks that trait objects not implementing `Debug` are rendered by their
+/// state.
+#[test]
+fn displayed() {
+	let handlers = Handlers { handler: Some(Box::new(Noop)), ..Handlers::default() };
+	assert!(handlers.to_string().contains("* handler  | Some"));
+}
+
+trait Handler {}
+
+struct Noop;
+
+impl Handler for Noop {}
+
+#[derive(CheckInitialState, Default)]
+#[check_initial_state(
+	violations_iter,
+	derive_display,
+	empty_via(Vec = is_empty),
+	constructor = "default",
+	mutation_test,
+)]
+// Types are spelled out, so their rendering is checked
+#[allow(clippy::type_complexity)]
+struct Handlers {
+	handler: Option<Box<dyn Handler>>,
+	threaded: Option<Box<dyn Handler + Send + Sync + 'static>>,
+	shared: Option<Rc<dyn Handler>>,
+	callback: Option<Box<dyn for<'a> Fn(&'a str) -> &'a str>>,
+	#[option_alias]
+	iterator: Option<Box<dyn Iterator<Item = u8>>>,
+	chain: Vec<Box<dyn Handler>>,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(violations_iter, initial_const)]
+struct Borrowed<'a, T: Handler + ?Sized> {
+	handler: Option<&'a T>,
+	debug: Option<&'a dyn Debug>,
+}
@@ -0,0 +1,85 @@
+use check_initial_state::{CheckInitialState, Violation};
+use std::marker::PhantomData;
+
+/// Checks that nothing panics for a newtype wrapping a builder in initial
+/// state.
+#[test]
+fn delegated_initial_state() {
+	Wrapper(Builder::default()).check_initial_state();
+	Named { builder: Builder::default(), _marker: PhantomData::<u8> }.check_initial_state();
+}
+
+/// Checks that the check of a tuple newtype reports fields of the wrapped
+/// builder.
+#[test]
+#[should_panic(expected = "Field `enabled` is true instead of false")]
+fn delegated_tuple_violation() {
+	Wrapper(Builder { option: None, enabled: true }).check_initial_state();
+}
+
+/// Checks that the check of a newtype with named fields is delegated, while
+/// `PhantomData` fields are skipped.
+#[test]
+#[should_panic(expected = "Field `option` has Some value instead of None")]
+fn delegated_named_violation() {
+	Named { builder: Builder { option: Some(1), enabled: false }, _marker: PhantomData::<u8> }
+		.check_initial_state();
+}
+
+/// Checks that all violations of the wrapped builder are reported.
+#[test]
+fn delegated_reports() {
+	let wrapper = Wrapper(Builder { option: Some(1), enabled: true });
+	let mut report = Vec::new();
+	wrapper.write_initial_state_report(&mut report).unwrap();
+	assert_eq!(
+		String::from_utf8(report).unwrap(),
+		"Field `option` has Some value instead of None\nField `enabled` is true instead of false\n",
+	);
+	let error = wrapper.try_check_initial_state().unwrap_err();
+	assert_eq!(error.struct_name(), "Builder");
+	assert_eq!(error.violations()[1], Violation::new("enabled", "is true instead of false"));
+}
+
+/// Checks that a generic newtype is bounded by the check of its wrapped type.
+#[test]
+#[should_panic(expected = "Field `option` has Some value instead of None")]
+fn delegated_generic() {
+	Generic(Builder { option: Some(1), enabled: false }).check_initial_state();
+}
+
+/// Checks that a transparent newtype wrapping an `Option` checks it as usual.
+#[test]
+#[should_panic(expected = "Field `option` has Some value instead of None")]
+fn transparent_option_checked() {
+	OptionWrapper { option: Some(1) }.check_initial_state();
+}
+
+#[derive(CheckInitialState, Default)]
+struct Builder {
+	option: Option<i32>,
+	#[expect_false]
+	enabled: bool,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(constructor = Self(Builder::default()))]
+#[repr(transparent)]
+struct Wrapper(Builder);
+
+#[derive(CheckInitialState)]
+#[repr(transparent)]
+struct Named<T> {
+	builder: Builder,
+	_marker: PhantomData<T>,
+}
+
+#[derive(CheckInitialState)]
+#[repr(transparent)]
+struct Generic<T>(T);
+
+#[derive(CheckInitialState)]
+#[repr(transparent)]
+struct OptionWrapper {
+	option: Option<i32>,
+}
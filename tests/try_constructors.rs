@@ -0,0 +1,81 @@
+use check_initial_state::Violation;
+use check_initial_state_derive::CheckInitialState;
+use std::cell::Cell;
+
+thread_local! {
+	/// Whether `Builder::dirty()` sets a field, so the generated test of the
+	/// constructor, which runs on another thread, passes.
+	static DIRTY: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Checks that the generated function returns the instance constructed in
+/// initial state.
+#[test]
+fn clean_instance_returned() {
+	let builder = Builder::try_new().unwrap();
+	assert_eq!(builder.capacity, 0);
+}
+
+/// Checks that the generated function reports fields of the constructed
+/// instance, which are not in initial state.
+#[test]
+fn dirty_instance_rejected() {
+	DIRTY.with(|dirty| dirty.set(true));
+	let error = Builder::try_dirty().err().unwrap();
+	assert_eq!(error.struct_name(), "Builder");
+	assert_eq!(error.violations(), [Violation::new("option", "has Some value instead of None")]);
+}
+
+/// Checks that a function outside of the struct is named after the last
+/// segment of the constructor path.
+#[test]
+fn foreign_constructor_called() {
+	assert_eq!(Builder::try_make_builder().unwrap().capacity, 5);
+}
+
+/// Checks that a generic struct is supported.
+#[test]
+fn generic_constructor_called() {
+	Generic::try_new().unwrap().check_initial_state();
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(
+	constructors("new", "Self::dirty", "crate::make_builder", "Self::with_capacity(10)"),
+	try_constructors,
+)]
+struct Builder {
+	option: Option<i32>,
+	#[ignore_field]
+	capacity: usize,
+}
+
+impl Builder {
+	fn new() -> Self {
+		Self::with_capacity(0)
+	}
+
+	fn dirty() -> Self {
+		Self { option: DIRTY.with(Cell::get).then_some(10), capacity: 0 }
+	}
+
+	fn with_capacity(capacity: usize) -> Self {
+		Self { option: None, capacity }
+	}
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(constructor = "new", try_constructors)]
+struct Generic<'a> {
+	_option: Option<&'a str>,
+}
+
+impl Generic<'_> {
+	fn new() -> Self {
+		Self { _option: None }
+	}
+}
+
+fn make_builder() -> Builder {
+	Builder::with_capacity(5)
+}
@@ -0,0 +1,74 @@
+#![cfg(feature = "item-macros")]
+
+use check_initial_state_derive::{verify_initial_literals, CheckInitialState};
+
+/// Checks that constructors initializing only fields, which are not expected
+/// to be `None`, with `Some` compile and construct the struct.
+#[test]
+fn allowed_literals() {
+	let builder = Builder::new();
+	builder.check_initial_state();
+	assert_eq!(builder._ignored, Some(1));
+	assert_eq!(Builder::with_option(2).option, Some(2));
+	assert_eq!(Builder::named().option, None);
+	assert_eq!(Builder::default().option, None);
+	Builder::new().with_value(3).option.unwrap();
+}
+
+/// Checks that a generic struct is analyzed.
+#[test]
+fn generic_literals() {
+	Generic::<u8>::new().check_initial_state();
+}
+
+#[derive(CheckInitialState)]
+struct Builder {
+	option: Option<i32>,
+	#[expect_matches(None | Some(0))]
+	matched: Option<i32>,
+	#[ignore_field]
+	_ignored: Option<i32>,
+}
+
+#[verify_initial_literals]
+impl Builder {
+	fn new() -> Self {
+		Self { option: None, matched: Some(0), _ignored: Some(1) }
+	}
+
+	#[ignore_constructor]
+	fn with_option(option: i32) -> Self {
+		Self { option: Some(option), ..Self::new() }
+	}
+
+	fn named() -> Self {
+		let value = None;
+		Builder { option: value, ..Self::new() }
+	}
+
+	// Methods with a receiver are setters rather than constructors
+	fn with_value(self, value: i32) -> Self {
+		Self { option: Some(value), ..self }
+	}
+}
+
+#[verify_initial_literals]
+impl Default for Builder {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[derive(CheckInitialState)]
+struct Generic<T> {
+	option: Option<T>,
+	#[ignore_field]
+	_count: Option<usize>,
+}
+
+#[verify_initial_literals]
+impl<T> Generic<T> {
+	fn new() -> Self {
+		Self { option: None, _count: Some(0) }
+	}
+}
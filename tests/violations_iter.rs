@@ -0,0 +1,84 @@
+use check_initial_state_derive::CheckInitialState;
+use std::cell::Cell;
+
+/// Checks that nothing is yielded for a struct in initial state.
+#[test]
+fn no_violations() {
+	let instance = Struct { option: None, _ignored: Some(1), dirty: false, count: 0 };
+	assert_eq!(instance.violations().count(), 0);
+}
+
+/// Checks that names of violating fields are yielded in the order of
+/// declaration.
+#[test]
+fn violations_yielded() {
+	let instance = Struct { option: Some(1), _ignored: Some(1), dirty: false, count: 3 };
+	assert_eq!(instance.violations().collect::<Vec<_>>(), ["option", "count"]);
+}
+
+/// Checks that fields following the consumed violation are not evaluated.
+#[test]
+fn fields_checked_lazily() {
+	let instance = Probed { first: Some(1), second: Counted::default() };
+	assert_eq!(instance.violations().next(), Some("first"));
+	assert_eq!(instance.second.calls.get(), 0);
+	assert_eq!(instance.violations().count(), 1);
+	assert_eq!(instance.second.calls.get(), 1);
+}
+
+/// Checks violations of a struct compared with a reference instance.
+#[test]
+fn violations_compared_with_reference() {
+	assert_eq!(Reference { option: None }.violations().collect::<Vec<_>>(), ["option"]);
+}
+
+/// Checks that report names are yielded.
+#[test]
+fn report_names_yielded() {
+	assert_eq!(Renamed { option: Some(1) }.violations().collect::<Vec<_>>(), ["Renamed option"]);
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(violations_iter)]
+struct Struct {
+	option: Option<i32>,
+	#[ignore_field]
+	_ignored: Option<i32>,
+	#[expect_false]
+	dirty: bool,
+	#[expect_zero]
+	count: u8,
+}
+
+/// Counts `is_empty()` calls.
+#[derive(Default)]
+struct Counted {
+	calls: Cell<usize>,
+}
+
+impl Counted {
+	fn is_empty(&self) -> bool {
+		self.calls.set(self.calls.get() + 1);
+		true
+	}
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(violations_iter, empty_via(Counted = "is_empty"))]
+struct Probed {
+	first: Option<i32>,
+	second: Counted,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(violations_iter, compare_with = "Self { option: Some(1) }")]
+struct Reference {
+	option: Option<i32>,
+}
+
+#[derive(CheckInitialState)]
+#[check_initial_state(violations_iter)]
+struct Renamed {
+	#[report_name = "Renamed option"]
+	option: Option<i32>,
+}
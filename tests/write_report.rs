@@ -0,0 +1,34 @@
+use check_initial_state_derive::CheckInitialState;
+
+/// Checks that nothing is written when a struct is in initial state.
+#[test]
+fn write_report_without_violations() {
+	let mut report = Vec::new();
+	Struct { option: None, option2: None, _integer: 10 }
+		.write_initial_state_report(&mut report)
+		.unwrap();
+	assert!(report.is_empty());
+}
+
+/// Checks that all violations are written to an unsized sink.
+#[test]
+fn write_report_with_violations() {
+	let mut report = Vec::new();
+	let writer: &mut dyn std::io::Write = &mut report;
+	Struct { option: Some(10), option2: Some("value".to_owned()), _integer: 10 }
+		.write_initial_state_report(writer)
+		.unwrap();
+	assert_eq!(
+		String::from_utf8(report).unwrap(),
+		"Field `option` has Some value instead of None\n\
+			Field `option2` has Some value instead of None\n",
+	);
+}
+
+#[derive(CheckInitialState)]
+struct Struct {
+	option: Option<i32>,
+	option2: Option<String>,
+	#[ignore_field]
+	_integer: i32,
+}